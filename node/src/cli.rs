@@ -7,6 +7,15 @@ pub struct Cli {
 
     #[clap(flatten)]
     pub run: RunCmd,
+
+    #[clap(flatten)]
+    pub indexer: crate::indexer::IndexerParams,
+
+    /// Base URL of the IPFS gateway the off-chain worker fetches the game CIDs from, such as
+    /// `http://127.0.0.1:8080`. The CIDs are not checked unless it is set and the keystore holds
+    /// the `lgcr` key of a registered CID reporter.
+    #[arg(long, value_name = "URL")]
+    pub ipfs_gateway: Option<String>,
 }
 
 #[derive(Debug, clap::Subcommand)]
@@ -179,6 +179,8 @@ pub fn run() -> sc_cli::Result<()> {
         },
         None => {
             let runner = cli.create_runner(&cli.run)?;
+            let indexer = cli.indexer.clone();
+            let ipfs_gateway = cli.ipfs_gateway.clone();
             runner.run_node_until_exit(|config| async move {
                 match config.network.network_backend {
                     sc_network::config::NetworkBackendType::Libp2p => service::new_full::<
@@ -186,11 +188,19 @@ pub fn run() -> sc_cli::Result<()> {
                             liganite_runtime::opaque::Block,
                             <liganite_runtime::opaque::Block as sp_runtime::traits::Block>::Hash,
                         >,
-                    >(config)
+                    >(
+                        config,
+                        indexer,
+                        ipfs_gateway,
+                    )
                     .map_err(sc_cli::Error::Service),
                     sc_network::config::NetworkBackendType::Litep2p => {
-                        service::new_full::<sc_network::Litep2pNetworkBackend>(config)
-                            .map_err(sc_cli::Error::Service)
+                        service::new_full::<sc_network::Litep2pNetworkBackend>(
+                            config,
+                            indexer,
+                            ipfs_gateway,
+                        )
+                        .map_err(sc_cli::Error::Service)
                     },
                 }
             })
@@ -0,0 +1,21 @@
+//! Reading of the runtime events deposited in the blocks.
+
+use liganite_runtime::{opaque::Block, Hash, RuntimeEvent};
+use sc_client_api::{Backend, StorageProvider};
+use scale_codec::Decode;
+use sp_core::{storage::StorageKey, twox_128};
+
+/// Returns the events deposited in the block, or `None` if they cannot be read or decoded, such
+/// as for the blocks of a runtime with a different event layout.
+pub fn block_events<C, BE>(client: &C, block_hash: Hash) -> Option<Vec<RuntimeEvent>>
+where
+    C: StorageProvider<Block, BE>,
+    BE: Backend<Block>,
+{
+    let key = StorageKey([twox_128(b"System"), twox_128(b"Events")].concat());
+    let events = client.storage(block_hash, &key).ok()??;
+    let records =
+        Vec::<frame_system::EventRecord<RuntimeEvent, Hash>>::decode(&mut &events.0[..]).ok()?;
+
+    Some(records.into_iter().map(|record| record.event).collect())
+}
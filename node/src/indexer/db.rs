@@ -0,0 +1,712 @@
+//! The SQLite database of the indexer.
+//!
+//! Accounts are stored in their SS58 form, and the details of the games as the JSON of their
+//! `GameInfo`, as returned by the `liganite` RPC methods.
+
+use std::{
+    collections::BTreeMap,
+    path::Path,
+    sync::{Mutex, PoisonError},
+};
+
+use liganite_games_rpc::GameInfo;
+use liganite_primitives::types::{GameId, RegionId, TagId};
+use liganite_runtime::{AssetId, BlockNumber};
+use rusqlite::{params, types::Type, Connection, OptionalExtension, Row};
+use serde::{Deserialize, Serialize};
+
+/// The result of the indexer operations.
+pub type Result<T> = std::result::Result<T, Box<dyn std::error::Error + Send + Sync>>;
+
+/// A game, as stored by the indexer.
+pub type GameRecord = GameInfo<String, AssetId>;
+
+const SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS indexed_block (
+        id INTEGER PRIMARY KEY CHECK (id = 0),
+        number INTEGER NOT NULL
+    );
+    CREATE TABLE IF NOT EXISTS publishers (
+        account TEXT PRIMARY KEY,
+        name TEXT NOT NULL,
+        url TEXT NOT NULL,
+        registered_at INTEGER NOT NULL
+    );
+    CREATE TABLE IF NOT EXISTS tags (
+        tag_id INTEGER PRIMARY KEY,
+        name TEXT NOT NULL,
+        deprecated INTEGER NOT NULL DEFAULT 0
+    );
+    CREATE TABLE IF NOT EXISTS games (
+        publisher TEXT NOT NULL,
+        game_id INTEGER NOT NULL,
+        info TEXT NOT NULL,
+        added_at INTEGER NOT NULL,
+        updated_at INTEGER NOT NULL,
+        PRIMARY KEY (publisher, game_id)
+    );
+    CREATE TABLE IF NOT EXISTS game_tags (
+        publisher TEXT NOT NULL,
+        game_id INTEGER NOT NULL,
+        tag_id INTEGER NOT NULL,
+        PRIMARY KEY (publisher, game_id, tag_id)
+    );
+    CREATE INDEX IF NOT EXISTS game_tags_by_tag ON game_tags (tag_id);
+    CREATE VIRTUAL TABLE IF NOT EXISTS games_search USING fts5(
+        publisher UNINDEXED,
+        game_id UNINDEXED,
+        name,
+        tags,
+        publisher_name
+    );
+    CREATE TABLE IF NOT EXISTS orders (
+        buyer TEXT NOT NULL,
+        publisher TEXT NOT NULL,
+        game_id INTEGER NOT NULL,
+        kind TEXT NOT NULL,
+        status TEXT NOT NULL,
+        region INTEGER,
+        placed_at INTEGER NOT NULL,
+        updated_at INTEGER NOT NULL,
+        PRIMARY KEY (buyer, publisher, game_id)
+    );
+    CREATE INDEX IF NOT EXISTS orders_by_publisher ON orders (publisher, status);
+    CREATE TABLE IF NOT EXISTS ownership (
+        account TEXT NOT NULL,
+        publisher TEXT NOT NULL,
+        game_id INTEGER NOT NULL,
+        acquired_at INTEGER NOT NULL,
+        PRIMARY KEY (account, publisher, game_id)
+    );
+    CREATE TABLE IF NOT EXISTS purchases (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        buyer TEXT NOT NULL,
+        publisher TEXT NOT NULL,
+        game_id INTEGER NOT NULL,
+        kind TEXT NOT NULL,
+        region INTEGER,
+        block_number INTEGER NOT NULL
+    );
+    CREATE INDEX IF NOT EXISTS purchases_by_buyer ON purchases (buyer);
+    CREATE INDEX IF NOT EXISTS purchases_by_publisher ON purchases (publisher);
+    CREATE TABLE IF NOT EXISTS skipped_blocks (
+        number INTEGER PRIMARY KEY,
+        reason TEXT NOT NULL
+    );
+";
+
+/// A page of query results.
+#[derive(Clone, Debug, Serialize)]
+pub struct Page<T> {
+    /// The results in the page.
+    pub items: Vec<T>,
+    /// The number of results across all the pages.
+    pub total: u64,
+}
+
+/// A registered publisher.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PublisherRecord {
+    /// The publisher account.
+    pub account: String,
+    /// The name of the publisher.
+    pub name: String,
+    /// The URL of the publisher.
+    pub url: String,
+    /// The block the publisher registered at.
+    pub registered_at: BlockNumber,
+}
+
+/// The way a game has been ordered.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum OrderKind {
+    /// The game is bought without an order.
+    Instant,
+    /// The game is delivered by the publisher once ordered.
+    Order,
+    /// The game is pre-ordered before its release.
+    PreOrder,
+}
+
+impl OrderKind {
+    fn as_str(&self) -> &'static str {
+        match self {
+            OrderKind::Instant => "instant",
+            OrderKind::Order => "order",
+            OrderKind::PreOrder => "preOrder",
+        }
+    }
+}
+
+/// The status of an order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum OrderStatus {
+    /// The order waits for the publisher.
+    Open,
+    /// The game has been delivered.
+    Fulfilled,
+    /// The order has been cancelled by the buyer.
+    Cancelled,
+    /// The pre-order has been refunded.
+    Refunded,
+    /// The refund of the pre-order failed, and waits to be claimed by the buyer.
+    RefundFailed,
+}
+
+impl OrderStatus {
+    fn as_str(&self) -> &'static str {
+        match self {
+            OrderStatus::Open => "open",
+            OrderStatus::Fulfilled => "fulfilled",
+            OrderStatus::Cancelled => "cancelled",
+            OrderStatus::Refunded => "refunded",
+            OrderStatus::RefundFailed => "refundFailed",
+        }
+    }
+}
+
+/// An order of a game, with its latest status.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderRecord {
+    /// The buyer of the game.
+    pub buyer: String,
+    /// The publisher of the game.
+    pub publisher: String,
+    /// The game id.
+    pub game_id: GameId,
+    /// The way the game has been ordered.
+    pub kind: String,
+    /// The status of the order.
+    pub status: String,
+    /// The region tier the game was charged at, if any.
+    pub region: Option<RegionId>,
+    /// The block the order was placed at.
+    pub placed_at: BlockNumber,
+    /// The block the status last changed at.
+    pub updated_at: BlockNumber,
+}
+
+/// A game owned by an account.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OwnedGameRecord {
+    /// The publisher of the game.
+    pub publisher: String,
+    /// The game id.
+    pub game_id: GameId,
+    /// The block the game was acquired at.
+    pub acquired_at: BlockNumber,
+}
+
+/// A purchase of a game.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PurchaseRecord {
+    /// The buyer of the game.
+    pub buyer: String,
+    /// The publisher of the game.
+    pub publisher: String,
+    /// The game id.
+    pub game_id: GameId,
+    /// The way the game has been ordered.
+    pub kind: String,
+    /// The region tier the game was charged at, if any.
+    pub region: Option<RegionId>,
+    /// The block the game was delivered at.
+    pub block_number: BlockNumber,
+}
+
+/// The indexer database, shared by the indexer and the RPC methods.
+pub struct Database {
+    connection: Mutex<Connection>,
+}
+
+impl Database {
+    /// Opens the database at the given path, creating it and its schema if needed.
+    pub fn open(path: &Path) -> Result<Self> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let connection = Connection::open(path)?;
+        connection.execute_batch(SCHEMA)?;
+
+        Ok(Self { connection: Mutex::new(connection) })
+    }
+
+    /// Returns the number of the last indexed block, if any.
+    pub fn last_indexed(&self) -> Result<Option<BlockNumber>> {
+        let connection = self.connection();
+        let number = connection
+            .query_row("SELECT number FROM indexed_block WHERE id = 0", [], |row| row.get(0))
+            .optional()?;
+        Ok(number)
+    }
+
+    /// Indexes the block in a single transaction, marking it as the last indexed block.
+    pub fn index_block<R>(
+        &self,
+        number: BlockNumber,
+        f: impl FnOnce(&Writer) -> Result<R>,
+    ) -> Result<R> {
+        let mut connection = self.connection();
+        let transaction = connection.transaction()?;
+
+        let result = f(&Writer { connection: &transaction, block: number })?;
+        transaction.execute(
+            "INSERT INTO indexed_block (id, number) VALUES (0, ?1)
+                ON CONFLICT (id) DO UPDATE SET number = excluded.number",
+            [number],
+        )?;
+        transaction.commit()?;
+
+        Ok(result)
+    }
+
+    /// Returns the games whose name, tags or publisher name match all the words of the query, the
+    /// last word being matched as a prefix. The best matches come first.
+    pub fn search_games(&self, query: &str, offset: u32, limit: u32) -> Result<Page<GameRecord>> {
+        let Some(query) = match_query(query) else {
+            return Ok(Page { items: Vec::new(), total: 0 });
+        };
+        let connection = self.connection();
+
+        let total = connection.query_row(
+            "SELECT COUNT(*) FROM games_search WHERE games_search MATCH ?1",
+            [&query],
+            |row| row.get(0),
+        )?;
+        let items = connection
+            .prepare(
+                "SELECT games.info FROM games_search
+                    JOIN games ON games.publisher = games_search.publisher
+                        AND games.game_id = games_search.game_id
+                    WHERE games_search MATCH ?1
+                    ORDER BY games_search.rank
+                    LIMIT ?2 OFFSET ?3",
+            )?
+            .query_map(params![query, limit, offset], game_record)?
+            .collect::<rusqlite::Result<_>>()?;
+
+        Ok(Page { items, total })
+    }
+
+    /// Returns the games of the publisher, by game id.
+    pub fn games_by_publisher(
+        &self,
+        publisher: &str,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Page<GameRecord>> {
+        let connection = self.connection();
+
+        let total = connection.query_row(
+            "SELECT COUNT(*) FROM games WHERE publisher = ?1",
+            [publisher],
+            |row| row.get(0),
+        )?;
+        let items = connection
+            .prepare(
+                "SELECT info FROM games WHERE publisher = ?1
+                    ORDER BY game_id LIMIT ?2 OFFSET ?3",
+            )?
+            .query_map(params![publisher, limit, offset], game_record)?
+            .collect::<rusqlite::Result<_>>()?;
+
+        Ok(Page { items, total })
+    }
+
+    /// Returns the publishers, in the order they registered.
+    pub fn publishers(&self, offset: u32, limit: u32) -> Result<Page<PublisherRecord>> {
+        let connection = self.connection();
+
+        let total =
+            connection.query_row("SELECT COUNT(*) FROM publishers", [], |row| row.get(0))?;
+        let items = connection
+            .prepare(
+                "SELECT account, name, url, registered_at FROM publishers
+                    ORDER BY registered_at, account LIMIT ?1 OFFSET ?2",
+            )?
+            .query_map(params![limit, offset], |row| {
+                Ok(PublisherRecord {
+                    account: row.get(0)?,
+                    name: row.get(1)?,
+                    url: row.get(2)?,
+                    registered_at: row.get(3)?,
+                })
+            })?
+            .collect::<rusqlite::Result<_>>()?;
+
+        Ok(Page { items, total })
+    }
+
+    /// Returns the games owned by the account, the latest acquired first.
+    pub fn owned_games(
+        &self,
+        account: &str,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Page<OwnedGameRecord>> {
+        let connection = self.connection();
+
+        let total = connection.query_row(
+            "SELECT COUNT(*) FROM ownership WHERE account = ?1",
+            [account],
+            |row| row.get(0),
+        )?;
+        let items = connection
+            .prepare(
+                "SELECT publisher, game_id, acquired_at FROM ownership WHERE account = ?1
+                    ORDER BY acquired_at DESC, publisher, game_id LIMIT ?2 OFFSET ?3",
+            )?
+            .query_map(params![account, limit, offset], |row| {
+                Ok(OwnedGameRecord {
+                    publisher: row.get(0)?,
+                    game_id: row.get(1)?,
+                    acquired_at: row.get(2)?,
+                })
+            })?
+            .collect::<rusqlite::Result<_>>()?;
+
+        Ok(Page { items, total })
+    }
+
+    /// Returns the orders of the publisher games with the given status, or with any status, the
+    /// latest placed first.
+    pub fn orders(
+        &self,
+        publisher: &str,
+        status: Option<OrderStatus>,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Page<OrderRecord>> {
+        let connection = self.connection();
+        let status = status.map(|status| status.as_str());
+
+        let total = connection.query_row(
+            "SELECT COUNT(*) FROM orders WHERE publisher = ?1 AND (?2 IS NULL OR status = ?2)",
+            params![publisher, status],
+            |row| row.get(0),
+        )?;
+        let items = connection
+            .prepare(
+                "SELECT buyer, publisher, game_id, kind, status, region, placed_at, updated_at
+                    FROM orders WHERE publisher = ?1 AND (?2 IS NULL OR status = ?2)
+                    ORDER BY placed_at DESC, buyer, game_id LIMIT ?3 OFFSET ?4",
+            )?
+            .query_map(params![publisher, status, limit, offset], |row| {
+                Ok(OrderRecord {
+                    buyer: row.get(0)?,
+                    publisher: row.get(1)?,
+                    game_id: row.get(2)?,
+                    kind: row.get(3)?,
+                    status: row.get(4)?,
+                    region: row.get(5)?,
+                    placed_at: row.get(6)?,
+                    updated_at: row.get(7)?,
+                })
+            })?
+            .collect::<rusqlite::Result<_>>()?;
+
+        Ok(Page { items, total })
+    }
+
+    /// Returns the purchases made by the buyer, or of the publisher games if `column` is
+    /// `"publisher"`, the latest first.
+    fn purchases(
+        &self,
+        column: &str,
+        account: &str,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Page<PurchaseRecord>> {
+        let connection = self.connection();
+
+        let total = connection.query_row(
+            &format!("SELECT COUNT(*) FROM purchases WHERE {column} = ?1"),
+            [account],
+            |row| row.get(0),
+        )?;
+        let items = connection
+            .prepare(&format!(
+                "SELECT buyer, publisher, game_id, kind, region, block_number FROM purchases
+                    WHERE {column} = ?1 ORDER BY id DESC LIMIT ?2 OFFSET ?3"
+            ))?
+            .query_map(params![account, limit, offset], |row| {
+                Ok(PurchaseRecord {
+                    buyer: row.get(0)?,
+                    publisher: row.get(1)?,
+                    game_id: row.get(2)?,
+                    kind: row.get(3)?,
+                    region: row.get(4)?,
+                    block_number: row.get(5)?,
+                })
+            })?
+            .collect::<rusqlite::Result<_>>()?;
+
+        Ok(Page { items, total })
+    }
+
+    /// Returns the purchases made by the buyer, the latest first.
+    pub fn purchases_by_buyer(
+        &self,
+        buyer: &str,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Page<PurchaseRecord>> {
+        self.purchases("buyer", buyer, offset, limit)
+    }
+
+    /// Returns the purchases of the publisher games, the latest first.
+    pub fn purchases_by_publisher(
+        &self,
+        publisher: &str,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Page<PurchaseRecord>> {
+        self.purchases("publisher", publisher, offset, limit)
+    }
+
+    fn connection(&self) -> std::sync::MutexGuard<'_, Connection> {
+        self.connection.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Writes the changes of a block, within the transaction indexing it.
+pub struct Writer<'a> {
+    connection: &'a Connection,
+    block: BlockNumber,
+}
+
+impl Writer<'_> {
+    /// Records the block as skipped, for the reason it cannot be indexed.
+    pub fn block_skip(&self, reason: &str) -> Result<()> {
+        self.connection.execute(
+            "INSERT INTO skipped_blocks (number, reason) VALUES (?1, ?2)
+                ON CONFLICT (number) DO UPDATE SET reason = excluded.reason",
+            params![self.block, reason],
+        )?;
+        Ok(())
+    }
+
+    /// Inserts or updates a publisher.
+    pub fn publisher_upsert(&self, account: &str, name: &str, url: &str) -> Result<()> {
+        self.connection.execute(
+            "INSERT INTO publishers (account, name, url, registered_at) VALUES (?1, ?2, ?3, ?4)
+                ON CONFLICT (account) DO UPDATE SET name = excluded.name, url = excluded.url",
+            params![account, name, url, self.block],
+        )?;
+        Ok(())
+    }
+
+    /// Inserts or renames a tag.
+    pub fn tag_upsert(&self, tag_id: TagId, name: &str) -> Result<()> {
+        self.connection.execute(
+            "INSERT INTO tags (tag_id, name) VALUES (?1, ?2)
+                ON CONFLICT (tag_id) DO UPDATE SET name = excluded.name",
+            params![tag_id, name],
+        )?;
+        Ok(())
+    }
+
+    /// Renames a tag, along with the details and search entries of the games tagged with it.
+    pub fn tag_rename(&self, tag_id: TagId, name: &str) -> Result<()> {
+        self.tag_upsert(tag_id, name)?;
+
+        let games = self
+            .connection
+            .prepare(
+                "SELECT games.info FROM game_tags
+                    JOIN games ON games.publisher = game_tags.publisher
+                        AND games.game_id = game_tags.game_id
+                    WHERE game_tags.tag_id = ?1",
+            )?
+            .query_map([tag_id], game_record)?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        for mut game in games {
+            game.tags
+                .iter_mut()
+                .filter(|tag| tag.id == tag_id)
+                .for_each(|tag| tag.name = name.to_string());
+            self.game_upsert(&game)?;
+        }
+        Ok(())
+    }
+
+    /// Marks a tag as deprecated.
+    pub fn tag_deprecate(&self, tag_id: TagId) -> Result<()> {
+        self.connection
+            .execute("UPDATE tags SET deprecated = 1 WHERE tag_id = ?1", [tag_id])?;
+        Ok(())
+    }
+
+    /// Returns the names of all the tags.
+    pub fn tag_names(&self) -> Result<BTreeMap<TagId, String>> {
+        let names = self
+            .connection
+            .prepare("SELECT tag_id, name FROM tags")?
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .collect::<rusqlite::Result<_>>()?;
+        Ok(names)
+    }
+
+    /// Inserts or updates a game, along with its tags and its search entry.
+    pub fn game_upsert(&self, game: &GameRecord) -> Result<()> {
+        let info = serde_json::to_string(game)?;
+        self.connection.execute(
+            "INSERT INTO games (publisher, game_id, info, added_at, updated_at)
+                VALUES (?1, ?2, ?3, ?4, ?4)
+                ON CONFLICT (publisher, game_id) DO UPDATE
+                    SET info = excluded.info, updated_at = excluded.updated_at",
+            params![game.publisher, game.game_id, info, self.block],
+        )?;
+
+        self.game_tags_remove(&game.publisher, game.game_id)?;
+        for tag in &game.tags {
+            self.connection.execute(
+                "INSERT INTO game_tags (publisher, game_id, tag_id) VALUES (?1, ?2, ?3)",
+                params![game.publisher, game.game_id, tag.id],
+            )?;
+        }
+
+        let tags = game.tags.iter().map(|tag| tag.name.as_str()).collect::<Vec<_>>().join(" ");
+        let publisher_name = self
+            .connection
+            .query_row("SELECT name FROM publishers WHERE account = ?1", [&game.publisher], |row| {
+                row.get::<_, String>(0)
+            })
+            .optional()?
+            .unwrap_or_default();
+        self.connection.execute(
+            "INSERT INTO games_search (publisher, game_id, name, tags, publisher_name)
+                VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![game.publisher, game.game_id, game.name, tags, publisher_name],
+        )?;
+        Ok(())
+    }
+
+    /// Removes a game, along with its tags and its search entry.
+    pub fn game_remove(&self, publisher: &str, game_id: GameId) -> Result<()> {
+        self.connection.execute(
+            "DELETE FROM games WHERE publisher = ?1 AND game_id = ?2",
+            params![publisher, game_id],
+        )?;
+        self.game_tags_remove(publisher, game_id)
+    }
+
+    fn game_tags_remove(&self, publisher: &str, game_id: GameId) -> Result<()> {
+        self.connection.execute(
+            "DELETE FROM game_tags WHERE publisher = ?1 AND game_id = ?2",
+            params![publisher, game_id],
+        )?;
+        self.connection.execute(
+            "DELETE FROM games_search WHERE publisher = ?1 AND game_id = ?2",
+            params![publisher, game_id],
+        )?;
+        Ok(())
+    }
+
+    /// Opens an order, replacing any previous order of the buyer for the game.
+    pub fn order_place(
+        &self,
+        buyer: &str,
+        publisher: &str,
+        game_id: GameId,
+        kind: OrderKind,
+        region: Option<RegionId>,
+    ) -> Result<()> {
+        self.connection.execute(
+            "INSERT OR REPLACE INTO orders
+                (buyer, publisher, game_id, kind, status, region, placed_at, updated_at)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?7)",
+            params![
+                buyer,
+                publisher,
+                game_id,
+                kind.as_str(),
+                OrderStatus::Open.as_str(),
+                region,
+                self.block
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// Closes the open order of the buyer for the game, if any, returning its kind and region. A
+    /// pre-order whose refund failed counts as open.
+    pub fn order_close(
+        &self,
+        buyer: &str,
+        publisher: &str,
+        game_id: GameId,
+        status: OrderStatus,
+    ) -> Result<Option<(String, Option<RegionId>)>> {
+        let order = self
+            .connection
+            .query_row(
+                "UPDATE orders SET status = ?4, updated_at = ?5
+                    WHERE buyer = ?1 AND publisher = ?2 AND game_id = ?3 AND status IN (?6, ?7)
+                    RETURNING kind, region",
+                params![
+                    buyer,
+                    publisher,
+                    game_id,
+                    status.as_str(),
+                    self.block,
+                    OrderStatus::Open.as_str(),
+                    OrderStatus::RefundFailed.as_str()
+                ],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .optional()?;
+        Ok(order)
+    }
+
+    /// Records the delivery of a game to the buyer, fulfilling their open order if any.
+    pub fn purchase(
+        &self,
+        buyer: &str,
+        publisher: &str,
+        game_id: GameId,
+        region: Option<RegionId>,
+    ) -> Result<()> {
+        let order = self.order_close(buyer, publisher, game_id, OrderStatus::Fulfilled)?;
+        let (kind, region) = match order {
+            Some((kind, order_region)) => (kind, region.or(order_region)),
+            None => (OrderKind::Instant.as_str().to_string(), region),
+        };
+
+        self.connection.execute(
+            "INSERT OR IGNORE INTO ownership (account, publisher, game_id, acquired_at)
+                VALUES (?1, ?2, ?3, ?4)",
+            params![buyer, publisher, game_id, self.block],
+        )?;
+        self.connection.execute(
+            "INSERT INTO purchases (buyer, publisher, game_id, kind, region, block_number)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![buyer, publisher, game_id, kind, region, self.block],
+        )?;
+        Ok(())
+    }
+}
+
+/// Decodes the game stored in the first column.
+fn game_record(row: &Row) -> rusqlite::Result<GameRecord> {
+    let info = row.get::<_, String>(0)?;
+    serde_json::from_str(&info)
+        .map_err(|e| rusqlite::Error::FromSqlConversionFailure(0, Type::Text, Box::new(e)))
+}
+
+/// Converts a search query into an FTS5 query matching all its words, quoting them so that they
+/// are not parsed as operators. Returns `None` if the query has no words.
+fn match_query(query: &str) -> Option<String> {
+    let words = query
+        .split_whitespace()
+        .map(|word| format!("\"{}\"", word.replace('"', "\"\"")))
+        .collect::<Vec<_>>();
+
+    (!words.is_empty()).then(|| format!("{}*", words.join(" ")))
+}
@@ -0,0 +1,270 @@
+//! An optional indexer, following the finalized blocks into a local SQLite database.
+//!
+//! The publishers, games, tags, orders, ownership and purchases are decoded from the events of
+//! the `liganite_games` and `liganite_publish` pallets, completed with the details read from the
+//! state of the block, and served by the `indexer` RPC methods.
+
+use std::{marker::PhantomData, path::PathBuf, sync::Arc};
+
+use futures::StreamExt;
+use liganite_games_runtime_api::GamesApi;
+use liganite_primitives::types::{GameId, Locale, PublisherDetails};
+use liganite_runtime::{
+    opaque::Block, AccountId, AssetId, Balance, BlockNumber, Hash, Runtime, RuntimeEvent,
+};
+use sc_client_api::{Backend, BlockchainEvents, StorageProvider};
+use scale_codec::{Decode, Encode};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::{crypto::Ss58Codec, storage::StorageKey, twox_128, twox_64};
+use sp_runtime::traits::Header;
+
+use crate::events::block_events;
+
+mod db;
+mod rpc;
+
+pub use db::Database;
+use db::{GameRecord, OrderKind, OrderStatus, Result, Writer};
+pub use rpc::{IndexerApiServer, IndexerRpc};
+
+const LOG_TARGET: &str = "liganite-indexer";
+
+/// The locale the tags are named in. Tags are not translated to it, so their default names are
+/// used.
+const TAGS_LOCALE: &[u8] = b"en";
+
+type GamesEvent = liganite_games::Event<Runtime>;
+type PublishEvent = liganite_publish::Event<Runtime>;
+
+/// Parameters of the indexer.
+#[derive(Debug, Clone, clap::Args)]
+pub struct IndexerParams {
+    /// Index the finalized blocks into a local SQLite database, queried through the `indexer`
+    /// RPC methods. Requires an archive node, run with `--state-pruning archive`.
+    #[arg(long)]
+    pub indexer: bool,
+
+    /// Path of the indexer database. Defaults to `indexer.sqlite` in the chain directory.
+    #[arg(long, value_name = "PATH", requires = "indexer")]
+    pub indexer_path: Option<PathBuf>,
+}
+
+/// Follows the finalized blocks and indexes them into the database.
+pub struct Indexer<C, BE> {
+    client: Arc<C>,
+    database: Arc<Database>,
+    _marker: PhantomData<BE>,
+}
+
+impl<C, BE> Indexer<C, BE>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + BlockchainEvents<Block>,
+    C: StorageProvider<Block, BE> + Send + Sync + 'static,
+    C::Api: GamesApi<Block, AccountId, Balance, AssetId, BlockNumber>,
+    BE: Backend<Block> + 'static,
+{
+    /// Creates a new indexer writing into the database.
+    pub fn new(client: Arc<C>, database: Arc<Database>) -> Self {
+        Self { client, database, _marker: Default::default() }
+    }
+
+    /// Indexes the blocks finalized since the last indexed block, then every newly finalized
+    /// block.
+    pub async fn run(self) {
+        let mut finality = self.client.finality_notification_stream();
+
+        self.index_until(self.client.info().finalized_number);
+        while let Some(notification) = finality.next().await {
+            self.index_until(*notification.header.number());
+        }
+    }
+
+    /// Indexes the blocks following the last indexed block, up to the given block.
+    ///
+    /// A block that cannot be read, such as one whose events were deposited by a runtime with a
+    /// different event layout, is recorded as skipped with the reason. A database failure stops
+    /// the indexing, to retry the block on the next finalized block.
+    fn index_until(&self, finalized: BlockNumber) {
+        let mut next = match self.database.last_indexed() {
+            Ok(last) => last.map_or(0, |number| number + 1),
+            Err(e) => {
+                log::error!(target: LOG_TARGET, "Failed to read the last indexed block: {e}");
+                return;
+            },
+        };
+
+        while next <= finalized {
+            match self.index_block(next) {
+                Ok(()) => {},
+                Err(e) if e.downcast_ref::<rusqlite::Error>().is_some() => {
+                    log::error!(target: LOG_TARGET, "Failed to index block #{next}: {e}");
+                    return;
+                },
+                Err(e) => {
+                    log::warn!(target: LOG_TARGET, "Skipping block #{next}: {e}");
+                    let reason = e.to_string();
+                    if let Err(e) =
+                        self.database.index_block(next, |writer| writer.block_skip(&reason))
+                    {
+                        log::error!(target: LOG_TARGET, "Failed to skip block #{next}: {e}");
+                        return;
+                    }
+                },
+            }
+            next += 1;
+        }
+    }
+
+    fn index_block(&self, number: BlockNumber) -> Result<()> {
+        let hash = self.client.hash(number)?.ok_or("The finalized block is not found")?;
+        // The genesis block may have no events stored
+        let events = block_events(&*self.client, hash)
+            .or_else(|| (number == 0).then(Vec::new))
+            .ok_or("The events of the block cannot be read")?;
+
+        self.database.index_block(number, |writer| {
+            if number == 0 {
+                self.index_genesis(writer, hash)?;
+            }
+            for event in events {
+                match event {
+                    RuntimeEvent::Publish(event) => {
+                        self.index_publish_event(writer, hash, event)?
+                    },
+                    RuntimeEvent::Games(event) => self.index_games_event(writer, hash, event)?,
+                    _ => {},
+                }
+            }
+            Ok(())
+        })
+    }
+
+    /// Indexes the tags added in the genesis block, which have no event.
+    fn index_genesis(&self, writer: &Writer, hash: Hash) -> Result<()> {
+        let locale = Locale::truncate_from(TAGS_LOCALE.to_vec());
+        for (tag_id, tag) in self.client.runtime_api().tags_for_locale(hash, locale)? {
+            writer.tag_upsert(tag_id, &utf8(&tag))?;
+        }
+        Ok(())
+    }
+
+    fn index_publish_event(&self, writer: &Writer, hash: Hash, event: PublishEvent) -> Result<()> {
+        if let PublishEvent::PublisherAdded { publisher } = event {
+            if let Some(details) = self.publisher_details(hash, &publisher)? {
+                writer.publisher_upsert(
+                    &account(&publisher),
+                    &utf8(&details.name),
+                    &utf8(&details.url),
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    fn index_games_event(&self, writer: &Writer, hash: Hash, event: GamesEvent) -> Result<()> {
+        match event {
+            GamesEvent::GameAdded { publisher, game_id } |
+            GamesEvent::GamePriceSet { publisher, game_id, .. } |
+            GamesEvent::GameDiscountSet { publisher, game_id, .. } |
+            GamesEvent::PreOrderReleased { publisher, game_id } |
+            GamesEvent::PreOrderCancelled { publisher, game_id } |
+            GamesEvent::PreOrderExpired { publisher, game_id } => {
+                self.index_game(writer, hash, publisher, game_id)
+            },
+            GamesEvent::TagAdded { tag_id, tag, .. } => writer.tag_upsert(tag_id, &utf8(&tag)),
+            GamesEvent::TagRenamed { tag_id, tag } => writer.tag_rename(tag_id, &utf8(&tag)),
+            GamesEvent::TagDeprecated { tag_id } => writer.tag_deprecate(tag_id),
+            GamesEvent::GamePurchased { buyer, publisher, game_id, region, .. } => {
+                writer.purchase(&account(&buyer), &account(&publisher), game_id, region)
+            },
+            GamesEvent::OrderFulfilled { buyer, publisher, game_id } => {
+                writer.purchase(&account(&buyer), &account(&publisher), game_id, None)
+            },
+            GamesEvent::OrderPlaced { buyer, publisher, game_id, region } => writer.order_place(
+                &account(&buyer),
+                &account(&publisher),
+                game_id,
+                OrderKind::Order,
+                region,
+            ),
+            GamesEvent::PreOrderPlaced { buyer, publisher, game_id, region } => writer.order_place(
+                &account(&buyer),
+                &account(&publisher),
+                game_id,
+                OrderKind::PreOrder,
+                region,
+            ),
+            GamesEvent::OrderCancelled { buyer, publisher, game_id } => writer
+                .order_close(
+                    &account(&buyer),
+                    &account(&publisher),
+                    game_id,
+                    OrderStatus::Cancelled,
+                )
+                .map(|_| ()),
+            GamesEvent::PreOrderRefunded { buyer, publisher, game_id } => writer
+                .order_close(&account(&buyer), &account(&publisher), game_id, OrderStatus::Refunded)
+                .map(|_| ()),
+            GamesEvent::PreOrderRefundFailed { buyer, publisher, game_id, .. } => writer
+                .order_close(
+                    &account(&buyer),
+                    &account(&publisher),
+                    game_id,
+                    OrderStatus::RefundFailed,
+                )
+                .map(|_| ()),
+            _ => Ok(()),
+        }
+    }
+
+    /// Indexes the details of the game as of the block, removing it if it is not found.
+    fn index_game(
+        &self,
+        writer: &Writer,
+        hash: Hash,
+        publisher: AccountId,
+        game_id: GameId,
+    ) -> Result<()> {
+        let details = self.client.runtime_api().game_details(hash, publisher.clone(), game_id)?;
+        match details {
+            Some(details) => {
+                let game =
+                    GameRecord::new(account(&publisher), game_id, details, &writer.tag_names()?);
+                writer.game_upsert(&game)
+            },
+            None => writer.game_remove(&account(&publisher), game_id),
+        }
+    }
+
+    /// Reads the details of the publisher from the state of the block.
+    fn publisher_details(
+        &self,
+        hash: Hash,
+        publisher: &AccountId,
+    ) -> Result<Option<PublisherDetails>> {
+        let encoded = publisher.encode();
+        let key = [
+            twox_128(b"Publish").as_slice(),
+            &twox_128(b"Publishers"),
+            &twox_64(&encoded),
+            &encoded,
+        ]
+        .concat();
+
+        let Some(data) = self.client.storage(hash, &StorageKey(key))? else {
+            return Ok(None);
+        };
+        Ok(Some(PublisherDetails::decode(&mut &data.0[..])?))
+    }
+}
+
+/// Returns the SS58 form of the account, as stored in the database.
+fn account(account: &AccountId) -> String {
+    account.to_ss58check()
+}
+
+/// Decodes the bytes as UTF-8, replacing invalid sequences.
+fn utf8(bytes: &[u8]) -> String {
+    String::from_utf8_lossy(bytes).into_owned()
+}
@@ -0,0 +1,219 @@
+//! The `indexer` RPC methods, querying the indexer database.
+
+use std::sync::Arc;
+
+use jsonrpsee::{
+    core::RpcResult,
+    proc_macros::rpc,
+    types::error::{ErrorObject, ErrorObjectOwned},
+};
+use liganite_runtime::{AccountId, BlockNumber};
+
+use super::{
+    account,
+    db::{
+        GameRecord, OrderRecord, OrderStatus, OwnedGameRecord, Page, PublisherRecord,
+        PurchaseRecord,
+    },
+    Database,
+};
+
+/// The maximum number of results in a page.
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+/// The `indexer` RPC methods. The results are paginated: `page` is zero-based and `page_size`
+/// defaults to, and is capped at, `MAX_PAGE_SIZE`.
+#[rpc(server, namespace = "indexer")]
+pub trait IndexerApi {
+    /// Returns the number of the last indexed block, or `None` if no block is indexed yet.
+    #[method(name = "status", blocking)]
+    fn status(&self) -> RpcResult<Option<BlockNumber>>;
+
+    /// Returns the games whose name, tags or publisher name match all the words of the query,
+    /// the best matches first.
+    #[method(name = "searchGames", blocking)]
+    fn search_games(
+        &self,
+        query: String,
+        page: Option<u32>,
+        page_size: Option<u32>,
+    ) -> RpcResult<Page<GameRecord>>;
+
+    /// Returns the games of the publisher.
+    #[method(name = "gamesByPublisher", blocking)]
+    fn games_by_publisher(
+        &self,
+        publisher: AccountId,
+        page: Option<u32>,
+        page_size: Option<u32>,
+    ) -> RpcResult<Page<GameRecord>>;
+
+    /// Returns the registered publishers.
+    #[method(name = "publishers", blocking)]
+    fn publishers(
+        &self,
+        page: Option<u32>,
+        page_size: Option<u32>,
+    ) -> RpcResult<Page<PublisherRecord>>;
+
+    /// Returns the games owned by the account.
+    #[method(name = "ownedGames", blocking)]
+    fn owned_games(
+        &self,
+        who: AccountId,
+        page: Option<u32>,
+        page_size: Option<u32>,
+    ) -> RpcResult<Page<OwnedGameRecord>>;
+
+    /// Returns the orders of the publisher games, optionally only those with the given status.
+    #[method(name = "orders", blocking)]
+    fn orders(
+        &self,
+        publisher: AccountId,
+        status: Option<OrderStatus>,
+        page: Option<u32>,
+        page_size: Option<u32>,
+    ) -> RpcResult<Page<OrderRecord>>;
+
+    /// Returns the purchase history of the buyer.
+    #[method(name = "purchases", blocking)]
+    fn purchases(
+        &self,
+        buyer: AccountId,
+        page: Option<u32>,
+        page_size: Option<u32>,
+    ) -> RpcResult<Page<PurchaseRecord>>;
+
+    /// Returns the purchase history of the publisher games.
+    #[method(name = "sales", blocking)]
+    fn sales(
+        &self,
+        publisher: AccountId,
+        page: Option<u32>,
+        page_size: Option<u32>,
+    ) -> RpcResult<Page<PurchaseRecord>>;
+}
+
+/// Error codes of the `indexer` RPC methods.
+pub enum Error {
+    /// The query to the database failed.
+    DatabaseError,
+}
+
+impl From<Error> for i32 {
+    fn from(e: Error) -> i32 {
+        match e {
+            Error::DatabaseError => 1,
+        }
+    }
+}
+
+fn database_error(e: impl ToString) -> ErrorObjectOwned {
+    ErrorObject::owned(
+        Error::DatabaseError.into(),
+        "Unable to query the indexer.",
+        Some(e.to_string()),
+    )
+}
+
+/// Implementation of the `indexer` RPC methods.
+pub struct IndexerRpc {
+    database: Arc<Database>,
+}
+
+impl IndexerRpc {
+    /// Creates a new instance of the `indexer` RPC methods.
+    pub fn new(database: Arc<Database>) -> Self {
+        Self { database }
+    }
+}
+
+impl IndexerApiServer for IndexerRpc {
+    fn status(&self) -> RpcResult<Option<BlockNumber>> {
+        self.database.last_indexed().map_err(database_error)
+    }
+
+    fn search_games(
+        &self,
+        query: String,
+        page: Option<u32>,
+        page_size: Option<u32>,
+    ) -> RpcResult<Page<GameRecord>> {
+        let (offset, limit) = bounds(page, page_size);
+        self.database.search_games(&query, offset, limit).map_err(database_error)
+    }
+
+    fn games_by_publisher(
+        &self,
+        publisher: AccountId,
+        page: Option<u32>,
+        page_size: Option<u32>,
+    ) -> RpcResult<Page<GameRecord>> {
+        let (offset, limit) = bounds(page, page_size);
+        self.database
+            .games_by_publisher(&account(&publisher), offset, limit)
+            .map_err(database_error)
+    }
+
+    fn publishers(
+        &self,
+        page: Option<u32>,
+        page_size: Option<u32>,
+    ) -> RpcResult<Page<PublisherRecord>> {
+        let (offset, limit) = bounds(page, page_size);
+        self.database.publishers(offset, limit).map_err(database_error)
+    }
+
+    fn owned_games(
+        &self,
+        who: AccountId,
+        page: Option<u32>,
+        page_size: Option<u32>,
+    ) -> RpcResult<Page<OwnedGameRecord>> {
+        let (offset, limit) = bounds(page, page_size);
+        self.database.owned_games(&account(&who), offset, limit).map_err(database_error)
+    }
+
+    fn orders(
+        &self,
+        publisher: AccountId,
+        status: Option<OrderStatus>,
+        page: Option<u32>,
+        page_size: Option<u32>,
+    ) -> RpcResult<Page<OrderRecord>> {
+        let (offset, limit) = bounds(page, page_size);
+        self.database
+            .orders(&account(&publisher), status, offset, limit)
+            .map_err(database_error)
+    }
+
+    fn purchases(
+        &self,
+        buyer: AccountId,
+        page: Option<u32>,
+        page_size: Option<u32>,
+    ) -> RpcResult<Page<PurchaseRecord>> {
+        let (offset, limit) = bounds(page, page_size);
+        self.database
+            .purchases_by_buyer(&account(&buyer), offset, limit)
+            .map_err(database_error)
+    }
+
+    fn sales(
+        &self,
+        publisher: AccountId,
+        page: Option<u32>,
+        page_size: Option<u32>,
+    ) -> RpcResult<Page<PurchaseRecord>> {
+        let (offset, limit) = bounds(page, page_size);
+        self.database
+            .purchases_by_publisher(&account(&publisher), offset, limit)
+            .map_err(database_error)
+    }
+}
+
+/// Returns the offset and the number of the results of the page.
+fn bounds(page: Option<u32>, page_size: Option<u32>) -> (u32, u32) {
+    let limit = page_size.unwrap_or(MAX_PAGE_SIZE).min(MAX_PAGE_SIZE);
+    (page.unwrap_or(0).saturating_mul(limit), limit)
+}
@@ -6,6 +6,8 @@ mod benchmarking;
 mod chain_spec;
 mod cli;
 mod command;
+mod events;
+mod indexer;
 mod rpc;
 mod service;
 
@@ -7,47 +7,62 @@
 
 use std::sync::Arc;
 
+use crate::indexer::Database;
 use jsonrpsee::RpcModule;
-use liganite_runtime::{opaque::Block, AccountId, Balance, Nonce};
+use liganite_runtime::{opaque::Block, AccountId, AssetId, Balance, BlockNumber, Nonce};
+use sc_client_api::{Backend, BlockchainEvents, StorageProvider};
+use sc_rpc::SubscriptionTaskExecutor;
 use sc_transaction_pool_api::TransactionPool;
 use sp_api::ProvideRuntimeApi;
 use sp_block_builder::BlockBuilder;
 use sp_blockchain::{Error as BlockChainError, HeaderBackend, HeaderMetadata};
 
+mod subscriptions;
+
 /// Full client dependencies.
 pub struct FullDeps<C, P> {
     /// The client instance to use.
     pub client: Arc<C>,
     /// Transaction pool instance.
     pub pool: Arc<P>,
+    /// The indexer database, if the indexer is enabled.
+    pub indexer: Option<Arc<Database>>,
 }
 
 /// Instantiate all full RPC extensions.
-pub fn create_full<C, P>(
+pub fn create_full<C, P, BE>(
     deps: FullDeps<C, P>,
+    subscription_executor: SubscriptionTaskExecutor,
 ) -> Result<RpcModule<()>, Box<dyn std::error::Error + Send + Sync>>
 where
     C: ProvideRuntimeApi<Block>,
     C: HeaderBackend<Block> + HeaderMetadata<Block, Error = BlockChainError> + 'static,
+    C: BlockchainEvents<Block> + StorageProvider<Block, BE>,
     C: Send + Sync + 'static,
     C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
     C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
+    C::Api: liganite_games_runtime_api::GamesApi<Block, AccountId, Balance, AssetId, BlockNumber>,
     C::Api: BlockBuilder<Block>,
     P: TransactionPool + 'static,
+    BE: Backend<Block> + 'static,
 {
+    use crate::indexer::{IndexerApiServer, IndexerRpc};
+    use liganite_games_rpc::{Liganite, LiganiteApiServer};
     use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
+    use subscriptions::{LiganiteSubscriptionApiServer, LiganiteSubscriptions};
     use substrate_frame_rpc_system::{System, SystemApiServer};
 
     let mut module = RpcModule::new(());
-    let FullDeps { client, pool } = deps;
+    let FullDeps { client, pool, indexer } = deps;
 
     module.merge(System::new(client.clone(), pool).into_rpc())?;
-    module.merge(TransactionPayment::new(client).into_rpc())?;
-
-    // Extend this RPC with a custom API by using the following syntax.
-    // `YourRpcStruct` should have a reference to a client, which is needed
-    // to call into the runtime.
-    // `module.merge(YourRpcTrait::into_rpc(YourRpcStruct::new(ReferenceToClient, ...)))?;`
+    module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+    let liganite = Liganite::new(client.clone());
+    module.merge(LiganiteApiServer::<_, _, AccountId, Balance, AssetId>::into_rpc(liganite))?;
+    module.merge(LiganiteSubscriptions::new(client, subscription_executor).into_rpc())?;
+    if let Some(database) = indexer {
+        module.merge(IndexerRpc::new(database).into_rpc())?;
+    }
 
     // You probably want to enable the `rpc v2 chainSpec` API as well
     //
@@ -0,0 +1,223 @@
+//! Subscriptions to the changes of game ownership and orders.
+//!
+//! The events of the games pallet are decoded from the new best blocks, or from the finalized
+//! blocks if requested, and pushed to the subscribers they concern.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use futures::{future, stream, stream::BoxStream, StreamExt};
+use jsonrpsee::{proc_macros::rpc, PendingSubscriptionSink};
+use liganite_primitives::types::GameId;
+use liganite_runtime::{opaque::Block, AccountId, Hash, Runtime, RuntimeEvent};
+use sc_client_api::{Backend, BlockchainEvents, StorageProvider};
+use sc_rpc::{
+    utils::{pipe_from_stream, spawn_subscription_task},
+    SubscriptionTaskExecutor,
+};
+use serde::Serialize;
+
+use crate::events::block_events;
+
+/// The event of the games pallet.
+type GamesEvent = liganite_games::Event<Runtime>;
+
+/// A change of a game ownership or order.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GameNotification {
+    /// The block the change happened in.
+    pub block_hash: Hash,
+    /// The kind of the change.
+    pub kind: GameNotificationKind,
+    /// The buyer of the game.
+    pub buyer: AccountId,
+    /// The publisher of the game.
+    pub publisher: AccountId,
+    /// The game id.
+    pub game_id: GameId,
+    /// The CID of the game, if it can now be downloaded by the buyer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cid: Option<String>,
+}
+
+/// The kind of a change of a game ownership or order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum GameNotificationKind {
+    /// The game has been purchased, or a pre-ordered game has been released.
+    Purchased,
+    /// An order has been placed.
+    OrderPlaced,
+    /// An order has been cancelled.
+    OrderCancelled,
+    /// An order has been fulfilled.
+    OrderFulfilled,
+    /// A pre-order has been placed.
+    PreOrderPlaced,
+    /// A pre-order has been refunded.
+    PreOrderRefunded,
+}
+
+impl GameNotificationKind {
+    /// Returns whether the buyer has been granted the game.
+    fn is_ownership(&self) -> bool {
+        matches!(self, Self::Purchased | Self::OrderFulfilled)
+    }
+
+    /// Returns whether the change concerns an order or a pre-order.
+    fn is_order(&self) -> bool {
+        !matches!(self, Self::Purchased)
+    }
+}
+
+/// The `liganite` subscriptions.
+#[rpc(server, namespace = "liganite")]
+pub trait LiganiteSubscriptionApi {
+    /// Subscribes to the games the account is granted, by a purchase or a fulfilled order. The
+    /// new best blocks are watched, unless `finalized` is set.
+    #[subscription(
+        name = "subscribeOwnership" => "ownership",
+        unsubscribe = "unsubscribeOwnership",
+        item = GameNotification
+    )]
+    fn subscribe_ownership(&self, who: AccountId, finalized: Option<bool>);
+
+    /// Subscribes to the orders and pre-orders of the publisher games. The new best blocks are
+    /// watched, unless `finalized` is set.
+    #[subscription(
+        name = "subscribeOrders" => "orders",
+        unsubscribe = "unsubscribeOrders",
+        item = GameNotification
+    )]
+    fn subscribe_orders(&self, publisher: AccountId, finalized: Option<bool>);
+}
+
+/// Implementation of the `liganite` subscriptions, reading the events from the client storage.
+pub struct LiganiteSubscriptions<C, BE> {
+    client: Arc<C>,
+    executor: SubscriptionTaskExecutor,
+    _marker: PhantomData<BE>,
+}
+
+impl<C, BE> LiganiteSubscriptions<C, BE> {
+    /// Creates a new instance of the `liganite` subscriptions.
+    pub fn new(client: Arc<C>, executor: SubscriptionTaskExecutor) -> Self {
+        Self { client, executor, _marker: Default::default() }
+    }
+}
+
+impl<C, BE> LiganiteSubscriptionApiServer for LiganiteSubscriptions<C, BE>
+where
+    C: BlockchainEvents<Block> + StorageProvider<Block, BE> + Send + Sync + 'static,
+    BE: Backend<Block> + 'static,
+{
+    fn subscribe_ownership(
+        &self,
+        pending: PendingSubscriptionSink,
+        who: AccountId,
+        finalized: Option<bool>,
+    ) {
+        self.subscribe(pending, finalized, move |notification| {
+            notification.kind.is_ownership() && notification.buyer == who
+        });
+    }
+
+    fn subscribe_orders(
+        &self,
+        pending: PendingSubscriptionSink,
+        publisher: AccountId,
+        finalized: Option<bool>,
+    ) {
+        self.subscribe(pending, finalized, move |notification| {
+            notification.kind.is_order() && notification.publisher == publisher
+        });
+    }
+}
+
+impl<C, BE> LiganiteSubscriptions<C, BE>
+where
+    C: BlockchainEvents<Block> + StorageProvider<Block, BE> + Send + Sync + 'static,
+    BE: Backend<Block> + 'static,
+{
+    /// Pushes the notifications accepted by `filter` to the subscriber.
+    fn subscribe(
+        &self,
+        pending: PendingSubscriptionSink,
+        finalized: Option<bool>,
+        filter: impl Fn(&GameNotification) -> bool + Send + 'static,
+    ) {
+        let client = self.client.clone();
+        let stream = self
+            .blocks(finalized.unwrap_or(false))
+            .flat_map(move |hash| stream::iter(notifications(&*client, hash)))
+            .filter(move |notification| future::ready(filter(notification)))
+            .boxed();
+
+        spawn_subscription_task(&self.executor, pipe_from_stream(pending, stream));
+    }
+
+    /// Returns the hashes of the new best blocks, or of all the finalized blocks.
+    fn blocks(&self, finalized: bool) -> BoxStream<'static, Hash> {
+        if finalized {
+            self.client
+                .finality_notification_stream()
+                .flat_map(|notification| {
+                    let mut hashes = notification.tree_route.to_vec();
+                    hashes.push(notification.hash);
+                    stream::iter(hashes)
+                })
+                .boxed()
+        } else {
+            self.client
+                .import_notification_stream()
+                .filter(|notification| future::ready(notification.is_new_best))
+                .map(|notification| notification.hash)
+                .boxed()
+        }
+    }
+}
+
+/// Returns the notifications of the games events deposited in the block. Blocks whose events
+/// cannot be read or decoded yield no notification.
+fn notifications<C, BE>(client: &C, block_hash: Hash) -> Vec<GameNotification>
+where
+    C: StorageProvider<Block, BE>,
+    BE: Backend<Block>,
+{
+    block_events(client, block_hash)
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|event| match event {
+            RuntimeEvent::Games(event) => notification(block_hash, event),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Converts a games event into a notification, if it changes a game ownership or order.
+fn notification(block_hash: Hash, event: GamesEvent) -> Option<GameNotification> {
+    let (kind, buyer, publisher, game_id, cid) = match event {
+        GamesEvent::GamePurchased { buyer, publisher, game_id, cid, .. } => {
+            let cid = String::from_utf8_lossy(&cid).into_owned();
+            (GameNotificationKind::Purchased, buyer, publisher, game_id, Some(cid))
+        },
+        GamesEvent::OrderPlaced { buyer, publisher, game_id, .. } => {
+            (GameNotificationKind::OrderPlaced, buyer, publisher, game_id, None)
+        },
+        GamesEvent::OrderCancelled { buyer, publisher, game_id } => {
+            (GameNotificationKind::OrderCancelled, buyer, publisher, game_id, None)
+        },
+        GamesEvent::OrderFulfilled { buyer, publisher, game_id } => {
+            (GameNotificationKind::OrderFulfilled, buyer, publisher, game_id, None)
+        },
+        GamesEvent::PreOrderPlaced { buyer, publisher, game_id, .. } => {
+            (GameNotificationKind::PreOrderPlaced, buyer, publisher, game_id, None)
+        },
+        GamesEvent::PreOrderRefunded { buyer, publisher, game_id } => {
+            (GameNotificationKind::PreOrderRefunded, buyer, publisher, game_id, None)
+        },
+        _ => return None,
+    };
+
+    Some(GameNotification { block_hash, kind, buyer, publisher, game_id, cid })
+}
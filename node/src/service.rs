@@ -1,14 +1,18 @@
 //! Service and ServiceFactory implementation. Specialized wrapper over substrate service.
 
+use crate::indexer::{Database, Indexer, IndexerParams};
 use futures::FutureExt;
 use liganite_runtime::{self, apis::RuntimeApi, opaque::Block};
 use sc_client_api::{Backend, BlockBackend};
 use sc_consensus_aura::{ImportQueueParams, SlotProportion, StartAuraParams};
 use sc_consensus_grandpa::SharedVoterState;
-use sc_service::{error::Error as ServiceError, Configuration, TaskManager, WarpSyncConfig};
+use sc_service::{
+    error::Error as ServiceError, Configuration, PruningMode, TaskManager, WarpSyncConfig,
+};
 use sc_telemetry::{Telemetry, TelemetryWorker};
 use sc_transaction_pool_api::OffchainTransactionPoolFactory;
 use sp_consensus_aura::sr25519::AuthorityPair as AuraPair;
+use sp_core::offchain::{OffchainStorage, STORAGE_PREFIX};
 use std::{sync::Arc, time::Duration};
 
 pub(crate) type FullClient = sc_service::TFullClient<
@@ -131,6 +135,8 @@ pub fn new_full<
     N: sc_network::NetworkBackend<Block, <Block as sp_runtime::traits::Block>::Hash>,
 >(
     config: Configuration,
+    indexer: IndexerParams,
+    ipfs_gateway: Option<String>,
 ) -> Result<TaskManager, ServiceError> {
     let sc_service::PartialComponents {
         client,
@@ -184,6 +190,10 @@ pub fn new_full<
         })?;
 
     if config.offchain_worker.enabled {
+        if let (Some(gateway), Some(mut storage)) = (ipfs_gateway, backend.offchain_storage()) {
+            storage.set(STORAGE_PREFIX, liganite_games::IPFS_GATEWAY_KEY, gateway.as_bytes());
+        }
+
         let offchain_workers =
             sc_offchain::OffchainWorkers::new(sc_offchain::OffchainWorkerOptions {
                 runtime_api_provider: client.clone(),
@@ -211,13 +221,41 @@ pub fn new_full<
     let enable_grandpa = !config.disable_grandpa;
     let prometheus_registry = config.prometheus_registry().cloned();
 
+    let indexer = if indexer.indexer {
+        // The events and details of every finalized block are read from its state
+        if !config.state_pruning.as_ref().is_some_and(PruningMode::is_archive) {
+            return Err(ServiceError::Other(
+                "The indexer requires an archive node, run with `--state-pruning archive`".into(),
+            ));
+        }
+        let path = indexer.indexer_path.unwrap_or_else(|| {
+            config.base_path.config_dir(config.chain_spec.id()).join("indexer.sqlite")
+        });
+        let database = Arc::new(Database::open(&path).map_err(|e| {
+            ServiceError::Other(format!("Failed to open the indexer database: {e}"))
+        })?);
+        task_manager.spawn_handle().spawn_blocking(
+            "liganite-indexer",
+            None,
+            Indexer::<_, FullBackend>::new(client.clone(), database.clone()).run(),
+        );
+        Some(database)
+    } else {
+        None
+    };
+
     let rpc_extensions_builder = {
         let client = client.clone();
         let pool = transaction_pool.clone();
 
-        Box::new(move |_| {
-            let deps = crate::rpc::FullDeps { client: client.clone(), pool: pool.clone() };
-            crate::rpc::create_full(deps).map_err(Into::into)
+        Box::new(move |subscription_executor| {
+            let deps = crate::rpc::FullDeps {
+                client: client.clone(),
+                pool: pool.clone(),
+                indexer: indexer.clone(),
+            };
+            crate::rpc::create_full::<_, _, FullBackend>(deps, subscription_executor)
+                .map_err(Into::into)
         })
     };
 
@@ -0,0 +1,327 @@
+//! RPC interface for the games pallet.
+//!
+//! The methods are exposed under the `liganite` namespace and return the games in a
+//! human-readable form: names and CIDs are strings, and tags are resolved to their names.
+
+#![warn(missing_docs)]
+
+use std::{collections::BTreeMap, marker::PhantomData, sync::Arc};
+
+use jsonrpsee::{
+    core::RpcResult,
+    proc_macros::rpc,
+    types::error::{ErrorObject, ErrorObjectOwned},
+};
+use liganite_games_runtime_api::GamesApi;
+use liganite_primitives::types::{GameId, Locale, TagId};
+use scale_codec::Codec;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::{Block as BlockT, NumberFor};
+
+mod types;
+
+pub use types::{DistributionInfo, GameInfo, NamedTag, PublisherInfo, RegionalPrice};
+
+/// The maximum number of games returned by a single call.
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+/// The locale used to name the tags when none is requested. Tags are not translated to it, so
+/// their default names are used.
+const DEFAULT_LOCALE: &str = "en";
+
+/// The `liganite` RPC methods.
+#[rpc(client, server, namespace = "liganite")]
+pub trait LiganiteApi<BlockHash, BlockNumber, AccountId, Balance, AssetId> {
+    /// Returns whether the account owns the game.
+    #[method(name = "ownsGame")]
+    fn owns_game(
+        &self,
+        who: AccountId,
+        publisher: AccountId,
+        game_id: GameId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<bool>;
+
+    /// Returns whether the account has access to the game at the given block number, either by
+    /// owning it or by an active subscription to a plan including it. Defaults to the number of
+    /// the queried block.
+    #[method(name = "hasAccess")]
+    fn has_access(
+        &self,
+        who: AccountId,
+        publisher: AccountId,
+        game_id: GameId,
+        block_number: Option<BlockNumber>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<bool>;
+
+    /// Returns the details of the game, or `None` if the game is not found. The tags are named
+    /// in the given locale.
+    #[method(name = "gameDetails")]
+    fn game_details(
+        &self,
+        publisher: AccountId,
+        game_id: GameId,
+        locale: Option<String>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<GameInfo<AccountId, AssetId>>>;
+
+    /// Returns up to `limit` games of the publisher, continuing after the `start_after` game if
+    /// given. The tags are named in the given locale.
+    #[method(name = "gamesByPublisher")]
+    fn games_by_publisher(
+        &self,
+        publisher: AccountId,
+        start_after: Option<GameId>,
+        limit: Option<u32>,
+        locale: Option<String>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<GameInfo<AccountId, AssetId>>>;
+
+    /// Returns up to `limit` games tagged with all the given tags, continuing after the
+    /// `start_after` game if given. The tags are given and named in the given locale, ignoring
+    /// case.
+    #[method(name = "searchByTag")]
+    fn search_by_tag(
+        &self,
+        tags: Vec<String>,
+        start_after: Option<(AccountId, GameId)>,
+        limit: Option<u32>,
+        locale: Option<String>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<GameInfo<AccountId, AssetId>>>;
+
+    /// Returns the details of the publisher, or `None` if the publisher is not registered.
+    #[method(name = "publisherInfo")]
+    fn publisher_info(
+        &self,
+        publisher: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<PublisherInfo<AccountId>>>;
+
+    /// Returns the deposit held when registering a publisher, as a decimal string.
+    #[method(name = "publisherDeposit")]
+    fn publisher_deposit(&self, at: Option<BlockHash>) -> RpcResult<String>;
+}
+
+/// Error codes of the `liganite` RPC methods.
+pub enum Error {
+    /// The call to the runtime failed.
+    RuntimeError,
+    /// The request is invalid.
+    InvalidRequest,
+}
+
+impl From<Error> for i32 {
+    fn from(e: Error) -> i32 {
+        match e {
+            Error::RuntimeError => 1,
+            Error::InvalidRequest => 2,
+        }
+    }
+}
+
+fn runtime_error(e: impl ToString) -> ErrorObjectOwned {
+    ErrorObject::owned(
+        Error::RuntimeError.into(),
+        "Unable to query the games.",
+        Some(e.to_string()),
+    )
+}
+
+fn invalid_request(message: String) -> ErrorObjectOwned {
+    ErrorObject::owned(Error::InvalidRequest.into(), message, None::<()>)
+}
+
+/// Implementation of the `liganite` RPC methods, calling into the `GamesApi` of the runtime.
+pub struct Liganite<C, Block> {
+    client: Arc<C>,
+    _marker: PhantomData<Block>,
+}
+
+impl<C, Block> Liganite<C, Block> {
+    /// Creates a new instance of the `liganite` RPC methods.
+    pub fn new(client: Arc<C>) -> Self {
+        Self { client, _marker: Default::default() }
+    }
+}
+
+impl<C, Block, AccountId, Balance, AssetId>
+    LiganiteApiServer<<Block as BlockT>::Hash, NumberFor<Block>, AccountId, Balance, AssetId>
+    for Liganite<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: GamesApi<Block, AccountId, Balance, AssetId, NumberFor<Block>>,
+    AccountId: Codec + Clone + Send + Sync + 'static,
+    Balance: Codec + ToString + Send + Sync + 'static,
+    AssetId: Codec + Send + Sync + 'static,
+{
+    fn owns_game(
+        &self,
+        who: AccountId,
+        publisher: AccountId,
+        game_id: GameId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<bool> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.owns_game(at, who, publisher, game_id).map_err(runtime_error)
+    }
+
+    fn has_access(
+        &self,
+        who: AccountId,
+        publisher: AccountId,
+        game_id: GameId,
+        block_number: Option<NumberFor<Block>>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<bool> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let block_number = match block_number {
+            Some(block_number) => block_number,
+            None => self
+                .client
+                .number(at)
+                .map_err(runtime_error)?
+                .ok_or_else(|| runtime_error("The block is not found"))?,
+        };
+
+        api.has_access(at, who, publisher, game_id, block_number).map_err(runtime_error)
+    }
+
+    fn game_details(
+        &self,
+        publisher: AccountId,
+        game_id: GameId,
+        locale: Option<String>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<GameInfo<AccountId, AssetId>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let Some(details) =
+            api.game_details(at, publisher.clone(), game_id).map_err(runtime_error)?
+        else {
+            return Ok(None);
+        };
+        let tag_names = self.tag_names::<AccountId, Balance, AssetId>(at, locale)?;
+
+        Ok(Some(GameInfo::new(publisher, game_id, details, &tag_names)))
+    }
+
+    fn games_by_publisher(
+        &self,
+        publisher: AccountId,
+        start_after: Option<GameId>,
+        limit: Option<u32>,
+        locale: Option<String>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<GameInfo<AccountId, AssetId>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let games = api
+            .games_by_publisher(at, publisher.clone(), start_after, page_size(limit))
+            .map_err(runtime_error)?;
+        let tag_names = self.tag_names::<AccountId, Balance, AssetId>(at, locale)?;
+
+        Ok(games
+            .into_iter()
+            .map(|(game_id, details)| {
+                GameInfo::new(publisher.clone(), game_id, details, &tag_names)
+            })
+            .collect())
+    }
+
+    fn search_by_tag(
+        &self,
+        tags: Vec<String>,
+        start_after: Option<(AccountId, GameId)>,
+        limit: Option<u32>,
+        locale: Option<String>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<GameInfo<AccountId, AssetId>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let tag_names = self.tag_names::<AccountId, Balance, AssetId>(at, locale)?;
+        let tag_ids = tags
+            .iter()
+            .map(|tag| {
+                tag_names
+                    .iter()
+                    .find(|(_, name)| name.eq_ignore_ascii_case(tag))
+                    .map(|(tag_id, _)| *tag_id)
+                    .ok_or_else(|| invalid_request(format!("Unknown tag: {tag}")))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let games = api
+            .search_by_tags(at, tag_ids, start_after, page_size(limit))
+            .map_err(runtime_error)?;
+
+        Ok(games
+            .into_iter()
+            .map(|((publisher, game_id), details)| {
+                GameInfo::new(publisher, game_id, details, &tag_names)
+            })
+            .collect())
+    }
+
+    fn publisher_info(
+        &self,
+        publisher: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<PublisherInfo<AccountId>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let details = api.publisher_details(at, publisher.clone()).map_err(runtime_error)?;
+
+        Ok(details.map(|details| PublisherInfo::new(publisher, details)))
+    }
+
+    fn publisher_deposit(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<String> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.publisher_deposit(at)
+            .map(|deposit| deposit.to_string())
+            .map_err(runtime_error)
+    }
+}
+
+impl<C, Block> Liganite<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block>,
+{
+    /// Returns the names of all the tags in the given locale.
+    fn tag_names<AccountId, Balance, AssetId>(
+        &self,
+        at: <Block as BlockT>::Hash,
+        locale: Option<String>,
+    ) -> RpcResult<BTreeMap<TagId, String>>
+    where
+        C::Api: GamesApi<Block, AccountId, Balance, AssetId, NumberFor<Block>>,
+        AccountId: Codec,
+        Balance: Codec,
+        AssetId: Codec,
+    {
+        let locale = locale.unwrap_or_else(|| DEFAULT_LOCALE.to_string());
+        let locale = Locale::try_from(locale.into_bytes())
+            .map_err(|_| invalid_request("The locale is too long".to_string()))?;
+
+        let tags = self.client.runtime_api().tags_for_locale(at, locale).map_err(runtime_error)?;
+        Ok(tags.into_iter().map(|(tag_id, tag)| (tag_id, types::utf8(&tag))).collect())
+    }
+}
+
+/// Caps the requested number of games to `MAX_PAGE_SIZE`.
+fn page_size(limit: Option<u32>) -> u32 {
+    limit.unwrap_or(MAX_PAGE_SIZE).min(MAX_PAGE_SIZE)
+}
@@ -0,0 +1,287 @@
+//! Human-readable forms of the games, as returned by the RPC.
+
+use std::collections::BTreeMap;
+
+use liganite_primitives::types::{
+    AgeRating, ContentDescriptors, Distribution, GameDetails, GameId, PaymentAsset,
+    PublisherDetails, RegionId, TagId,
+};
+use serde::{Deserialize, Serialize};
+
+/// The names of the content descriptors, in the order of their bits.
+const CONTENT_DESCRIPTORS: [(ContentDescriptors, &str); 8] = [
+    (ContentDescriptors::VIOLENCE, "violence"),
+    (ContentDescriptors::BAD_LANGUAGE, "badLanguage"),
+    (ContentDescriptors::FEAR, "fear"),
+    (ContentDescriptors::SEXUAL_CONTENT, "sexualContent"),
+    (ContentDescriptors::DRUGS, "drugs"),
+    (ContentDescriptors::DISCRIMINATION, "discrimination"),
+    (ContentDescriptors::GAMBLING, "gambling"),
+    (ContentDescriptors::IN_GAME_PURCHASES, "inGamePurchases"),
+];
+
+/// A game and its details.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GameInfo<AccountId, AssetId> {
+    /// The publisher of the game.
+    pub publisher: AccountId,
+    /// The id of the game.
+    pub game_id: GameId,
+    /// The name of the game.
+    pub name: String,
+    /// The tags of the game.
+    pub tags: Vec<NamedTag>,
+    /// The way the game is distributed.
+    pub distribution: DistributionInfo<AssetId>,
+    /// The prices overriding the distribution price for buyers from the given regions.
+    pub regional_prices: Vec<RegionalPrice>,
+    /// The minimum age the game is suitable for, such as "12+", or "unrated".
+    pub age_rating: String,
+    /// The content descriptors explaining the age rating.
+    pub content_descriptors: Vec<String>,
+}
+
+/// A tag along with its name.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NamedTag {
+    /// The id of the tag.
+    pub id: TagId,
+    /// The name of the tag.
+    pub name: String,
+}
+
+/// The way a game is distributed. Prices are decimal strings, and a `None` asset stands for the
+/// native currency.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum DistributionInfo<AssetId> {
+    /// The game is distributed free of charge.
+    Free {
+        /// The CID of the game.
+        cid: String,
+    },
+    /// The game can be purchased instantly.
+    Instant {
+        /// The price of the game.
+        price: String,
+        /// The asset the price is paid in.
+        asset: Option<AssetId>,
+        /// The CID of the game.
+        cid: String,
+    },
+    /// The game is delivered by the publisher once ordered.
+    Individual {
+        /// The price of the game.
+        price: String,
+        /// The asset the price is paid in.
+        asset: Option<AssetId>,
+    },
+    /// The game is sold before its release.
+    PreOrder {
+        /// The price of the game.
+        price: String,
+        /// The asset the price is paid in.
+        asset: Option<AssetId>,
+    },
+}
+
+/// A registered publisher and its details.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PublisherInfo<AccountId> {
+    /// The account of the publisher.
+    pub publisher: AccountId,
+    /// The name of the publisher.
+    pub name: String,
+    /// The URL of the publisher.
+    pub url: String,
+}
+
+/// The price of a game for buyers from a region.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RegionalPrice {
+    /// The region id.
+    pub region: RegionId,
+    /// The price, as a decimal string.
+    pub price: String,
+}
+
+impl<AccountId, AssetId> GameInfo<AccountId, AssetId> {
+    /// Converts the details of the game, looking up the names of its tags in `tag_names`. Tags
+    /// without a name are named by their id.
+    pub fn new<Balance: ToString>(
+        publisher: AccountId,
+        game_id: GameId,
+        details: GameDetails<Balance, AssetId>,
+        tag_names: &BTreeMap<TagId, String>,
+    ) -> Self {
+        let tags = details
+            .tags
+            .iter()
+            .map(|id| NamedTag {
+                id: *id,
+                name: tag_names.get(id).cloned().unwrap_or_else(|| id.to_string()),
+            })
+            .collect();
+        let regional_prices = details
+            .regional_prices
+            .iter()
+            .map(|(region, price)| RegionalPrice { region: *region, price: price.to_string() })
+            .collect();
+        let content_descriptors = CONTENT_DESCRIPTORS
+            .iter()
+            .filter(|(descriptor, _)| details.rating.descriptors.contains(*descriptor))
+            .map(|(_, name)| name.to_string())
+            .collect();
+
+        GameInfo {
+            publisher,
+            game_id,
+            name: utf8(&details.name),
+            tags,
+            distribution: details.distribution.into(),
+            regional_prices,
+            age_rating: age_rating(details.rating.age).to_string(),
+            content_descriptors,
+        }
+    }
+}
+
+impl<AccountId> PublisherInfo<AccountId> {
+    /// Converts the details of the publisher.
+    pub fn new(publisher: AccountId, details: PublisherDetails) -> Self {
+        PublisherInfo { publisher, name: utf8(&details.name), url: utf8(&details.url) }
+    }
+}
+
+impl<Balance: ToString, AssetId> From<Distribution<Balance, AssetId>>
+    for DistributionInfo<AssetId>
+{
+    fn from(distribution: Distribution<Balance, AssetId>) -> Self {
+        match distribution {
+            Distribution::Free { cid } => DistributionInfo::Free { cid: utf8(&cid) },
+            Distribution::Instant { price, asset, cid } => DistributionInfo::Instant {
+                price: price.to_string(),
+                asset: asset_id(asset),
+                cid: utf8(&cid),
+            },
+            Distribution::Individual { price, asset } => {
+                DistributionInfo::Individual { price: price.to_string(), asset: asset_id(asset) }
+            },
+            Distribution::PreOrder { price, asset } => {
+                DistributionInfo::PreOrder { price: price.to_string(), asset: asset_id(asset) }
+            },
+        }
+    }
+}
+
+/// Decodes the bytes as UTF-8, replacing invalid sequences.
+pub fn utf8(bytes: &[u8]) -> String {
+    String::from_utf8_lossy(bytes).into_owned()
+}
+
+fn asset_id<AssetId>(asset: PaymentAsset<AssetId>) -> Option<AssetId> {
+    match asset {
+        PaymentAsset::Native => None,
+        PaymentAsset::Asset(asset_id) => Some(asset_id),
+    }
+}
+
+fn age_rating(age: AgeRating) -> &'static str {
+    match age {
+        AgeRating::Age3 => "3+",
+        AgeRating::Age7 => "7+",
+        AgeRating::Age12 => "12+",
+        AgeRating::Age16 => "16+",
+        AgeRating::Age18 => "18+",
+        AgeRating::Unrated => "unrated",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use liganite_primitives::{testing::bounded_vec, types::ContentRating};
+    use serde_json::json;
+
+    #[test]
+    fn test_game_info() {
+        let details = GameDetails {
+            name: bounded_vec("Café Racer".as_bytes()),
+            tags: bounded_vec(&[0, 6, 999]),
+            distribution: Distribution::Instant {
+                price: 340_282_366_920_938_463_463_374_607_431_768_211_455u128,
+                asset: PaymentAsset::Asset(7u32),
+                cid: bounded_vec(b"QmYwAPJzv5CZsnAztbCxjRMoa6zFzFG8pGzLFZxojtL8MX"),
+            },
+            regional_prices: bounded_vec(&[(3, 1000)]),
+            rating: ContentRating {
+                age: AgeRating::Age12,
+                descriptors: ContentDescriptors::VIOLENCE.union(ContentDescriptors::GAMBLING),
+            },
+        };
+        let tag_names = BTreeMap::from([(0, "Action".to_string()), (6, "RPG".to_string())]);
+
+        let info = GameInfo::new("publisher", 1, details, &tag_names);
+
+        assert_eq!(
+            serde_json::to_value(info).unwrap(),
+            json!({
+                "publisher": "publisher",
+                "gameId": 1,
+                "name": "Café Racer",
+                "tags": [
+                    { "id": 0, "name": "Action" },
+                    { "id": 6, "name": "RPG" },
+                    { "id": 999, "name": "999" },
+                ],
+                "distribution": {
+                    "kind": "instant",
+                    "price": "340282366920938463463374607431768211455",
+                    "asset": 7,
+                    "cid": "QmYwAPJzv5CZsnAztbCxjRMoa6zFzFG8pGzLFZxojtL8MX",
+                },
+                "regionalPrices": [{ "region": 3, "price": "1000" }],
+                "ageRating": "12+",
+                "contentDescriptors": ["violence", "gambling"],
+            })
+        );
+    }
+
+    #[test]
+    fn test_publisher_info() {
+        let details = PublisherDetails {
+            name: bounded_vec("Éditions Ludiques".as_bytes()),
+            url: bounded_vec(b"https://example.com"),
+        };
+
+        let info = PublisherInfo::new("publisher", details);
+
+        assert_eq!(
+            serde_json::to_value(info).unwrap(),
+            json!({
+                "publisher": "publisher",
+                "name": "Éditions Ludiques",
+                "url": "https://example.com",
+            })
+        );
+    }
+
+    #[test]
+    fn test_distribution_info() {
+        let free: DistributionInfo<u32> =
+            Distribution::<u64, u32>::Free { cid: bounded_vec(b"bafkreihdwdcefgh4dqkjv67u") }
+                .into();
+        assert_eq!(
+            serde_json::to_value(free).unwrap(),
+            json!({ "kind": "free", "cid": "bafkreihdwdcefgh4dqkjv67u" })
+        );
+
+        let pre_order: DistributionInfo<u32> =
+            Distribution::PreOrder { price: 5u64, asset: PaymentAsset::Native }.into();
+        assert_eq!(
+            serde_json::to_value(pre_order).unwrap(),
+            json!({ "kind": "preOrder", "price": "5", "asset": null })
+        );
+    }
+}
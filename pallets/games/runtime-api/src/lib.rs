@@ -0,0 +1,155 @@
+//! Runtime API definition for the games pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use liganite_primitives::types::{
+    AgeRating, BuildManifest, ContentDescriptors, GameDetails, GameId, Locale, OrderDetails,
+    PaymentAsset, PublisherDetails, Receipt, SalesStats, Tag, TagCategory, TagId, TagProposal,
+    TagProposalId, WishlistEntry,
+};
+use scale_codec::Codec;
+
+sp_api::decl_runtime_apis! {
+    /// The API to query the games published on the network.
+    #[api_version(13)]
+    pub trait GamesApi<AccountId, Balance, AssetId, BlockNumber>
+    where
+        AccountId: Codec,
+        Balance: Codec,
+        AssetId: Codec,
+        BlockNumber: Codec,
+    {
+        /// Returns the details of the game, or `None` if the game is not found.
+        #[api_version(8)]
+        fn game_details(
+            publisher: AccountId,
+            game_id: GameId,
+        ) -> Option<GameDetails<Balance, AssetId>>;
+
+        /// Returns up to `limit` games of the publisher along with their details, continuing
+        /// after the `start_after` game if given.
+        #[api_version(8)]
+        fn games_by_publisher(
+            publisher: AccountId,
+            start_after: Option<GameId>,
+            limit: u32,
+        ) -> Vec<(GameId, GameDetails<Balance, AssetId>)>;
+
+        /// Returns whether the account owns the game.
+        #[api_version(8)]
+        fn owns_game(who: AccountId, publisher: AccountId, game_id: GameId) -> bool;
+
+        /// Returns whether the account has access to the game at the given block, either by
+        /// owning it or by an active subscription to a plan including it.
+        #[api_version(12)]
+        fn has_access(
+            who: AccountId,
+            publisher: AccountId,
+            game_id: GameId,
+            at: BlockNumber,
+        ) -> bool;
+
+        /// Returns the games owned by the account.
+        #[api_version(8)]
+        fn owned_games(who: AccountId) -> Vec<(AccountId, GameId)>;
+
+        /// Returns the orders waiting to be fulfilled by the publisher, along with their buyers.
+        #[api_version(8)]
+        fn pending_orders(
+            publisher: AccountId,
+        ) -> Vec<(GameId, AccountId, OrderDetails<Balance, AssetId>)>;
+
+        /// Returns the build manifest of the game, or `None` if the game has none.
+        #[api_version(11)]
+        fn game_build(publisher: AccountId, game_id: GameId) -> Option<BuildManifest>;
+
+        /// Returns the sales statistics of the game, by payment asset.
+        #[api_version(10)]
+        fn game_sales(
+            publisher: AccountId,
+            game_id: GameId,
+        ) -> Vec<(PaymentAsset<AssetId>, SalesStats<Balance>)>;
+
+        /// Returns the sales statistics of the publisher games, by payment asset.
+        #[api_version(10)]
+        fn publisher_sales(
+            publisher: AccountId,
+        ) -> Vec<(PaymentAsset<AssetId>, SalesStats<Balance>)>;
+
+        /// Returns the purchase receipts of the buyer, along with their publishers and game ids.
+        #[api_version(9)]
+        fn receipts_by_buyer(
+            buyer: AccountId,
+        ) -> Vec<((AccountId, GameId), Receipt<Balance, AssetId, BlockNumber>)>;
+
+        /// Returns up to `limit` purchase receipts of the publisher games, along with their game
+        /// ids and buyers, continuing after the `start_after` purchase if given.
+        #[api_version(9)]
+        fn receipts_by_publisher(
+            publisher: AccountId,
+            start_after: Option<(GameId, AccountId)>,
+            limit: u32,
+        ) -> Vec<(GameId, AccountId, Receipt<Balance, AssetId, BlockNumber>)>;
+
+        /// Returns up to `limit` games suitable for the given age rating and having none of the
+        /// excluded content descriptors, continuing after the `start_after` game if given.
+        fn games_by_rating(
+            max_age: AgeRating,
+            excluded: ContentDescriptors,
+            start_after: Option<(AccountId, GameId)>,
+            limit: u32,
+        ) -> Vec<(AccountId, GameId)>;
+
+        /// Returns up to `limit` games tagged with all the given tags, continuing after the
+        /// `start_after` game if given.
+        #[api_version(5)]
+        fn games_by_tags(
+            tags: Vec<TagId>,
+            start_after: Option<(AccountId, GameId)>,
+            limit: u32,
+        ) -> Vec<(AccountId, GameId)>;
+
+        /// Returns up to `limit` games tagged with all the given tags along with their details,
+        /// continuing after the `start_after` game if given.
+        #[api_version(5)]
+        fn search_by_tags(
+            tags: Vec<TagId>,
+            start_after: Option<(AccountId, GameId)>,
+            limit: u32,
+        ) -> Vec<((AccountId, GameId), GameDetails<Balance, AssetId>)>;
+
+        /// Returns up to `limit` tags with the most community votes on the game, along with
+        /// their votes.
+        #[api_version(6)]
+        fn top_tags(publisher: AccountId, game_id: GameId, limit: u32) -> Vec<(TagId, u32)>;
+
+        /// Returns the tags of the given category.
+        #[api_version(2)]
+        fn tags_by_category(category: TagCategory) -> Vec<(TagId, Tag)>;
+
+        /// Returns all the tags with their names in the given locale, falling back to the
+        /// default name of the tags without a translation.
+        #[api_version(3)]
+        fn tags_for_locale(locale: Locale) -> Vec<(TagId, Tag)>;
+
+        /// Returns the tags proposed by publishers and waiting to be curated.
+        #[api_version(4)]
+        fn tag_proposals() -> Vec<(TagProposalId, TagProposal<AccountId, Balance, BlockNumber>)>;
+
+        /// Returns the games in the wishlist of the account, along with the price currently
+        /// charged for them.
+        #[api_version(7)]
+        fn wishlist(who: AccountId) -> Vec<WishlistEntry<AccountId, Balance, AssetId>>;
+
+        /// Returns the details of the publisher, or `None` if the publisher is not registered.
+        #[api_version(13)]
+        fn publisher_details(publisher: AccountId) -> Option<PublisherDetails>;
+
+        /// Returns the deposit held when registering a publisher.
+        #[api_version(13)]
+        fn publisher_deposit() -> Balance;
+    }
+}
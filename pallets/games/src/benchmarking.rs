@@ -5,10 +5,16 @@ use super::*;
 #[allow(unused)]
 use crate::Pallet as Games;
 use frame_benchmarking::v2::*;
-use frame_support::sp_runtime::traits::{Bounded, CheckedDiv};
+use frame_support::sp_runtime::{
+    traits::{Bounded, CheckedDiv, One},
+    RuntimeAppPublic,
+};
 use frame_system::RawOrigin;
 use liganite_primitives::{
-    testing::bounded_vec, types::PublisherDetails, MAX_NAME_SIZE, MAX_TAGS_PER_GAME,
+    testing::bounded_vec,
+    types::{BuildManifest, PublisherDetails, RegionalPrices},
+    MAX_GAMES_PER_PLAN, MAX_LOCALE_SIZE, MAX_NAME_SIZE, MAX_REGIONAL_PRICES, MAX_TAGS_PER_GAME,
+    MAX_TAG_SIZE,
 };
 use scale_info::prelude::{vec, vec::Vec};
 
@@ -25,6 +31,69 @@ fn prefund_account<T: Config>(account: &T::AccountId) {
     T::Currency::set_balance(account, CurrencyOf::<T>::from(initial_balance));
 }
 
+/// The region charged in the benchmarks, placed last in the price table.
+const REGION: RegionId = MAX_REGIONAL_PRICES as RegionId - 1;
+
+fn regional_prices<T: Config>(price: CurrencyOf<T>) -> RegionalPrices<CurrencyOf<T>> {
+    let prices = (0..MAX_REGIONAL_PRICES as RegionId).map(|region| (region, price));
+    bounded_vec(&prices.collect::<Vec<_>>())
+}
+
+/// Stores a tag proposal of the account, holding its deposit.
+fn insert_tag_proposal<T: Config>(
+    proposal_id: TagProposalId,
+    proposer: &T::AccountId,
+    expires_at: BlockNumberFor<T>,
+) {
+    prefund_account::<T>(proposer);
+    let deposit = T::TagProposalDeposit::get();
+    T::Currency::hold(&HoldReason::TagProposal.into(), proposer, deposit)
+        .expect("account is funded; qed");
+    TagTaxonomy::<T>::insert(
+        TagId::default(),
+        TagInfo { category: TagCategory::TopLevelGenre, parent: None },
+    );
+    let proposal = TagProposal {
+        proposer: proposer.clone(),
+        tag: bounded_vec(&vec![b'a'; MAX_TAG_SIZE as usize]),
+        info: TagInfo { category: TagCategory::Genre, parent: Some(TagId::default()) },
+        deposit,
+        expires_at,
+    };
+    TagProposals::<T>::insert(proposal_id, proposal);
+}
+
+/// Generates a key signing the availability reports.
+fn generate_reporter_key<T: Config>() -> T::Public {
+    type Crypto<T> = <T as Config>::AuthorityId;
+    let key =
+        <Crypto<T> as AppCrypto<T::Public, T::Signature>>::RuntimeAppPublic::generate_pair(None);
+    let public: <Crypto<T> as AppCrypto<T::Public, T::Signature>>::GenericPublic = key.into();
+    public.into()
+}
+
+/// Holds the receipt deposit of a purchase from the buyer, returning its amount.
+fn hold_receipt_deposit<T: Config>(buyer: &T::AccountId) -> CurrencyOf<T> {
+    let deposit = T::ReceiptDeposit::get();
+    T::Currency::hold(&HoldReason::ReceiptDeposit.into(), buyer, deposit)
+        .expect("buyer is funded; qed");
+    deposit
+}
+
+fn discount<T: Config>() -> DiscountOf<T> {
+    Discount { percent: Percent::from_percent(10), ends_at: BlockNumberFor::<T>::max_value() }
+}
+
+/// Stores a full wishlist of the account, with the given game placed last.
+fn insert_full_wishlist<T: Config>(who: &T::AccountId, game: GlobalGameId<T>) {
+    let mut games = (1..T::MaxWishlistSize::get())
+        .map(|index| (get_account::<T>(index), GameId::default()))
+        .collect::<Vec<_>>();
+    games.push(game.clone());
+    Wishlists::<T>::insert(who, BoundedVec::truncate_from(games));
+    WishlistCounts::<T>::insert(game, 1);
+}
+
 #[benchmarks]
 mod benchmarks {
     use super::*;
@@ -37,6 +106,7 @@ mod benchmarks {
 
         for tag in tags.iter() {
             Tags::<T>::insert(*tag, Tag::default());
+            TagTaxonomy::<T>::insert(*tag, TagInfo::default());
         }
 
         let publisher = whitelisted_caller();
@@ -46,13 +116,17 @@ mod benchmarks {
         );
 
         let game_id = 1;
+        let price = CurrencyOf::<T>::from(1_000u32);
         let details = GameDetails {
             name,
             tags,
             distribution: Distribution::Instant {
-                price: CurrencyOf::<T>::from(1_000u32),
-                cid: bounded_vec(b"bafkrgqe3nmefcemf5kx23jw4mpkux4kswm4umtvwmc2j6cf3ftvkv7flwpfrwe7nvlhyzvvn7wtfhec5nkkwxv3hvzjivkw6yv3f3og5edhcldy"),
+                price,
+                asset: PaymentAsset::Native,
+                cid: bounded_vec(b"bagbaihragmzc2vwmec24nt3qaaozuk5wh43n5izfer6chasuzd7z4iuwnhmq"),
             },
+            regional_prices: regional_prices::<T>(price),
+            rating: Default::default(),
         };
 
         #[extrinsic_call]
@@ -69,17 +143,22 @@ mod benchmarks {
             name: bounded_vec(&vec![b'a'; MAX_NAME_SIZE as usize]),
             tags: bounded_vec(&vec![TagId::default(); MAX_TAGS_PER_GAME as usize]),
             distribution: Distribution::Free {
-                cid: bounded_vec(b"bafkrgqe3nmefcemf5kx23jw4mpkux4kswm4umtvwmc2j6cf3ftvkv7flwpfrwe7nvlhyzvvn7wtfhec5nkkwxv3hvzjivkw6yv3f3og5edhcldy")
+                cid: bounded_vec(b"bagbaihragmzc2vwmec24nt3qaaozuk5wh43n5izfer6chasuzd7z4iuwnhmq"),
             },
+            regional_prices: Default::default(),
+            rating: Default::default(),
         };
         PublishedGames::<T>::insert(&publisher, game_id, game_details);
+        GameDiscounts::<T>::insert(&publisher, game_id, discount::<T>());
         let buyer = whitelisted_caller();
         prefund_account::<T>(&buyer);
+        insert_full_wishlist::<T>(&buyer, (publisher.clone(), game_id));
 
         #[extrinsic_call]
-        game_buy(RawOrigin::Signed(buyer.clone()), publisher.clone(), game_id);
+        game_buy(RawOrigin::Signed(buyer.clone()), publisher.clone(), game_id, None);
 
         assert_eq!(OwnedGames::<T>::get(&buyer, (&publisher, game_id)), Some(()));
+        assert!(Receipts::<T>::contains_key(&buyer, (&publisher, game_id)));
     }
 
     #[benchmark]
@@ -92,15 +171,20 @@ mod benchmarks {
             tags: bounded_vec(&vec![TagId::default(); MAX_TAGS_PER_GAME as usize]),
             distribution: Distribution::Instant {
                 price,
-                cid: bounded_vec(b"bafkrgqe3nmefcemf5kx23jw4mpkux4kswm4umtvwmc2j6cf3ftvkv7flwpfrwe7nvlhyzvvn7wtfhec5nkkwxv3hvzjivkw6yv3f3og5edhcldy")
+                asset: PaymentAsset::Native,
+                cid: bounded_vec(b"bagbaihragmzc2vwmec24nt3qaaozuk5wh43n5izfer6chasuzd7z4iuwnhmq"),
             },
+            regional_prices: regional_prices::<T>(price),
+            rating: Default::default(),
         };
         PublishedGames::<T>::insert(&publisher, game_id, game_details);
+        GameDiscounts::<T>::insert(&publisher, game_id, discount::<T>());
         let buyer = whitelisted_caller();
         prefund_account::<T>(&buyer);
+        insert_full_wishlist::<T>(&buyer, (publisher.clone(), game_id));
 
         #[extrinsic_call]
-        game_buy(RawOrigin::Signed(buyer.clone()), publisher.clone(), game_id);
+        game_buy(RawOrigin::Signed(buyer.clone()), publisher.clone(), game_id, Some(REGION));
 
         assert_eq!(OwnedGames::<T>::get(&buyer, (&publisher, game_id)), Some(()));
     }
@@ -113,16 +197,25 @@ mod benchmarks {
         let game_details = GameDetails {
             name: bounded_vec(&vec![b'a'; MAX_NAME_SIZE as usize]),
             tags: bounded_vec(&vec![TagId::default(); MAX_TAGS_PER_GAME as usize]),
-            distribution: Distribution::Individual { price },
+            distribution: Distribution::Individual { price, asset: PaymentAsset::Native },
+            regional_prices: regional_prices::<T>(price),
+            rating: Default::default(),
         };
         PublishedGames::<T>::insert(&publisher, game_id, game_details);
+        GameDiscounts::<T>::insert(&publisher, game_id, discount::<T>());
         let buyer = whitelisted_caller();
         prefund_account::<T>(&buyer);
+        insert_full_wishlist::<T>(&buyer, (publisher.clone(), game_id));
 
         #[extrinsic_call]
-        game_buy(RawOrigin::Signed(buyer.clone()), publisher.clone(), game_id);
+        game_buy(RawOrigin::Signed(buyer.clone()), publisher.clone(), game_id, Some(REGION));
 
-        let expected = OrderDetails { deposit: price };
+        let expected = OrderDetails {
+            deposit: price,
+            asset: PaymentAsset::Native,
+            region: Some(REGION),
+            receipt_deposit: T::ReceiptDeposit::get(),
+        };
         assert_eq!(BuyerOrders::<T>::get(&buyer, (&publisher, game_id)), Some(expected));
         assert_eq!(PublisherOrders::<T>::get(&publisher, game_id), Some(buyer));
     }
@@ -135,13 +228,25 @@ mod benchmarks {
         let game_details = GameDetails {
             name: bounded_vec(&vec![b'a'; MAX_NAME_SIZE as usize]),
             tags: bounded_vec(&vec![TagId::default(); MAX_TAGS_PER_GAME as usize]),
-            distribution: Distribution::Individual { price },
+            distribution: Distribution::Individual { price, asset: PaymentAsset::Native },
+            regional_prices: Default::default(),
+            rating: Default::default(),
         };
         PublishedGames::<T>::insert(&publisher, game_id, game_details);
 
         let buyer = whitelisted_caller();
         prefund_account::<T>(&buyer);
-        BuyerOrders::<T>::insert(&buyer, (&publisher, game_id), &OrderDetails { deposit: price });
+        let receipt_deposit = hold_receipt_deposit::<T>(&buyer);
+        BuyerOrders::<T>::insert(
+            &buyer,
+            (&publisher, game_id),
+            &OrderDetails {
+                deposit: price,
+                asset: PaymentAsset::Native,
+                region: None,
+                receipt_deposit,
+            },
+        );
         PublisherOrders::<T>::insert(&publisher, game_id, &buyer);
 
         #[extrinsic_call]
@@ -161,10 +266,23 @@ mod benchmarks {
         let game_details = GameDetails {
             name: bounded_vec(&vec![b'a'; MAX_NAME_SIZE as usize]),
             tags: bounded_vec(&vec![TagId::default(); MAX_TAGS_PER_GAME as usize]),
-            distribution: Distribution::Individual { price },
+            distribution: Distribution::Individual { price, asset: PaymentAsset::Native },
+            regional_prices: Default::default(),
+            rating: Default::default(),
         };
         PublishedGames::<T>::insert(&publisher, game_id, game_details);
-        BuyerOrders::<T>::insert(&buyer, (&publisher, game_id), &OrderDetails { deposit: price });
+        prefund_account::<T>(&buyer);
+        let receipt_deposit = hold_receipt_deposit::<T>(&buyer);
+        BuyerOrders::<T>::insert(
+            &buyer,
+            (&publisher, game_id),
+            &OrderDetails {
+                deposit: price,
+                asset: PaymentAsset::Native,
+                region: None,
+                receipt_deposit,
+            },
+        );
         PublisherOrders::<T>::insert(&publisher, game_id, &buyer);
 
         #[extrinsic_call]
@@ -173,6 +291,646 @@ mod benchmarks {
         assert_eq!(BuyerOrders::<T>::get(&buyer, (&publisher, game_id)), None);
         assert_eq!(PublisherOrders::<T>::get(&publisher, game_id), None);
         assert_eq!(OwnedGames::<T>::get(&buyer, (&publisher, game_id)), Some(()));
+        assert!(Receipts::<T>::contains_key(&buyer, (&publisher, game_id)));
+    }
+
+    #[benchmark]
+    fn preorder_place() {
+        let publisher = get_account::<T>(0);
+        let game_id = 10;
+        let price = CurrencyOf::<T>::from(2_000_000_000u32);
+        let game_details = GameDetails {
+            name: bounded_vec(&vec![b'a'; MAX_NAME_SIZE as usize]),
+            tags: bounded_vec(&vec![TagId::default(); MAX_TAGS_PER_GAME as usize]),
+            distribution: Distribution::PreOrder { price, asset: PaymentAsset::Native },
+            regional_prices: regional_prices::<T>(price),
+            rating: Default::default(),
+        };
+        PublishedGames::<T>::insert(&publisher, game_id, game_details);
+        PreOrderSchedules::<T>::insert(
+            (&publisher, game_id),
+            PreOrderSchedule { deadline: T::PreOrderDeadline::get(), release: None },
+        );
+        GameDiscounts::<T>::insert(&publisher, game_id, discount::<T>());
+        let buyer = whitelisted_caller();
+        prefund_account::<T>(&buyer);
+        insert_full_wishlist::<T>(&buyer, (publisher.clone(), game_id));
+
+        #[extrinsic_call]
+        game_buy(RawOrigin::Signed(buyer.clone()), publisher.clone(), game_id, Some(REGION));
+
+        let expected = OrderDetails {
+            deposit: price,
+            asset: PaymentAsset::Native,
+            region: Some(REGION),
+            receipt_deposit: T::ReceiptDeposit::get(),
+        };
+        assert_eq!(PreOrders::<T>::get((&publisher, game_id), &buyer), Some(expected));
+    }
+
+    #[benchmark]
+    fn preorder_release() {
+        let publisher: T::AccountId = whitelisted_caller();
+        let game_id = 10;
+        let now = frame_system::Pallet::<T>::block_number();
+        let at = now + 2u32.into();
+        let cid: Cid =
+            bounded_vec(b"bagbaihragmzc2vwmec24nt3qaaozuk5wh43n5izfer6chasuzd7z4iuwnhmq");
+        // Worst case: a previous release is replaced
+        let previous = PreOrderRelease { at: now + One::one(), cid: cid.clone() };
+        PreOrderAgenda::<T>::insert(
+            previous.at,
+            BoundedVec::truncate_from(vec![(publisher.clone(), game_id)]),
+        );
+        PreOrderSchedules::<T>::insert(
+            (&publisher, game_id),
+            PreOrderSchedule { deadline: at + One::one(), release: Some(previous) },
+        );
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(publisher.clone()), game_id, at, cid.clone());
+
+        let release = PreOrderSchedules::<T>::get((&publisher, game_id)).and_then(|s| s.release);
+        assert_eq!(release, Some(PreOrderRelease { at, cid }));
+    }
+
+    #[benchmark]
+    fn preorder_cancel() {
+        let publisher: T::AccountId = whitelisted_caller();
+        let game_id = 10;
+        let price = CurrencyOf::<T>::from(2_000_000_000u32);
+        let game_details = GameDetails {
+            name: bounded_vec(&vec![b'a'; MAX_NAME_SIZE as usize]),
+            tags: bounded_vec(&vec![TagId::default(); MAX_TAGS_PER_GAME as usize]),
+            distribution: Distribution::PreOrder { price, asset: PaymentAsset::Native },
+            regional_prices: regional_prices::<T>(price),
+            rating: Default::default(),
+        };
+        PublishedGames::<T>::insert(&publisher, game_id, game_details);
+        GameDiscounts::<T>::insert(&publisher, game_id, discount::<T>());
+        PreOrderSchedules::<T>::insert(
+            (&publisher, game_id),
+            PreOrderSchedule { deadline: T::PreOrderDeadline::get(), release: None },
+        );
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(publisher.clone()), game_id);
+
+        assert_eq!(PreOrderSchedules::<T>::get((&publisher, game_id)), None);
+        assert_eq!(PublishedGames::<T>::get(&publisher, game_id), None);
+        assert_eq!(
+            PreOrderSettlements::<T>::get((&publisher, game_id)),
+            Some(PreOrderSettlement::Refund)
+        );
+    }
+
+    #[benchmark]
+    fn preorder_settle() {
+        let publisher = get_account::<T>(0);
+        prefund_account::<T>(&publisher);
+        let game_id = 10;
+        let game = (publisher.clone(), game_id);
+        let buyer = get_account::<T>(1);
+        prefund_account::<T>(&buyer);
+        let price = CurrencyOf::<T>::from(2_000_000_000u32);
+        T::Currency::hold(&HoldReason::GamePayment.into(), &buyer, price)
+            .expect("buyer is funded; qed");
+        let receipt_deposit = hold_receipt_deposit::<T>(&buyer);
+        let order = OrderDetails {
+            deposit: price,
+            asset: PaymentAsset::Native,
+            region: None,
+            receipt_deposit,
+        };
+        let settlement = PreOrderSettlement::Release {
+            cid: bounded_vec(b"bagbaihragmzc2vwmec24nt3qaaozuk5wh43n5izfer6chasuzd7z4iuwnhmq"),
+        };
+
+        #[block]
+        {
+            Games::<T>::settle_preorder(&game, &settlement, buyer.clone(), order);
+        }
+
+        assert_eq!(OwnedGames::<T>::get(&buyer, &game), Some(()));
+        assert!(Receipts::<T>::contains_key(&buyer, &game));
+    }
+
+    #[benchmark]
+    fn preorder_claim() {
+        let publisher = get_account::<T>(0);
+        prefund_account::<T>(&publisher);
+        let game_id = 10;
+        let game = (publisher.clone(), game_id);
+        let buyer: T::AccountId = whitelisted_caller();
+        prefund_account::<T>(&buyer);
+        let price = CurrencyOf::<T>::from(2_000_000_000u32);
+        T::Currency::hold(&HoldReason::GamePayment.into(), &buyer, price)
+            .expect("buyer is funded; qed");
+        let receipt_deposit = hold_receipt_deposit::<T>(&buyer);
+        let order = OrderDetails {
+            deposit: price,
+            asset: PaymentAsset::Native,
+            region: None,
+            receipt_deposit,
+        };
+        PreOrders::<T>::insert(&game, &buyer, order);
+        let settlement = PreOrderSettlement::Release {
+            cid: bounded_vec(b"bagbaihragmzc2vwmec24nt3qaaozuk5wh43n5izfer6chasuzd7z4iuwnhmq"),
+        };
+        PreOrderSettlements::<T>::insert(&game, settlement);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(buyer.clone()), publisher, game_id);
+
+        assert_eq!(PreOrders::<T>::get(&game, &buyer), None);
+        assert_eq!(OwnedGames::<T>::get(&buyer, &game), Some(()));
+        assert!(Receipts::<T>::contains_key(&buyer, &game));
+    }
+
+    #[benchmark]
+    fn preorder_schedule(n: Linear<0, { T::MaxPreOrdersScheduledPerBlock::get() }>) {
+        let at = frame_system::Pallet::<T>::block_number() + One::one();
+        let price = CurrencyOf::<T>::from(2_000_000_000u32);
+        for i in 0..n {
+            let publisher = get_account::<T>(i);
+            let game_id = i as GameId;
+            let game_details = GameDetails {
+                name: bounded_vec(&vec![b'a'; MAX_NAME_SIZE as usize]),
+                tags: bounded_vec(&vec![TagId::default(); MAX_TAGS_PER_GAME as usize]),
+                distribution: Distribution::PreOrder { price, asset: PaymentAsset::Native },
+                regional_prices: Default::default(),
+                rating: Default::default(),
+            };
+            PublishedGames::<T>::insert(&publisher, game_id, game_details);
+            GameDiscounts::<T>::insert(&publisher, game_id, discount::<T>());
+            // An expiry withdraws the game, which costs more than a release
+            PreOrderSchedules::<T>::insert(
+                (&publisher, game_id),
+                PreOrderSchedule { deadline: at, release: None },
+            );
+            Games::<T>::schedule_preorder(at, (publisher, game_id))
+                .expect("agenda is not full; qed");
+        }
+
+        #[block]
+        {
+            Games::<T>::on_initialize(at);
+        }
+
+        assert_eq!(PreOrderSettlements::<T>::iter().count(), n as usize);
+    }
+
+    #[benchmark]
+    fn plan_add(n: Linear<1, MAX_GAMES_PER_PLAN>) {
+        let publisher: T::AccountId = whitelisted_caller();
+        T::PublisherManager::insert_publisher(
+            &publisher,
+            &PublisherDetails {
+                name: bounded_vec(b"Publisher"),
+                url: bounded_vec(b"https://publisher.com"),
+            },
+        );
+        let games = (0..n as GameId).collect::<Vec<_>>();
+        for game_id in games.iter() {
+            let game_details = GameDetails {
+                name: bounded_vec(&vec![b'a'; MAX_NAME_SIZE as usize]),
+                tags: Default::default(),
+                distribution: Distribution::Individual {
+                    price: CurrencyOf::<T>::from(1_000u32),
+                    asset: PaymentAsset::Native,
+                },
+                regional_prices: Default::default(),
+                rating: Default::default(),
+            };
+            PublishedGames::<T>::insert(&publisher, game_id, game_details);
+        }
+
+        let plan_id = 1;
+        let details = SubscriptionPlan {
+            name: bounded_vec(&vec![b'a'; MAX_NAME_SIZE as usize]),
+            price: CurrencyOf::<T>::from(1_000u32),
+            asset: PaymentAsset::Native,
+            period: One::one(),
+            games: bounded_vec(&games),
+        };
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(publisher.clone()), plan_id, details.clone());
+
+        assert_eq!(SubscriptionPlans::<T>::get(&publisher, plan_id), Some(details));
+    }
+
+    #[benchmark]
+    fn subscription_buy() {
+        let publisher = get_account::<T>(0);
+        let plan_id = 1;
+        let details = SubscriptionPlan {
+            name: bounded_vec(&vec![b'a'; MAX_NAME_SIZE as usize]),
+            price: CurrencyOf::<T>::from(2_000_000_000u32),
+            asset: PaymentAsset::Native,
+            period: One::one(),
+            games: bounded_vec(&vec![GameId::default(); MAX_GAMES_PER_PLAN as usize]),
+        };
+        SubscriptionPlans::<T>::insert(&publisher, plan_id, details);
+        let buyer = whitelisted_caller();
+        prefund_account::<T>(&buyer);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(buyer.clone()), publisher.clone(), plan_id);
+
+        assert!(Subscriptions::<T>::get(&buyer, (&publisher, plan_id)).is_some());
+    }
+
+    #[benchmark]
+    fn tag_add() -> Result<(), BenchmarkError> {
+        let origin =
+            T::TagOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let tag_id = NextTagId::<T>::get();
+        let parent = TagId::default();
+        TagTaxonomy::<T>::insert(
+            parent,
+            TagInfo { category: TagCategory::TopLevelGenre, parent: None },
+        );
+        let tag: Tag = bounded_vec(&vec![b'a'; MAX_TAG_SIZE as usize]);
+        let info = TagInfo { category: TagCategory::Genre, parent: Some(parent) };
+
+        #[extrinsic_call]
+        _(origin, tag.clone(), info);
+
+        assert_eq!(Tags::<T>::get(tag_id), Some(tag));
+        assert_eq!(TagTaxonomy::<T>::get(tag_id), Some(info));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn tag_rename() -> Result<(), BenchmarkError> {
+        let origin =
+            T::TagOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let tag_id = TagId::default();
+        Tags::<T>::insert(tag_id, Tag::default());
+        let tag: Tag = bounded_vec(&vec![b'a'; MAX_TAG_SIZE as usize]);
+
+        #[extrinsic_call]
+        _(origin, tag_id, tag.clone());
+
+        assert_eq!(Tags::<T>::get(tag_id), Some(tag));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn tag_deprecate() -> Result<(), BenchmarkError> {
+        let origin =
+            T::TagOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let tag_id = TagId::default();
+        Tags::<T>::insert(tag_id, Tag::default());
+
+        #[extrinsic_call]
+        _(origin, tag_id);
+
+        assert!(DeprecatedTags::<T>::contains_key(tag_id));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn tag_classify() -> Result<(), BenchmarkError> {
+        let origin =
+            T::TagOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let parent = TagId::default();
+        TagTaxonomy::<T>::insert(
+            parent,
+            TagInfo { category: TagCategory::TopLevelGenre, parent: None },
+        );
+        // Worst case: the tag changes category and leaves another parent
+        let old_parent = parent + 1;
+        TagTaxonomy::<T>::insert(
+            old_parent,
+            TagInfo { category: TagCategory::Genre, parent: None },
+        );
+        let tag_id = parent + 2;
+        TagTaxonomy::<T>::insert(
+            tag_id,
+            TagInfo { category: TagCategory::SubGenre, parent: Some(old_parent) },
+        );
+        TagChildren::<T>::insert(old_parent, tag_id, ());
+        let info = TagInfo { category: TagCategory::Genre, parent: Some(parent) };
+
+        #[extrinsic_call]
+        _(origin, tag_id, info);
+
+        assert_eq!(TagTaxonomy::<T>::get(tag_id), Some(info));
+        assert!(TagChildren::<T>::contains_key(parent, tag_id));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn tag_translate() -> Result<(), BenchmarkError> {
+        let origin =
+            T::TagOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let tag_id = TagId::default();
+        Tags::<T>::insert(tag_id, Tag::default());
+        let locale: Locale = bounded_vec(&vec![b'a'; MAX_LOCALE_SIZE as usize]);
+        let name: Tag = bounded_vec(&vec![b'a'; MAX_TAG_SIZE as usize]);
+
+        #[extrinsic_call]
+        _(origin, tag_id, locale.clone(), Some(name.clone()));
+
+        assert_eq!(TagTranslations::<T>::get(locale, tag_id), Some(name));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn tag_propose() {
+        let publisher: T::AccountId = whitelisted_caller();
+        T::PublisherManager::insert_publisher(
+            &publisher,
+            &PublisherDetails {
+                name: bounded_vec(b"Publisher"),
+                url: bounded_vec(b"https://publisher.com"),
+            },
+        );
+        prefund_account::<T>(&publisher);
+        let parent = TagId::default();
+        TagTaxonomy::<T>::insert(
+            parent,
+            TagInfo { category: TagCategory::TopLevelGenre, parent: None },
+        );
+        let tag: Tag = bounded_vec(&vec![b'a'; MAX_TAG_SIZE as usize]);
+        let info = TagInfo { category: TagCategory::Genre, parent: Some(parent) };
+        let proposal_id = NextTagProposalId::<T>::get();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(publisher.clone()), tag, info);
+
+        assert!(TagProposals::<T>::contains_key(proposal_id));
+    }
+
+    #[benchmark]
+    fn tag_proposal_approve() -> Result<(), BenchmarkError> {
+        let origin =
+            T::TagCuratorOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let proposer = get_account::<T>(0);
+        let proposal_id = TagProposalId::default();
+        insert_tag_proposal::<T>(proposal_id, &proposer, One::one());
+
+        #[extrinsic_call]
+        _(origin, proposal_id);
+
+        assert!(!TagProposals::<T>::contains_key(proposal_id));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn tag_proposal_reject() -> Result<(), BenchmarkError> {
+        let origin =
+            T::TagCuratorOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let proposer = get_account::<T>(0);
+        let proposal_id = TagProposalId::default();
+        insert_tag_proposal::<T>(proposal_id, &proposer, One::one());
+
+        #[extrinsic_call]
+        _(origin, proposal_id);
+
+        assert!(!TagProposals::<T>::contains_key(proposal_id));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn tag_proposal_expire(n: Linear<0, { T::MaxTagProposalsExpiringPerBlock::get() }>) {
+        let at = frame_system::Pallet::<T>::block_number() + One::one();
+        for i in 0..n {
+            insert_tag_proposal::<T>(i, &get_account::<T>(i), at);
+        }
+        let proposals = (0..n).collect::<Vec<_>>();
+        TagProposalAgenda::<T>::insert(at, BoundedVec::truncate_from(proposals));
+
+        #[block]
+        {
+            Games::<T>::on_initialize(at);
+        }
+
+        assert_eq!(TagProposals::<T>::iter().count(), 0);
+    }
+
+    #[benchmark]
+    fn tag_vote() {
+        let buyer: T::AccountId = whitelisted_caller();
+        let publisher = get_account::<T>(0);
+        let game_id = GameId::default();
+        let game = (publisher.clone(), game_id);
+        OwnedGames::<T>::insert(&buyer, &game, ());
+        let tag_id = TagId::default();
+        TagTaxonomy::<T>::insert(tag_id, TagInfo::default());
+        let tags = (1..T::MaxTagVotesPerGame::get() as TagId).collect::<Vec<_>>();
+        OwnerTagVotes::<T>::insert(&buyer, &game, BoundedVec::truncate_from(tags));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(buyer.clone()), publisher, game_id, tag_id);
+
+        assert_eq!(TagVotes::<T>::get(&game, tag_id), 1);
+    }
+
+    #[benchmark]
+    fn tag_vote_withdraw() {
+        let buyer: T::AccountId = whitelisted_caller();
+        let publisher = get_account::<T>(0);
+        let game_id = GameId::default();
+        let game = (publisher.clone(), game_id);
+        let tags = (0..T::MaxTagVotesPerGame::get() as TagId).collect::<Vec<_>>();
+        let tag_id = *tags.last().expect("at least one vote per game; qed");
+        OwnerTagVotes::<T>::insert(&buyer, &game, BoundedVec::truncate_from(tags));
+        TagVotes::<T>::insert(&game, tag_id, 1);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(buyer.clone()), publisher, game_id, tag_id);
+
+        assert_eq!(TagVotes::<T>::get(&game, tag_id), 0);
+    }
+
+    #[benchmark]
+    fn wishlist_add() {
+        let who: T::AccountId = whitelisted_caller();
+        let publisher = get_account::<T>(0);
+        let game_id = 10;
+        let game_details = GameDetails {
+            name: bounded_vec(&vec![b'a'; MAX_NAME_SIZE as usize]),
+            tags: bounded_vec(&vec![TagId::default(); MAX_TAGS_PER_GAME as usize]),
+            distribution: Distribution::Individual {
+                price: CurrencyOf::<T>::from(2_000_000_000u32),
+                asset: PaymentAsset::Native,
+            },
+            regional_prices: Default::default(),
+            rating: Default::default(),
+        };
+        PublishedGames::<T>::insert(&publisher, game_id, game_details);
+        let games = (1..T::MaxWishlistSize::get())
+            .map(|index| (get_account::<T>(index), GameId::default()))
+            .collect::<Vec<_>>();
+        Wishlists::<T>::insert(&who, BoundedVec::truncate_from(games));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(who.clone()), publisher.clone(), game_id);
+
+        assert!(Wishlists::<T>::get(&who).contains(&(publisher.clone(), game_id)));
+        assert_eq!(WishlistCounts::<T>::get((publisher, game_id)), 1);
+    }
+
+    #[benchmark]
+    fn wishlist_remove() {
+        let who: T::AccountId = whitelisted_caller();
+        let publisher = get_account::<T>(0);
+        let game_id = 10;
+        insert_full_wishlist::<T>(&who, (publisher.clone(), game_id));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(who.clone()), publisher.clone(), game_id);
+
+        assert!(!Wishlists::<T>::get(&who).contains(&(publisher.clone(), game_id)));
+        assert_eq!(WishlistCounts::<T>::get((publisher, game_id)), 0);
+    }
+
+    #[benchmark]
+    fn game_price_set() {
+        let publisher: T::AccountId = whitelisted_caller();
+        let game_id = 10;
+        let game_details = GameDetails {
+            name: bounded_vec(&vec![b'a'; MAX_NAME_SIZE as usize]),
+            tags: bounded_vec(&vec![TagId::default(); MAX_TAGS_PER_GAME as usize]),
+            distribution: Distribution::Individual {
+                price: CurrencyOf::<T>::from(2_000_000_000u32),
+                asset: PaymentAsset::Native,
+            },
+            regional_prices: Default::default(),
+            rating: Default::default(),
+        };
+        PublishedGames::<T>::insert(&publisher, game_id, game_details);
+        GameDiscounts::<T>::insert(&publisher, game_id, discount::<T>());
+        WishlistCounts::<T>::insert((&publisher, game_id), 1);
+        let price = CurrencyOf::<T>::from(1_000_000_000u32);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(publisher.clone()), game_id, price);
+
+        let details = PublishedGames::<T>::get(&publisher, game_id).expect("game exists; qed");
+        assert_eq!(details.distribution.price(), Some((price, PaymentAsset::Native)));
+    }
+
+    #[benchmark]
+    fn game_discount_set() {
+        let publisher: T::AccountId = whitelisted_caller();
+        let game_id = 10;
+        let game_details = GameDetails {
+            name: bounded_vec(&vec![b'a'; MAX_NAME_SIZE as usize]),
+            tags: bounded_vec(&vec![TagId::default(); MAX_TAGS_PER_GAME as usize]),
+            distribution: Distribution::Individual {
+                price: CurrencyOf::<T>::from(2_000_000_000u32),
+                asset: PaymentAsset::Native,
+            },
+            regional_prices: Default::default(),
+            rating: Default::default(),
+        };
+        PublishedGames::<T>::insert(&publisher, game_id, game_details);
+        WishlistCounts::<T>::insert((&publisher, game_id), 1);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(publisher.clone()), game_id, Some(discount::<T>()));
+
+        assert_eq!(GameDiscounts::<T>::get(&publisher, game_id), Some(discount::<T>()));
+    }
+
+    #[benchmark]
+    fn receipt_remove() {
+        let buyer: T::AccountId = whitelisted_caller();
+        prefund_account::<T>(&buyer);
+        let publisher = get_account::<T>(0);
+        let game_id = 10;
+        let deposit = hold_receipt_deposit::<T>(&buyer);
+        let price = CurrencyOf::<T>::from(2_000_000_000u32);
+        let receipt = Receipt {
+            price,
+            publisher_amount: price,
+            protocol_amount: Zero::zero(),
+            asset: PaymentAsset::Native,
+            region: Some(REGION),
+            kind: DistributionKind::Instant,
+            block: frame_system::Pallet::<T>::block_number(),
+            deposit,
+        };
+        Receipts::<T>::insert(&buyer, (&publisher, game_id), receipt);
+        PublisherReceipts::<T>::insert(&publisher, (game_id, &buyer), ());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(buyer.clone()), publisher.clone(), game_id);
+
+        assert_eq!(Receipts::<T>::get(&buyer, (&publisher, game_id)), None);
+        assert_eq!(PublisherReceipts::<T>::get(&publisher, (game_id, &buyer)), None);
+    }
+
+    #[benchmark]
+    fn game_availability_report() {
+        let publisher = get_account::<T>(0);
+        let game_id = 10;
+        let game_details = GameDetails {
+            name: bounded_vec(&vec![b'a'; MAX_NAME_SIZE as usize]),
+            tags: bounded_vec(&vec![TagId::default(); MAX_TAGS_PER_GAME as usize]),
+            distribution: Distribution::Free {
+                cid: bounded_vec(b"bagbaihragmzc2vwmec24nt3qaaozuk5wh43n5izfer6chasuzd7z4iuwnhmq"),
+            },
+            regional_prices: Default::default(),
+            rating: Default::default(),
+        };
+        PublishedGames::<T>::insert(&publisher, game_id, game_details);
+        let public = generate_reporter_key::<T>();
+        CidReporters::<T>::insert(public.clone().into_account(), ());
+        let report = AvailabilityReport {
+            publisher: publisher.clone(),
+            game_id,
+            reachable: false,
+            block_number: frame_system::Pallet::<T>::block_number(),
+            public: public.clone(),
+        };
+        let signature = report
+            .using_encoded(|payload| T::AuthorityId::sign(payload, public))
+            .expect("key is in the keystore; qed");
+
+        #[extrinsic_call]
+        _(RawOrigin::None, report, signature);
+
+        assert!(UnreachableGames::<T>::contains_key((&publisher, game_id)));
+    }
+
+    #[benchmark]
+    fn game_build_set() {
+        let publisher: T::AccountId = whitelisted_caller();
+        let game_id = 10;
+        let cid: Cid =
+            bounded_vec(b"bagbaihragmzc2vwmec24nt3qaaozuk5wh43n5izfer6chasuzd7z4iuwnhmq");
+        let game_details = GameDetails {
+            name: bounded_vec(&vec![b'a'; MAX_NAME_SIZE as usize]),
+            tags: bounded_vec(&vec![TagId::default(); MAX_TAGS_PER_GAME as usize]),
+            distribution: Distribution::Free { cid: cid.clone() },
+            regional_prices: Default::default(),
+            rating: Default::default(),
+        };
+        PublishedGames::<T>::insert(&publisher, game_id, game_details);
+        let build = BuildManifest { cid, size: u64::MAX, digest: [u8::MAX; 32] };
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(publisher.clone()), game_id, Some(build.clone()));
+
+        assert_eq!(GameBuilds::<T>::get(&publisher, game_id), Some(build));
+    }
+
+    #[benchmark]
+    fn cid_reporter_set() -> Result<(), BenchmarkError> {
+        let origin = T::CidReporterOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        let reporter = get_account::<T>(0);
+
+        #[extrinsic_call]
+        _(origin, reporter.clone(), true);
+
+        assert_eq!(CidReporters::<T>::get(&reporter), Some(()));
+        Ok(())
     }
 
     impl_benchmark_test_suite!(Games, mock::new_test_ext(), mock::Test);
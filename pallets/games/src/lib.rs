@@ -1,23 +1,45 @@
 // We make sure this pallet uses `no_std` for compiling to Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
+use alloc::vec::Vec;
 use frame_support::{
     pallet_prelude::*,
+    sp_runtime::{
+        traits::{AccountIdConversion, IdentifyAccount, SaturatedConversion, Saturating, Zero},
+        PerThing, Percent,
+    },
     traits::{
         fungible::{hold::Mutate as FunHoldMutate, Inspect as FunInspect, Mutate as FunMutate},
+        fungibles::Mutate as FunsMutate,
         tokens::{
-            Fortitude::Polite, Precision::BestEffort, Preservation::Preserve, Restriction::Free,
+            Fortitude::Polite,
+            Precision::BestEffort,
+            Preservation::{Expendable, Preserve},
+            Restriction::Free,
         },
     },
+    weights::WeightMeter,
+    PalletId,
+};
+use frame_system::{
+    offchain::{AppCrypto, CreateInherent, SignedPayload, SigningTypes},
+    pallet_prelude::*,
 };
-use frame_system::pallet_prelude::*;
 use liganite_primitives::{
+    ownership::OwnershipManager,
     publisher::PublisherManager,
-    tags::TAGS,
+    tags::{TAGS, TAG_PARENTS},
     types::{
-        AccountIdOf, BuyerId, Cid, Distribution, GameDetails, GameId, GlobalGameId, OrderDetails,
-        PublisherId, Tag, TagId,
+        AccountIdOf, AgeRating, BuildManifest, BuyerId, Cid, ContentDescriptors, Discount,
+        Distribution, DistributionKind, Entitlement, GameDetails, GameId, GlobalGameId,
+        GlobalPlanId, Locale, OrderDetails, PaymentAsset, PlanId, PreOrderRelease,
+        PreOrderSchedule, PreOrderSettlement, PublisherId, Receipt, RegionId, SalesStats,
+        SubscriptionPlan, Tag, TagCategory, TagId, TagInfo, TagProposal, TagProposalId,
+        WishlistEntry,
     },
+    validate,
 };
 // Re-export pallet items so that they can be accessed from the crate namespace.
 pub use pallet::*;
@@ -31,18 +53,39 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod migrations;
+
+mod offchain;
+pub use offchain::{crypto, AvailabilityReport, IPFS_GATEWAY_KEY, KEY_TYPE};
+
 pub mod weights;
 pub use weights::*;
 
 type CurrencyOf<T> = <<T as Config>::Currency as FunInspect<AccountIdOf<T>>>::Balance;
-type GameDetailsOf<T> = GameDetails<CurrencyOf<T>>;
-type OrderDetailsOf<T> = OrderDetails<CurrencyOf<T>>;
+type AssetIdOf<T> = <T as Config>::AssetId;
+type PaymentAssetOf<T> = PaymentAsset<AssetIdOf<T>>;
+type GameDetailsOf<T> = GameDetails<CurrencyOf<T>, AssetIdOf<T>>;
+type OrderDetailsOf<T> = OrderDetails<CurrencyOf<T>, AssetIdOf<T>>;
+type PreOrderScheduleOf<T> = PreOrderSchedule<BlockNumberFor<T>>;
+type SubscriptionPlanOf<T> = SubscriptionPlan<CurrencyOf<T>, AssetIdOf<T>, BlockNumberFor<T>>;
+type EntitlementOf<T> = Entitlement<BlockNumberFor<T>>;
+type TagProposalOf<T> = TagProposal<AccountIdOf<T>, CurrencyOf<T>, BlockNumberFor<T>>;
+type DiscountOf<T> = Discount<BlockNumberFor<T>>;
+type WishlistEntryOf<T> = WishlistEntry<AccountIdOf<T>, CurrencyOf<T>, AssetIdOf<T>>;
+type ReceiptOf<T> = Receipt<CurrencyOf<T>, AssetIdOf<T>, BlockNumberFor<T>>;
+type SalesStatsOf<T> = SalesStats<CurrencyOf<T>>;
+type AvailabilityReportOf<T> =
+    AvailabilityReport<<T as SigningTypes>::Public, AccountIdOf<T>, BlockNumberFor<T>>;
 
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
 
+    /// The in-code storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     #[pallet::genesis_config]
@@ -56,11 +99,10 @@ pub mod pallet {
     #[pallet::genesis_build]
     impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
         fn build(&self) {
-            TAGS.iter().enumerate().for_each(|(i, tag)| {
-                let tag = Tag::try_from(tag.as_bytes().to_vec())
-                    .expect("Failed to create tag at genesis build");
-                Tags::<T>::insert(i as TagId, tag);
-            })
+            // Keep the pallet account alive, so it can receive asset payments of any kind
+            frame_system::Pallet::<T>::inc_providers(&Pallet::<T>::account_id());
+
+            Pallet::<T>::insert_genesis_tags(TAGS, TAG_PARENTS);
         }
     }
 
@@ -69,11 +111,15 @@ pub mod pallet {
     pub enum HoldReason {
         /// The game payment.
         GamePayment,
+        /// The deposit of a tag proposal.
+        TagProposal,
+        /// The deposit of a purchase receipt.
+        ReceiptDeposit,
     }
 
     /// The pallet's configuration trait.
     #[pallet::config]
-    pub trait Config: frame_system::Config {
+    pub trait Config: frame_system::Config + CreateInherent<Call<Self>> + SigningTypes {
         /// A type representing the weights required by the dispatchables of this pallet.
         type WeightInfo: WeightInfo;
 
@@ -87,8 +133,86 @@ pub mod pallet {
         type Currency: FunMutate<Self::AccountId>
             + FunHoldMutate<Self::AccountId, Reason = Self::RuntimeHoldReason>;
 
+        /// The identifier of the fungible assets games can be priced in.
+        type AssetId: Parameter + DecodeWithMemTracking + MaxEncodedLen;
+
+        /// Used to operate on fungible assets.
+        type Assets: FunsMutate<
+            Self::AccountId,
+            AssetId = Self::AssetId,
+            Balance = CurrencyOf<Self>,
+        >;
+
+        /// The pallet id, used to derive the account keeping asset payments of open orders.
+        #[pallet::constant]
+        type PalletId: Get<PalletId>;
+
         /// Used to operate on publishers.
         type PublisherManager: PublisherManager<PublisherId = PublisherId<Self>>;
+
+        /// The number of blocks after adding a pre-order game within which the publisher has to
+        /// release it. Once passed, all pre-orders of the game are refunded.
+        #[pallet::constant]
+        type PreOrderDeadline: Get<BlockNumberFor<Self>>;
+
+        /// The maximum number of pre-order releases and deadlines scheduled at a single block.
+        #[pallet::constant]
+        type MaxPreOrdersScheduledPerBlock: Get<u32>;
+
+        /// The origin allowed to manage the tag vocabulary.
+        type TagOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// The origin allowed to approve or reject the tags proposed by publishers.
+        type TagCuratorOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// The deposit held from a publisher proposing a tag. It is returned once the proposal is
+        /// approved or expires, and slashed if the proposal is rejected.
+        #[pallet::constant]
+        type TagProposalDeposit: Get<CurrencyOf<Self>>;
+
+        /// The number of blocks after which a tag proposal not yet curated expires.
+        #[pallet::constant]
+        type TagProposalPeriod: Get<BlockNumberFor<Self>>;
+
+        /// The maximum number of tag proposals expiring at a single block.
+        #[pallet::constant]
+        type MaxTagProposalsExpiringPerBlock: Get<u32>;
+
+        /// The maximum number of tags an owner can vote for on a single game.
+        #[pallet::constant]
+        type MaxTagVotesPerGame: Get<u32>;
+
+        /// The maximum number of games an account can have in its wishlist.
+        #[pallet::constant]
+        type MaxWishlistSize: Get<u32>;
+
+        /// The deposit held for storing a purchase receipt, until the receipt is removed. Receipts
+        /// of free games are stored without deposit.
+        #[pallet::constant]
+        type ReceiptDeposit: Get<CurrencyOf<Self>>;
+
+        /// The profile of the CIDs games can be distributed with, such as the CIDs the pinning
+        /// service can serve.
+        #[pallet::constant]
+        type CidPolicy: Get<validate::CidPolicy<'static>>;
+
+        /// The number of blocks between two checks of the game CIDs by the off-chain worker.
+        #[pallet::constant]
+        type CidCheckInterval: Get<BlockNumberFor<Self>>;
+
+        /// The maximum number of game CIDs fetched by the off-chain worker in a check.
+        #[pallet::constant]
+        type MaxCidChecksPerRun: Get<u32>;
+
+        /// The priority of the unsigned transactions reporting the availability of a game.
+        #[pallet::constant]
+        type UnsignedPriority: Get<TransactionPriority>;
+
+        /// The key signing the availability reports of the off-chain worker.
+        type AuthorityId: AppCrypto<Self::Public, Self::Signature>;
+
+        /// The origin allowed to register the accounts reporting the availability of games.
+        type CidReporterOrigin: EnsureOrigin<Self::RuntimeOrigin>;
     }
 
     /// Storage for the game details. Is a map of PublisherId -> GameId -> GameDetails.
@@ -103,10 +227,116 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    /// Storage for the games whose CID could not be fetched from IPFS. Is a map of
+    /// GlobalGameId -> BlockNumber of the report.
+    #[pallet::storage]
+    pub type UnreachableGames<T> =
+        StorageMap<_, Blake2_128Concat, GlobalGameId<T>, BlockNumberFor<T>, OptionQuery>;
+
+    /// Storage for the accounts allowed to report the availability of games. Is a map of
+    /// AccountId -> ().
+    #[pallet::storage]
+    pub type CidReporters<T> = StorageMap<_, Blake2_128Concat, AccountIdOf<T>, (), OptionQuery>;
+
+    /// Storage for the build manifests of the games. Is a map of
+    /// PublisherId -> GameId -> BuildManifest.
+    #[pallet::storage]
+    pub type GameBuilds<T> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        PublisherId<T>,
+        Blake2_128Concat,
+        GameId,
+        BuildManifest,
+        OptionQuery,
+    >;
+
+    /// Storage for the discounts of the games. Is a map of PublisherId -> GameId -> Discount.
+    #[pallet::storage]
+    pub type GameDiscounts<T> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        PublisherId<T>,
+        Blake2_128Concat,
+        GameId,
+        DiscountOf<T>,
+        OptionQuery,
+    >;
+
+    /// Storage for the games by tag. Is a map of TagId -> GlobalGameId -> ().
+    #[pallet::storage]
+    pub type GamesByTag<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        TagId,
+        Blake2_128Concat,
+        GlobalGameId<T>,
+        (),
+        OptionQuery,
+    >;
+
     /// Storage for the game tags. Is a map of TagId -> Tag.
     #[pallet::storage]
     pub type Tags<T> = CountedStorageMap<_, Blake2_128Concat, TagId, Tag, OptionQuery>;
 
+    /// Storage for the ids of the game tags by name. Is a map of Tag -> TagId.
+    #[pallet::storage]
+    pub type TagIds<T> = StorageMap<_, Blake2_128Concat, Tag, TagId, OptionQuery>;
+
+    /// Storage for the taxonomy of the game tags. Is a map of TagId -> TagInfo.
+    #[pallet::storage]
+    pub type TagTaxonomy<T> = StorageMap<_, Blake2_128Concat, TagId, TagInfo, OptionQuery>;
+
+    /// Storage for the children of the game tags. Is a map of TagId -> TagId -> ().
+    #[pallet::storage]
+    pub type TagChildren<T> =
+        StorageDoubleMap<_, Blake2_128Concat, TagId, Blake2_128Concat, TagId, (), OptionQuery>;
+
+    /// Storage for the game tags by category. Is a map of TagCategory -> TagId -> ().
+    #[pallet::storage]
+    pub type TagsByCategory<T> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        TagCategory,
+        Blake2_128Concat,
+        TagId,
+        (),
+        OptionQuery,
+    >;
+
+    /// Storage for the deprecated game tags. Is a map of TagId -> ().
+    #[pallet::storage]
+    pub type DeprecatedTags<T> = StorageMap<_, Blake2_128Concat, TagId, (), OptionQuery>;
+
+    /// Storage for the translated names of the game tags. Is a map of Locale -> TagId -> Tag.
+    #[pallet::storage]
+    pub type TagTranslations<T> =
+        StorageDoubleMap<_, Blake2_128Concat, Locale, Blake2_128Concat, TagId, Tag, OptionQuery>;
+
+    /// Storage for the id of the next added game tag.
+    #[pallet::storage]
+    pub type NextTagId<T> = StorageValue<_, TagId, ValueQuery>;
+
+    /// Storage for the pending tag proposals. Is a map of TagProposalId -> TagProposal.
+    #[pallet::storage]
+    pub type TagProposals<T> =
+        StorageMap<_, Blake2_128Concat, TagProposalId, TagProposalOf<T>, OptionQuery>;
+
+    /// Storage for the id of the next tag proposal.
+    #[pallet::storage]
+    pub type NextTagProposalId<T> = StorageValue<_, TagProposalId, ValueQuery>;
+
+    /// Storage for the tag proposals expiring at a block. Is a map of
+    /// BlockNumber -> [TagProposalId].
+    #[pallet::storage]
+    pub type TagProposalAgenda<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        BoundedVec<TagProposalId, T::MaxTagProposalsExpiringPerBlock>,
+        ValueQuery,
+    >;
+
     /// Storage for the game orders. Is a map of PublisherId -> GameId -> BuyerId.
     #[pallet::storage]
     pub type PublisherOrders<T> = StorageDoubleMap<
@@ -143,6 +373,169 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    /// Storage for the community votes on the game tags. Is a map of
+    /// GlobalGameId -> TagId -> votes.
+    #[pallet::storage]
+    pub type TagVotes<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        GlobalGameId<T>,
+        Blake2_128Concat,
+        TagId,
+        u32,
+        ValueQuery,
+    >;
+
+    /// Storage for the tags voted by the game owners. Is a map of
+    /// BuyerId -> GlobalGameId -> [TagId].
+    #[pallet::storage]
+    pub type OwnerTagVotes<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        BuyerId<T>,
+        Blake2_128Concat,
+        GlobalGameId<T>,
+        BoundedVec<TagId, T::MaxTagVotesPerGame>,
+        ValueQuery,
+    >;
+
+    /// Storage for the wishlists. Is a map of BuyerId -> [GlobalGameId].
+    #[pallet::storage]
+    pub type Wishlists<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        BuyerId<T>,
+        BoundedVec<GlobalGameId<T>, T::MaxWishlistSize>,
+        ValueQuery,
+    >;
+
+    /// Storage for the number of wishlists including a game. Is a map of GlobalGameId -> count.
+    #[pallet::storage]
+    pub type WishlistCounts<T> = StorageMap<_, Blake2_128Concat, GlobalGameId<T>, u32, ValueQuery>;
+
+    /// Storage for the purchase receipts. Is a map of BuyerId -> GlobalGameId -> Receipt.
+    #[pallet::storage]
+    pub type Receipts<T> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        BuyerId<T>,
+        Blake2_128Concat,
+        GlobalGameId<T>,
+        ReceiptOf<T>,
+        OptionQuery,
+    >;
+
+    /// Storage for the purchase receipts of the publisher games. Is a map of
+    /// PublisherId -> (GameId, BuyerId) -> ().
+    #[pallet::storage]
+    pub type PublisherReceipts<T> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        PublisherId<T>,
+        Blake2_128Concat,
+        (GameId, BuyerId<T>),
+        (),
+        OptionQuery,
+    >;
+
+    /// Storage for the sales statistics of the games. Is a map of
+    /// GlobalGameId -> PaymentAsset -> SalesStats.
+    #[pallet::storage]
+    pub type GameSales<T> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        GlobalGameId<T>,
+        Blake2_128Concat,
+        PaymentAssetOf<T>,
+        SalesStatsOf<T>,
+        ValueQuery,
+    >;
+
+    /// Storage for the sales statistics of the publishers. Is a map of
+    /// PublisherId -> PaymentAsset -> SalesStats.
+    #[pallet::storage]
+    pub type PublisherSales<T> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        PublisherId<T>,
+        Blake2_128Concat,
+        PaymentAssetOf<T>,
+        SalesStatsOf<T>,
+        ValueQuery,
+    >;
+
+    /// Storage for the game pre-orders. Is a map of GlobalGameId -> BuyerId -> OrderDetails.
+    #[pallet::storage]
+    pub type PreOrders<T> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        GlobalGameId<T>,
+        Twox64Concat,
+        BuyerId<T>,
+        OrderDetailsOf<T>,
+        OptionQuery,
+    >;
+
+    /// Storage for the pre-order schedules of games open for pre-ordering. Is a map of
+    /// GlobalGameId -> PreOrderSchedule.
+    #[pallet::storage]
+    pub type PreOrderSchedules<T> =
+        StorageMap<_, Blake2_128Concat, GlobalGameId<T>, PreOrderScheduleOf<T>, OptionQuery>;
+
+    /// Storage for the pre-order releases and deadlines due at a block. Is a map of
+    /// BlockNumber -> [GlobalGameId].
+    #[pallet::storage]
+    pub type PreOrderAgenda<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        BoundedVec<GlobalGameId<T>, T::MaxPreOrdersScheduledPerBlock>,
+        ValueQuery,
+    >;
+
+    /// Storage for the pre-orders waiting to be settled. Is a map of
+    /// GlobalGameId -> PreOrderSettlement.
+    #[pallet::storage]
+    pub type PreOrderSettlements<T> =
+        StorageMap<_, Blake2_128Concat, GlobalGameId<T>, PreOrderSettlement, OptionQuery>;
+
+    /// Storage for the pre-orders whose refund failed, along with the amounts still held from the
+    /// buyers. Is a map of GlobalGameId -> BuyerId -> OrderDetails.
+    #[pallet::storage]
+    pub type PreOrderFailedRefunds<T> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        GlobalGameId<T>,
+        Twox64Concat,
+        BuyerId<T>,
+        OrderDetailsOf<T>,
+        OptionQuery,
+    >;
+
+    /// Storage for the subscription plans. Is a map of PublisherId -> PlanId -> SubscriptionPlan.
+    #[pallet::storage]
+    pub type SubscriptionPlans<T> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        PublisherId<T>,
+        Blake2_128Concat,
+        PlanId,
+        SubscriptionPlanOf<T>,
+        OptionQuery,
+    >;
+
+    /// Storage for the subscriptions. Is a map of BuyerId -> GlobalPlanId -> Entitlement.
+    #[pallet::storage]
+    pub type Subscriptions<T> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        BuyerId<T>,
+        Blake2_128Concat,
+        GlobalPlanId<T>,
+        EntitlementOf<T>,
+        OptionQuery,
+    >;
+
     /// Events.
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -164,6 +557,8 @@ pub mod pallet {
             game_id: GameId,
             /// The CID of the game that can be downloaded.
             cid: Cid,
+            /// The region tier the game was charged at, if any.
+            region: Option<RegionId>,
         },
         /// An order has been placed.
         OrderPlaced {
@@ -173,6 +568,8 @@ pub mod pallet {
             publisher: PublisherId<T>,
             /// The game id.
             game_id: GameId,
+            /// The region tier the game was charged at, if any.
+            region: Option<RegionId>,
         },
         /// An order has been cancelled.
         OrderCancelled {
@@ -192,80 +589,459 @@ pub mod pallet {
             /// The game id.
             game_id: GameId,
         },
-    }
-
-    /// Errors.
-    #[pallet::error]
-    pub enum Error<T> {
-        /// The publisher is invalid.
-        InvalidPublisher,
-        /// The game is not found.
-        GameNotFound,
-        /// The game already exists.
-        GameAlreadyExists,
-        /// The game details are invalid.
-        GameDetailsInvalid,
-        /// The order is already placed.
-        OrderAlreadyPlaced,
-        /// The order is not found.
-        OrderNotFound,
-    }
-
-    /// Dispatchable functions ([`Call`]s).
-    #[pallet::call]
-    impl<T: Config> Pallet<T> {
-        /// Adds a new game to the system.
-        ///
-        /// This function adds a game by storing their details in the `PublishedGames` storage. It
-        /// checks that the game does not already exist in the system before adding
-        /// them. A `GameAdded` event is emitted once the game is successfully added.
-        #[pallet::call_index(0)]
-        #[pallet::weight(T::WeightInfo::game_add(details.name.len() as u32, details.tags.len() as u32
-        ))]
-        pub fn game_add(
-            origin: OriginFor<T>,
-            game_id: GameId,
-            details: GameDetailsOf<T>,
-        ) -> DispatchResult {
-            let publisher = ensure_signed(origin)?;
-            ensure!(
-                T::PublisherManager::is_valid_publisher(&publisher),
-                Error::<T>::InvalidPublisher
-            );
-            ensure!(
-                !PublishedGames::<T>::contains_key(&publisher, game_id),
-                Error::<T>::GameAlreadyExists
-            );
-            ensure!(
-                details.is_valid(|x| Tags::<T>::contains_key(x)),
-                Error::<T>::GameDetailsInvalid
-            );
-
-            PublishedGames::<T>::insert(&publisher, game_id, details);
-
-            Self::deposit_event(Event::GameAdded { publisher, game_id });
-            Ok(())
-        }
-
-        /// Places an order for a game.
-        ///
-        /// This function purchases by a way depending on the game's distribution. If the game is
-        /// distributed free of charge, the game is added to the buyer's collection. If the game
-        /// supports instant distribution, the game is added to the buyer's collection and the
-        /// payment is sent to the publisher. If the game supports delayed distribution, an order is
-        /// created and the payment is sent to the publisher.
-        #[pallet::call_index(1)]
-        #[pallet::weight(T::WeightInfo::game_buy())]
-        pub fn game_buy(
-            origin: OriginFor<T>,
+        /// A pre-order has been placed.
+        PreOrderPlaced {
+            /// The buyer of the game.
+            buyer: BuyerId<T>,
+            /// The publisher of the game.
             publisher: PublisherId<T>,
+            /// The game id.
             game_id: GameId,
+            /// The region tier the game was charged at, if any.
+            region: Option<RegionId>,
+        },
+        /// A pre-order release has been scheduled.
+        PreOrderReleaseScheduled {
+            /// The publisher of the game.
+            publisher: PublisherId<T>,
+            /// The game id.
+            game_id: GameId,
+            /// The block at which the game is released.
+            at: BlockNumberFor<T>,
+        },
+        /// A pre-ordered game has been released.
+        PreOrderReleased {
+            /// The publisher of the game.
+            publisher: PublisherId<T>,
+            /// The game id.
+            game_id: GameId,
+        },
+        /// The pre-orders of a game have been cancelled by the publisher.
+        PreOrderCancelled {
+            /// The publisher of the game.
+            publisher: PublisherId<T>,
+            /// The game id.
+            game_id: GameId,
+        },
+        /// The publisher has not released a pre-ordered game before the deadline.
+        PreOrderExpired {
+            /// The publisher of the game.
+            publisher: PublisherId<T>,
+            /// The game id.
+            game_id: GameId,
+        },
+        /// A pre-order has been refunded.
+        PreOrderRefunded {
+            /// The buyer of the game.
+            buyer: BuyerId<T>,
+            /// The publisher of the game.
+            publisher: PublisherId<T>,
+            /// The game id.
+            game_id: GameId,
+        },
+        /// The payment or the receipt deposit of a pre-order could not be returned to the buyer.
+        /// What is left aside can be claimed again by the buyer.
+        PreOrderRefundFailed {
+            /// The buyer of the game.
+            buyer: BuyerId<T>,
+            /// The publisher of the game.
+            publisher: PublisherId<T>,
+            /// The game id.
+            game_id: GameId,
+            /// The payment left aside, zero if it was returned.
+            amount: CurrencyOf<T>,
+            /// The asset of the payment.
+            asset: PaymentAssetOf<T>,
+            /// The receipt deposit left aside, zero if it was returned.
+            receipt_deposit: CurrencyOf<T>,
+        },
+        /// A subscription plan has been added.
+        PlanAdded {
+            /// The publisher of the plan.
+            publisher: PublisherId<T>,
+            /// The plan id.
+            plan_id: PlanId,
+        },
+        /// A subscription has been purchased or extended.
+        SubscriptionPurchased {
+            /// The subscriber.
+            buyer: BuyerId<T>,
+            /// The publisher of the plan.
+            publisher: PublisherId<T>,
+            /// The plan id.
+            plan_id: PlanId,
+            /// The block at which the subscription expires.
+            expires_at: BlockNumberFor<T>,
+        },
+        /// A tag has been added.
+        TagAdded {
+            /// The tag id.
+            tag_id: TagId,
+            /// The name of the tag.
+            tag: Tag,
+            /// The taxonomy of the tag.
+            info: TagInfo,
+        },
+        /// A tag has been renamed.
+        TagRenamed {
+            /// The tag id.
+            tag_id: TagId,
+            /// The new name of the tag.
+            tag: Tag,
+        },
+        /// A tag has been deprecated.
+        TagDeprecated {
+            /// The tag id.
+            tag_id: TagId,
+        },
+        /// A tag has been moved in the taxonomy.
+        TagClassified {
+            /// The tag id.
+            tag_id: TagId,
+            /// The new taxonomy of the tag.
+            info: TagInfo,
+        },
+        /// The translated name of a tag has been set or removed.
+        TagTranslated {
+            /// The tag id.
+            tag_id: TagId,
+            /// The locale of the translation.
+            locale: Locale,
+            /// The translated name of the tag, or `None` if the translation has been removed.
+            name: Option<Tag>,
+        },
+        /// A tag has been proposed by a publisher.
+        TagProposed {
+            /// The tag proposal id.
+            proposal_id: TagProposalId,
+            /// The publisher proposing the tag.
+            proposer: PublisherId<T>,
+            /// The name of the proposed tag.
+            tag: Tag,
+            /// The taxonomy of the proposed tag.
+            info: TagInfo,
+        },
+        /// A tag proposal has been approved and the tag added.
+        TagProposalApproved {
+            /// The tag proposal id.
+            proposal_id: TagProposalId,
+            /// The id of the added tag.
+            tag_id: TagId,
+        },
+        /// A tag proposal has been rejected and its deposit slashed.
+        TagProposalRejected {
+            /// The tag proposal id.
+            proposal_id: TagProposalId,
+            /// The slashed deposit.
+            slashed: CurrencyOf<T>,
+        },
+        /// A tag proposal has expired and its deposit returned.
+        TagProposalExpired {
+            /// The tag proposal id.
+            proposal_id: TagProposalId,
+        },
+        /// An owner has voted for a tag on a game.
+        TagVoted {
+            /// The owner of the game.
+            voter: BuyerId<T>,
+            /// The publisher of the game.
+            publisher: PublisherId<T>,
+            /// The game id.
+            game_id: GameId,
+            /// The tag id.
+            tag_id: TagId,
+        },
+        /// An owner has withdrawn their vote for a tag on a game.
+        TagVoteWithdrawn {
+            /// The owner of the game.
+            voter: BuyerId<T>,
+            /// The publisher of the game.
+            publisher: PublisherId<T>,
+            /// The game id.
+            game_id: GameId,
+            /// The tag id.
+            tag_id: TagId,
+        },
+        /// A game has been added to a wishlist.
+        WishlistAdded {
+            /// The owner of the wishlist.
+            who: BuyerId<T>,
+            /// The publisher of the game.
+            publisher: PublisherId<T>,
+            /// The game id.
+            game_id: GameId,
+        },
+        /// A game has been removed from a wishlist.
+        WishlistRemoved {
+            /// The owner of the wishlist.
+            who: BuyerId<T>,
+            /// The publisher of the game.
+            publisher: PublisherId<T>,
+            /// The game id.
+            game_id: GameId,
+        },
+        /// The price of a game has been updated.
+        GamePriceSet {
+            /// The publisher of the game.
+            publisher: PublisherId<T>,
+            /// The game id.
+            game_id: GameId,
+            /// The new price of the game.
+            price: CurrencyOf<T>,
+        },
+        /// The discount of a game has been set or removed.
+        GameDiscountSet {
+            /// The publisher of the game.
+            publisher: PublisherId<T>,
+            /// The game id.
+            game_id: GameId,
+            /// The discount, or `None` if it was removed.
+            discount: Option<DiscountOf<T>>,
+        },
+        /// The effective price of a game in at least one wishlist has dropped.
+        WishlistedGamePriceDropped {
+            /// The publisher of the game.
+            publisher: PublisherId<T>,
+            /// The game id.
+            game_id: GameId,
+            /// The previous effective price of the game.
+            old_price: CurrencyOf<T>,
+            /// The new effective price of the game.
+            new_price: CurrencyOf<T>,
+            /// The asset the price is paid in.
+            asset: PaymentAssetOf<T>,
+        },
+        /// A purchase receipt has been removed.
+        ReceiptRemoved {
+            /// The buyer of the game.
+            buyer: BuyerId<T>,
+            /// The publisher of the game.
+            publisher: PublisherId<T>,
+            /// The game id.
+            game_id: GameId,
+        },
+        /// The CID of a game could not be fetched from IPFS.
+        GameUnreachable {
+            /// The publisher of the game.
+            publisher: PublisherId<T>,
+            /// The game id.
+            game_id: GameId,
+        },
+        /// The CID of a game flagged as unreachable can be fetched again from IPFS.
+        GameReachable {
+            /// The publisher of the game.
+            publisher: PublisherId<T>,
+            /// The game id.
+            game_id: GameId,
+        },
+        /// An account has been allowed or disallowed to report the availability of games.
+        CidReporterSet {
+            /// The reporting account.
+            reporter: AccountIdOf<T>,
+            /// Whether the account is allowed to report.
+            enabled: bool,
+        },
+        /// The build manifest of a game has been set or removed.
+        GameBuildSet {
+            /// The publisher of the game.
+            publisher: PublisherId<T>,
+            /// The game id.
+            game_id: GameId,
+            /// The build manifest, or `None` if removed.
+            build: Option<BuildManifest>,
+        },
+    }
+
+    /// Errors.
+    #[pallet::error]
+    pub enum Error<T> {
+        /// The publisher is invalid.
+        InvalidPublisher,
+        /// The game is not found.
+        GameNotFound,
+        /// The game already exists.
+        GameAlreadyExists,
+        /// The game details are invalid.
+        GameDetailsInvalid,
+        /// The order is already placed.
+        OrderAlreadyPlaced,
+        /// The order is not found.
+        OrderNotFound,
+        /// The game is not open for pre-ordering.
+        PreOrderClosed,
+        /// The pre-order release block is invalid.
+        PreOrderReleaseInvalid,
+        /// Too many pre-order releases and deadlines are scheduled at the block.
+        PreOrderAgendaFull,
+        /// The game is neither released nor refunded yet.
+        PreOrderOpen,
+        /// The pre-orders of a withdrawn game are still being settled.
+        PreOrderUnsettled,
+        /// The subscription plan is not found.
+        PlanNotFound,
+        /// The subscription plan already exists.
+        PlanAlreadyExists,
+        /// The subscription plan details are invalid.
+        PlanDetailsInvalid,
+        /// The tag is not found.
+        TagNotFound,
+        /// The tag name is invalid.
+        TagInvalid,
+        /// A tag with the same name already exists.
+        TagAlreadyExists,
+        /// The tag is already deprecated.
+        TagAlreadyDeprecated,
+        /// No more tags can be added.
+        TagIdOverflow,
+        /// The category or the parent of the tag is invalid.
+        TagInfoInvalid,
+        /// The tag has children in the taxonomy.
+        TagHasChildren,
+        /// The locale is not a valid language code.
+        LocaleInvalid,
+        /// The tag proposal is not found.
+        TagProposalNotFound,
+        /// The tag proposal id cannot be incremented further.
+        TagProposalIdOverflow,
+        /// Too many tag proposals are expiring at the same block.
+        TagProposalAgendaFull,
+        /// The game is not owned by the account.
+        GameNotOwned,
+        /// The account has already voted for the tag on the game.
+        TagVoteAlreadyCast,
+        /// The account has not voted for the tag on the game.
+        TagVoteNotFound,
+        /// The account has voted for the maximum number of tags on the game.
+        TagVoteLimitReached,
+        /// The game is already in the wishlist.
+        GameAlreadyWishlisted,
+        /// The game is not in the wishlist.
+        GameNotWishlisted,
+        /// The wishlist has reached the maximum number of games.
+        WishlistFull,
+        /// The price is zero or the game is free.
+        GamePriceInvalid,
+        /// The discount is invalid or has already ended.
+        DiscountInvalid,
+        /// The receipt is not found.
+        ReceiptNotFound,
+        /// The game has no CID, or is already flagged as reported.
+        AvailabilityUnchanged,
+        /// The build manifest does not describe the game distributed through IPFS.
+        BuildInvalid,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+            let games = PreOrderAgenda::<T>::take(n);
+            let proposals = TagProposalAgenda::<T>::take(n);
+            let weight = T::WeightInfo::preorder_schedule(games.len() as u32)
+                .saturating_add(T::WeightInfo::tag_proposal_expire(proposals.len() as u32));
+
+            games.into_iter().for_each(|game| Self::process_preorder_schedule(n, game));
+            proposals.into_iter().for_each(Self::expire_tag_proposal);
+
+            weight
+        }
+
+        fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            Self::settle_preorders(remaining_weight)
+        }
+
+        fn offchain_worker(n: BlockNumberFor<T>) {
+            let interval = T::CidCheckInterval::get();
+            if !interval.is_zero() && (n % interval).is_zero() {
+                Self::cids_check(n);
+            }
+        }
+    }
+
+    /// Dispatchable functions ([`Call`]s).
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Adds a new game to the system.
+        ///
+        /// This function adds a game by storing their details in the `PublishedGames` storage. It
+        /// checks that the game does not already exist in the system before adding
+        /// them. A `GameAdded` event is emitted once the game is successfully added.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::game_add(details.name.len() as u32, details.tags.len() as u32
+        ))]
+        pub fn game_add(
+            origin: OriginFor<T>,
+            game_id: GameId,
+            details: GameDetailsOf<T>,
+        ) -> DispatchResult {
+            let publisher = ensure_signed(origin)?;
+            ensure!(
+                T::PublisherManager::is_valid_publisher(&publisher),
+                Error::<T>::InvalidPublisher
+            );
+            ensure!(
+                !PublishedGames::<T>::contains_key(&publisher, game_id),
+                Error::<T>::GameAlreadyExists
+            );
+            ensure!(
+                !PreOrderSettlements::<T>::contains_key((&publisher, game_id)),
+                Error::<T>::PreOrderUnsettled
+            );
+            ensure!(
+                details.is_valid(Self::tag_info, &T::CidPolicy::get()),
+                Error::<T>::GameDetailsInvalid
+            );
+
+            if let Distribution::PreOrder { .. } = details.distribution {
+                let deadline = frame_system::Pallet::<T>::block_number()
+                    .saturating_add(T::PreOrderDeadline::get());
+                Self::schedule_preorder(deadline, (publisher.clone(), game_id))?;
+                PreOrderSchedules::<T>::insert(
+                    (&publisher, game_id),
+                    PreOrderSchedule { deadline, release: None },
+                );
+            }
+
+            details.tags.iter().for_each(|tag_id| {
+                GamesByTag::<T>::insert(tag_id, (&publisher, game_id), ());
+            });
+            PublishedGames::<T>::insert(&publisher, game_id, details);
+
+            Self::deposit_event(Event::GameAdded { publisher, game_id });
+            Ok(())
+        }
+
+        /// Places an order for a game.
+        ///
+        /// This function purchases by a way depending on the game's distribution. If the game is
+        /// distributed free of charge, the game is added to the buyer's collection. If the game
+        /// supports instant distribution, the game is added to the buyer's collection and the
+        /// payment is sent to the publisher. If the game supports delayed distribution, an order is
+        /// created and the payment is sent to the publisher. If the game is sold as a pre-order,
+        /// the payment is held until the game is released.
+        ///
+        /// The buyer declares the region they are buying from. If the game has a price for the
+        /// region, that price is charged instead of the distribution price. An active discount of
+        /// the game applies to the charged price, and the game is removed from the buyer's
+        /// wishlist. The receipt deposit is held from the buyer on every path but the free one, and
+        /// a receipt is issued once the purchase completes.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::game_buy())]
+        pub fn game_buy(
+            origin: OriginFor<T>,
+            publisher: PublisherId<T>,
+            game_id: GameId,
+            region: Option<RegionId>,
         ) -> DispatchResult {
             let buyer = ensure_signed(origin)?;
             ensure!(
                 !BuyerOrders::<T>::contains_key(&buyer, (&publisher, game_id)),
                 Error::<T>::OrderAlreadyPlaced
             );
+            // A released game is sold instantly while its pre-orders are still being settled
+            ensure!(
+                !PreOrders::<T>::contains_key((&publisher, game_id), &buyer),
+                Error::<T>::OrderAlreadyPlaced
+            );
             ensure!(
                 !OwnedGames::<T>::contains_key(&buyer, (&publisher, game_id)),
                 Error::<T>::GameAlreadyExists
@@ -273,30 +1049,112 @@ pub mod pallet {
 
             let game_details =
                 PublishedGames::<T>::get(&publisher, game_id).ok_or(Error::<T>::GameNotFound)?;
+            let tier = game_details.regional_price(region);
+            let discount = Self::active_discount(
+                &publisher,
+                game_id,
+                frame_system::Pallet::<T>::block_number(),
+            );
+            let charge = |price| {
+                let (price, region) =
+                    tier.map_or((price, None), |(region, price)| (price, Some(region)));
+                (Self::apply_discount(price, discount), region)
+            };
+            Self::wishlist_remove_game(&buyer, &(publisher.clone(), game_id));
+            // Free games are acquired without funds, so their receipts are stored without deposit
+            let receipt_deposit = match game_details.distribution {
+                Distribution::Free { .. } => Zero::zero(),
+                _ => Self::receipt_deposit_hold(&buyer)?,
+            };
 
             match game_details.distribution {
                 Distribution::Free { cid } => {
                     // Simply add the game to a buyer's collection
                     OwnedGames::<T>::insert(&buyer, (&publisher, game_id), ());
+                    Self::sales_record(&publisher, game_id, &PaymentAsset::Native, |stats| {
+                        stats.record_sale(DistributionKind::Free, Zero::zero())
+                    });
+                    let payment = OrderDetails {
+                        deposit: Zero::zero(),
+                        asset: PaymentAsset::Native,
+                        region: None,
+                        receipt_deposit,
+                    };
+                    Self::receipt_issue(
+                        &buyer,
+                        (publisher.clone(), game_id),
+                        &payment,
+                        DistributionKind::Free,
+                    );
 
-                    Self::deposit_event(Event::GamePurchased { buyer, publisher, game_id, cid });
+                    Self::deposit_event(Event::GamePurchased {
+                        buyer,
+                        publisher,
+                        game_id,
+                        cid,
+                        region: None,
+                    });
                 },
-                Distribution::Instant { price, cid } => {
+                Distribution::Instant { price, asset, cid } => {
                     // Transfer money and add the game to a buyer's collection
-                    T::Currency::transfer(&buyer, &publisher, price, Preserve)?;
+                    let (price, region) = charge(price);
+                    Self::payment_transfer(&buyer, &publisher, &asset, price)?;
                     OwnedGames::<T>::insert(&buyer, (&publisher, game_id), ());
+                    Self::sales_record(&publisher, game_id, &asset, |stats| {
+                        stats.record_sale(DistributionKind::Instant, price)
+                    });
+                    let payment = OrderDetails { deposit: price, asset, region, receipt_deposit };
+                    Self::receipt_issue(
+                        &buyer,
+                        (publisher.clone(), game_id),
+                        &payment,
+                        DistributionKind::Instant,
+                    );
 
-                    Self::deposit_event(Event::GamePurchased { buyer, publisher, game_id, cid });
+                    Self::deposit_event(Event::GamePurchased {
+                        buyer,
+                        publisher,
+                        game_id,
+                        cid,
+                        region,
+                    });
                 },
-                Distribution::Individual { price } => {
+                Distribution::Individual { price, asset } => {
                     // Place an order
-                    T::Currency::hold(&HoldReason::GamePayment.into(), &buyer, price)?;
+                    let (price, region) = charge(price);
+                    Self::payment_hold(&buyer, &asset, price)?;
 
-                    let order = OrderDetails { deposit: price };
+                    let order = OrderDetails { deposit: price, asset, region, receipt_deposit };
                     BuyerOrders::<T>::insert(&buyer, (&publisher, game_id), &order);
                     PublisherOrders::<T>::insert(&publisher, game_id, &buyer);
+                    Self::sales_record(&publisher, game_id, &order.asset, |stats| {
+                        stats.record_order()
+                    });
+
+                    Self::deposit_event(Event::OrderPlaced { buyer, publisher, game_id, region });
+                },
+                Distribution::PreOrder { price, asset } => {
+                    // Hold the payment until the game is released
+                    ensure!(
+                        PreOrderSchedules::<T>::contains_key((&publisher, game_id)),
+                        Error::<T>::PreOrderClosed
+                    );
+
+                    let (price, region) = charge(price);
+                    Self::payment_hold(&buyer, &asset, price)?;
 
-                    Self::deposit_event(Event::OrderPlaced { buyer, publisher, game_id });
+                    let order = OrderDetails { deposit: price, asset, region, receipt_deposit };
+                    PreOrders::<T>::insert((&publisher, game_id), &buyer, &order);
+                    Self::sales_record(&publisher, game_id, &order.asset, |stats| {
+                        stats.record_order()
+                    });
+
+                    Self::deposit_event(Event::PreOrderPlaced {
+                        buyer,
+                        publisher,
+                        game_id,
+                        region,
+                    });
                 },
             }
 
@@ -306,8 +1164,9 @@ pub mod pallet {
         /// Cancels an order for a game.
         ///
         /// This function is triggered by the buyer when they want to cancel an order.
-        /// It checks that the order exists, and then releases the deposit from the buyer.
-        /// A `OrderCancelled` event is emitted once the order is successfully cancelled.
+        /// It checks that the order exists, and then releases the deposit and the receipt deposit
+        /// of the buyer. A `OrderCancelled` event is emitted once the order is successfully
+        /// cancelled.
         #[pallet::call_index(2)]
         #[pallet::weight(T::WeightInfo::order_cancel())]
         pub fn order_cancel(
@@ -320,15 +1179,14 @@ pub mod pallet {
             let order = BuyerOrders::<T>::get(&buyer, (&publisher, game_id))
                 .ok_or(Error::<T>::OrderNotFound)?;
 
-            T::Currency::release(
-                &HoldReason::GamePayment.into(),
-                &buyer,
-                order.deposit,
-                BestEffort,
-            )?;
+            Self::payment_release(&buyer, &order.asset, order.deposit)?;
+            Self::receipt_deposit_release(&buyer, order.receipt_deposit)?;
 
             BuyerOrders::<T>::remove(&buyer, (&publisher, game_id));
             PublisherOrders::<T>::remove(&publisher, game_id);
+            Self::sales_record(&publisher, game_id, &order.asset, |stats| {
+                stats.record_order_refund(order.deposit)
+            });
 
             Self::deposit_event(Event::OrderCancelled { buyer, publisher, game_id });
             Ok(())
@@ -339,7 +1197,8 @@ pub mod pallet {
         /// This function is triggered by the publisher when they want to fulfill an order.
         /// It checks that the order exists, transfers the deposit from the buyer to the publisher,
         /// and then removes the order from the system, adding the game to the owned games list
-        /// for the buyer. A `OrderFulfilled` event is emitted once the order is fulfilled.
+        /// for the buyer and issuing the buyer a receipt. A `OrderFulfilled` event is emitted once
+        /// the order is fulfilled.
         #[pallet::call_index(3)]
         #[pallet::weight(T::WeightInfo::order_fulfill())]
         pub fn order_fulfill(
@@ -352,24 +1211,1464 @@ pub mod pallet {
             let order = BuyerOrders::<T>::get(&buyer, (&publisher, game_id))
                 .ok_or(Error::<T>::OrderNotFound)?;
 
-            T::Currency::transfer_on_hold(
-                &HoldReason::GamePayment.into(),
-                &buyer,
-                &publisher,
-                order.deposit,
-                BestEffort,
-                Free,
-                Polite,
-            )?;
+            Self::payment_settle(&buyer, &publisher, &order.asset, order.deposit)?;
 
             BuyerOrders::<T>::remove(&buyer, (&publisher, game_id));
             PublisherOrders::<T>::remove(&publisher, game_id);
             OwnedGames::<T>::insert(&buyer, (&publisher, game_id), ());
+            Self::sales_record(&publisher, game_id, &order.asset, |stats| {
+                stats.record_order_sale(DistributionKind::Individual, order.deposit)
+            });
+            Self::receipt_issue(
+                &buyer,
+                (publisher.clone(), game_id),
+                &order,
+                DistributionKind::Individual,
+            );
 
             Self::deposit_event(Event::OrderFulfilled { buyer, publisher, game_id });
+            Ok(())
+        }
+
+        /// Schedules the release of a pre-ordered game.
+        ///
+        /// This function is triggered by the publisher when they want to release a pre-ordered
+        /// game at the given block, providing the CID of the game. The release block must not be
+        /// after the pre-order deadline, and replaces any previously scheduled release. At release
+        /// the held payments are transferred to the publisher and the game is added to the
+        /// buyers' collections. A `PreOrderReleaseScheduled` event is emitted once the release is
+        /// scheduled.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::preorder_release())]
+        pub fn preorder_release(
+            origin: OriginFor<T>,
+            game_id: GameId,
+            at: BlockNumberFor<T>,
+            cid: Cid,
+        ) -> DispatchResult {
+            let publisher = ensure_signed(origin)?;
+
+            let mut schedule = PreOrderSchedules::<T>::get((&publisher, game_id))
+                .ok_or(Error::<T>::PreOrderClosed)?;
+            ensure!(
+                at > frame_system::Pallet::<T>::block_number() && at <= schedule.deadline,
+                Error::<T>::PreOrderReleaseInvalid
+            );
+            ensure!(
+                validate::is_cid_with(&cid, &T::CidPolicy::get()),
+                Error::<T>::GameDetailsInvalid
+            );
+
+            // The deadline stays on the agenda, and a previous release is replaced
+            let game = (publisher.clone(), game_id);
+            if let Some(previous) = schedule.release.take().filter(|r| r.at != schedule.deadline) {
+                Self::unschedule_preorder(previous.at, &game);
+            }
+            if at != schedule.deadline {
+                Self::schedule_preorder(at, game)?;
+            }
+            schedule.release = Some(PreOrderRelease { at, cid });
+            PreOrderSchedules::<T>::insert((&publisher, game_id), schedule);
+
+            Self::deposit_event(Event::PreOrderReleaseScheduled { publisher, game_id, at });
+            Ok(())
+        }
+
+        /// Cancels the pre-orders of a game.
+        ///
+        /// This function is triggered by the publisher when they want to cancel an unreleased
+        /// pre-order game. The game is withdrawn from the catalogue and all held payments are
+        /// released back to the buyers. A `PreOrderCancelled` event is emitted once the pre-orders
+        /// are cancelled.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::preorder_cancel())]
+        pub fn preorder_cancel(origin: OriginFor<T>, game_id: GameId) -> DispatchResult {
+            let publisher = ensure_signed(origin)?;
+
+            PreOrderSchedules::<T>::take((&publisher, game_id))
+                .ok_or(Error::<T>::PreOrderClosed)?;
+            Self::withdraw_game(&publisher, game_id);
+            PreOrderSettlements::<T>::insert((&publisher, game_id), PreOrderSettlement::Refund);
+
+            Self::deposit_event(Event::PreOrderCancelled { publisher, game_id });
+            Ok(())
+        }
+
+        /// Adds a new subscription plan to the system.
+        ///
+        /// This function adds a plan granting access to a set of the publisher's games for a
+        /// period. It checks that the plan does not already exist and that all of its games are
+        /// published by the publisher. A `PlanAdded` event is emitted once the plan is added.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::plan_add(details.games.len() as u32))]
+        pub fn plan_add(
+            origin: OriginFor<T>,
+            plan_id: PlanId,
+            details: SubscriptionPlanOf<T>,
+        ) -> DispatchResult {
+            let publisher = ensure_signed(origin)?;
+            ensure!(
+                T::PublisherManager::is_valid_publisher(&publisher),
+                Error::<T>::InvalidPublisher
+            );
+            ensure!(
+                !SubscriptionPlans::<T>::contains_key(&publisher, plan_id),
+                Error::<T>::PlanAlreadyExists
+            );
+            ensure!(
+                details.is_valid(|game_id| PublishedGames::<T>::contains_key(&publisher, game_id)),
+                Error::<T>::PlanDetailsInvalid
+            );
 
-            // TODO: deposit GamePurchased event
+            SubscriptionPlans::<T>::insert(&publisher, plan_id, details);
+
+            Self::deposit_event(Event::PlanAdded { publisher, plan_id });
             Ok(())
         }
+
+        /// Purchases a subscription period of a plan.
+        ///
+        /// This function transfers the price of the plan to the publisher and grants the buyer
+        /// access to the plan's games for one period. An active subscription is extended by a
+        /// period, while a lapsed one starts anew. A `SubscriptionPurchased` event is emitted
+        /// once the subscription is purchased.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::subscription_buy())]
+        pub fn subscription_buy(
+            origin: OriginFor<T>,
+            publisher: PublisherId<T>,
+            plan_id: PlanId,
+        ) -> DispatchResult {
+            let buyer = ensure_signed(origin)?;
+
+            let plan =
+                SubscriptionPlans::<T>::get(&publisher, plan_id).ok_or(Error::<T>::PlanNotFound)?;
+
+            Self::payment_transfer(&buyer, &publisher, &plan.asset, plan.price)?;
+
+            let now = frame_system::Pallet::<T>::block_number();
+            let entitlement =
+                Subscriptions::<T>::mutate(&buyer, (&publisher, plan_id), |current| {
+                    let entitlement = match current.take() {
+                        Some(active) if active.expires_at >= now => Entitlement {
+                            starts_at: active.starts_at,
+                            expires_at: active.expires_at.saturating_add(plan.period),
+                        },
+                        _ => Entitlement {
+                            starts_at: now,
+                            expires_at: now.saturating_add(plan.period),
+                        },
+                    };
+                    *current = Some(entitlement.clone());
+                    entitlement
+                });
+
+            Self::deposit_event(Event::SubscriptionPurchased {
+                buyer,
+                publisher,
+                plan_id,
+                expires_at: entitlement.expires_at,
+            });
+            Ok(())
+        }
+
+        /// Adds a new tag to the vocabulary.
+        ///
+        /// This function is triggered by the tag origin. The tag is stored under the next free
+        /// tag id within the given category and under the given parent. A `TagAdded` event is
+        /// emitted once the tag is successfully added.
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::tag_add())]
+        pub fn tag_add(origin: OriginFor<T>, tag: Tag, info: TagInfo) -> DispatchResult {
+            T::TagOrigin::ensure_origin(origin)?;
+            Self::add_tag(tag, info)?;
+            Ok(())
+        }
+
+        /// Renames a tag.
+        ///
+        /// This function is triggered by the tag origin. The tag keeps its id, so the games
+        /// tagged with it are not affected. The new name must not be used by another tag. A
+        /// `TagRenamed` event is emitted once the tag is successfully renamed.
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::tag_rename())]
+        pub fn tag_rename(origin: OriginFor<T>, tag_id: TagId, tag: Tag) -> DispatchResult {
+            T::TagOrigin::ensure_origin(origin)?;
+            ensure!(validate::is_non_empty_string(&tag), Error::<T>::TagInvalid);
+            ensure!(!TagIds::<T>::contains_key(&tag), Error::<T>::TagAlreadyExists);
+            let old_tag = Tags::<T>::get(tag_id).ok_or(Error::<T>::TagNotFound)?;
+
+            TagIds::<T>::remove(old_tag);
+            TagIds::<T>::insert(&tag, tag_id);
+            Tags::<T>::insert(tag_id, &tag);
+
+            Self::deposit_event(Event::TagRenamed { tag_id, tag });
+            Ok(())
+        }
+
+        /// Deprecates a tag.
+        ///
+        /// This function is triggered by the tag origin. Deprecated tags remain on the games
+        /// already tagged with them, but cannot be used by newly added games. A `TagDeprecated`
+        /// event is emitted once the tag is successfully deprecated.
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::tag_deprecate())]
+        pub fn tag_deprecate(origin: OriginFor<T>, tag_id: TagId) -> DispatchResult {
+            T::TagOrigin::ensure_origin(origin)?;
+            ensure!(Tags::<T>::contains_key(tag_id), Error::<T>::TagNotFound);
+            ensure!(!DeprecatedTags::<T>::contains_key(tag_id), Error::<T>::TagAlreadyDeprecated);
+
+            DeprecatedTags::<T>::insert(tag_id, ());
+
+            Self::deposit_event(Event::TagDeprecated { tag_id });
+            Ok(())
+        }
+
+        /// Moves a tag in the taxonomy.
+        ///
+        /// This function is triggered by the tag origin. The parent of the tag must belong to the
+        /// category above the tag's category, e.g. a sub-genre can only have a genre as a parent,
+        /// and a tag with children cannot change category. The games already tagged are not
+        /// affected. A `TagClassified` event is emitted once the tag is successfully moved.
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::tag_classify())]
+        pub fn tag_classify(origin: OriginFor<T>, tag_id: TagId, info: TagInfo) -> DispatchResult {
+            T::TagOrigin::ensure_origin(origin)?;
+            let old_info = TagTaxonomy::<T>::get(tag_id).ok_or(Error::<T>::TagNotFound)?;
+            ensure!(
+                info.parent != Some(tag_id) && Self::is_valid_tag_info(&info),
+                Error::<T>::TagInfoInvalid
+            );
+            // The children belong to the category below the tag's one
+            ensure!(
+                info.category == old_info.category || !TagChildren::<T>::contains_prefix(tag_id),
+                Error::<T>::TagHasChildren
+            );
+
+            TagsByCategory::<T>::remove(old_info.category, tag_id);
+            TagsByCategory::<T>::insert(info.category, tag_id, ());
+            if let Some(parent) = old_info.parent {
+                TagChildren::<T>::remove(parent, tag_id);
+            }
+            if let Some(parent) = info.parent {
+                TagChildren::<T>::insert(parent, tag_id, ());
+            }
+            TagTaxonomy::<T>::insert(tag_id, info);
+
+            Self::deposit_event(Event::TagClassified { tag_id, info });
+            Ok(())
+        }
+
+        /// Sets or removes the translated name of a tag.
+        ///
+        /// This function is triggered by the tag origin. The locale is a language code, optionally
+        /// followed by region or script subtags, such as "pt-BR". Tags without a translation are
+        /// shown with their default name. A `TagTranslated` event is emitted once the translation
+        /// is successfully set or removed.
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::tag_translate())]
+        pub fn tag_translate(
+            origin: OriginFor<T>,
+            tag_id: TagId,
+            locale: Locale,
+            name: Option<Tag>,
+        ) -> DispatchResult {
+            T::TagOrigin::ensure_origin(origin)?;
+            ensure!(validate::is_locale(&locale), Error::<T>::LocaleInvalid);
+            ensure!(Tags::<T>::contains_key(tag_id), Error::<T>::TagNotFound);
+            ensure!(
+                name.as_ref().is_none_or(|name| validate::is_non_empty_string(name)),
+                Error::<T>::TagInvalid
+            );
+
+            TagTranslations::<T>::set(&locale, tag_id, name.clone());
+
+            Self::deposit_event(Event::TagTranslated { tag_id, locale, name });
+            Ok(())
+        }
+
+        /// Proposes a new tag.
+        ///
+        /// This function is triggered by a publisher. The `TagProposalDeposit` is held from the
+        /// publisher until a curator approves or rejects the proposal. Proposals not curated
+        /// within the `TagProposalPeriod` expire and their deposit is returned. A `TagProposed`
+        /// event is emitted once the tag is successfully proposed.
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::tag_propose())]
+        pub fn tag_propose(origin: OriginFor<T>, tag: Tag, info: TagInfo) -> DispatchResult {
+            let proposer = ensure_signed(origin)?;
+            ensure!(
+                T::PublisherManager::is_valid_publisher(&proposer),
+                Error::<T>::InvalidPublisher
+            );
+            ensure!(validate::is_non_empty_string(&tag), Error::<T>::TagInvalid);
+            ensure!(!TagIds::<T>::contains_key(&tag), Error::<T>::TagAlreadyExists);
+            ensure!(Self::is_valid_tag_info(&info), Error::<T>::TagInfoInvalid);
+
+            let proposal_id = NextTagProposalId::<T>::get();
+            let next_proposal_id =
+                proposal_id.checked_add(1).ok_or(Error::<T>::TagProposalIdOverflow)?;
+            let expires_at = frame_system::Pallet::<T>::block_number()
+                .saturating_add(T::TagProposalPeriod::get());
+            TagProposalAgenda::<T>::try_mutate(expires_at, |proposals| {
+                proposals.try_push(proposal_id).map_err(|_| Error::<T>::TagProposalAgendaFull)
+            })?;
+
+            let deposit = T::TagProposalDeposit::get();
+            T::Currency::hold(&HoldReason::TagProposal.into(), &proposer, deposit)?;
+
+            let proposal = TagProposal {
+                proposer: proposer.clone(),
+                tag: tag.clone(),
+                info,
+                deposit,
+                expires_at,
+            };
+            TagProposals::<T>::insert(proposal_id, proposal);
+            NextTagProposalId::<T>::put(next_proposal_id);
+
+            Self::deposit_event(Event::TagProposed { proposal_id, proposer, tag, info });
+            Ok(())
+        }
+
+        /// Approves a tag proposal.
+        ///
+        /// This function is triggered by the tag curator origin. The proposed tag is added to the
+        /// vocabulary and the deposit is returned to the publisher. A `TagProposalApproved` event
+        /// is emitted once the proposal is successfully approved.
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::tag_proposal_approve())]
+        pub fn tag_proposal_approve(
+            origin: OriginFor<T>,
+            proposal_id: TagProposalId,
+        ) -> DispatchResult {
+            T::TagCuratorOrigin::ensure_origin(origin)?;
+            let proposal =
+                TagProposals::<T>::take(proposal_id).ok_or(Error::<T>::TagProposalNotFound)?;
+
+            let tag_id = Self::add_tag(proposal.tag, proposal.info)?;
+            T::Currency::release(
+                &HoldReason::TagProposal.into(),
+                &proposal.proposer,
+                proposal.deposit,
+                BestEffort,
+            )?;
+
+            Self::deposit_event(Event::TagProposalApproved { proposal_id, tag_id });
+            Ok(())
+        }
+
+        /// Rejects a tag proposal.
+        ///
+        /// This function is triggered by the tag curator origin. The deposit of the publisher is
+        /// slashed. A `TagProposalRejected` event is emitted once the proposal is successfully
+        /// rejected.
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::tag_proposal_reject())]
+        pub fn tag_proposal_reject(
+            origin: OriginFor<T>,
+            proposal_id: TagProposalId,
+        ) -> DispatchResult {
+            T::TagCuratorOrigin::ensure_origin(origin)?;
+            let proposal =
+                TagProposals::<T>::take(proposal_id).ok_or(Error::<T>::TagProposalNotFound)?;
+
+            let slashed = T::Currency::burn_held(
+                &HoldReason::TagProposal.into(),
+                &proposal.proposer,
+                proposal.deposit,
+                BestEffort,
+                Polite,
+            )?;
+
+            Self::deposit_event(Event::TagProposalRejected { proposal_id, slashed });
+            Ok(())
+        }
+
+        /// Votes for a tag on an owned game.
+        ///
+        /// This function is triggered by an owner of the game. Each owner can vote for up to
+        /// `MaxTagVotesPerGame` tags per game, which do not need to be among the publisher's
+        /// tags. A `TagVoted` event is emitted once the vote is successfully cast.
+        #[pallet::call_index(16)]
+        #[pallet::weight(T::WeightInfo::tag_vote())]
+        pub fn tag_vote(
+            origin: OriginFor<T>,
+            publisher: PublisherId<T>,
+            game_id: GameId,
+            tag_id: TagId,
+        ) -> DispatchResult {
+            let voter = ensure_signed(origin)?;
+            let game = (publisher.clone(), game_id);
+            ensure!(OwnedGames::<T>::contains_key(&voter, &game), Error::<T>::GameNotOwned);
+            ensure!(Self::tag_info(&tag_id).is_some(), Error::<T>::TagNotFound);
+
+            OwnerTagVotes::<T>::try_mutate(&voter, &game, |tags| {
+                ensure!(!tags.contains(&tag_id), Error::<T>::TagVoteAlreadyCast);
+                tags.try_push(tag_id).map_err(|_| Error::<T>::TagVoteLimitReached)
+            })?;
+            TagVotes::<T>::mutate(&game, tag_id, |votes| votes.saturating_inc());
+
+            Self::deposit_event(Event::TagVoted { voter, publisher, game_id, tag_id });
+            Ok(())
+        }
+
+        /// Withdraws a vote for a tag on an owned game.
+        ///
+        /// This function is triggered by an owner of the game who has voted for the tag,
+        /// freeing a vote to cast on another tag. A `TagVoteWithdrawn` event is emitted once the
+        /// vote is successfully withdrawn.
+        #[pallet::call_index(17)]
+        #[pallet::weight(T::WeightInfo::tag_vote_withdraw())]
+        pub fn tag_vote_withdraw(
+            origin: OriginFor<T>,
+            publisher: PublisherId<T>,
+            game_id: GameId,
+            tag_id: TagId,
+        ) -> DispatchResult {
+            let voter = ensure_signed(origin)?;
+            let game = (publisher.clone(), game_id);
+
+            OwnerTagVotes::<T>::try_mutate_exists(&voter, &game, |maybe_tags| {
+                let tags = maybe_tags.as_mut().ok_or(Error::<T>::TagVoteNotFound)?;
+                let index = tags.iter().position(|id| id == &tag_id);
+                tags.remove(index.ok_or(Error::<T>::TagVoteNotFound)?);
+                if tags.is_empty() {
+                    *maybe_tags = None;
+                }
+                Ok::<_, Error<T>>(())
+            })?;
+            TagVotes::<T>::mutate_exists(&game, tag_id, |votes| {
+                *votes = votes.map(|votes| votes.saturating_sub(1)).filter(|votes| *votes > 0);
+            });
+
+            Self::deposit_event(Event::TagVoteWithdrawn { voter, publisher, game_id, tag_id });
+            Ok(())
+        }
+
+        /// Adds a game to the wishlist.
+        ///
+        /// This function is triggered by an account which does not own the game yet. The wishlist
+        /// holds up to `MaxWishlistSize` games, and a game is removed from it once purchased. A
+        /// `WishlistAdded` event is emitted once the game is successfully added.
+        #[pallet::call_index(18)]
+        #[pallet::weight(T::WeightInfo::wishlist_add())]
+        pub fn wishlist_add(
+            origin: OriginFor<T>,
+            publisher: PublisherId<T>,
+            game_id: GameId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let game = (publisher.clone(), game_id);
+            ensure!(
+                PublishedGames::<T>::contains_key(&publisher, game_id),
+                Error::<T>::GameNotFound
+            );
+            ensure!(!OwnedGames::<T>::contains_key(&who, &game), Error::<T>::GameAlreadyExists);
+
+            Wishlists::<T>::try_mutate(&who, |games| {
+                ensure!(!games.contains(&game), Error::<T>::GameAlreadyWishlisted);
+                games.try_push(game.clone()).map_err(|_| Error::<T>::WishlistFull)
+            })?;
+            WishlistCounts::<T>::mutate(&game, |count| count.saturating_inc());
+
+            Self::deposit_event(Event::WishlistAdded { who, publisher, game_id });
+            Ok(())
+        }
+
+        /// Removes a game from the wishlist.
+        ///
+        /// A `WishlistRemoved` event is emitted once the game is successfully removed.
+        #[pallet::call_index(19)]
+        #[pallet::weight(T::WeightInfo::wishlist_remove())]
+        pub fn wishlist_remove(
+            origin: OriginFor<T>,
+            publisher: PublisherId<T>,
+            game_id: GameId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(
+                Self::wishlist_remove_game(&who, &(publisher.clone(), game_id)),
+                Error::<T>::GameNotWishlisted
+            );
+
+            Self::deposit_event(Event::WishlistRemoved { who, publisher, game_id });
+            Ok(())
+        }
+
+        /// Updates the price of a game.
+        ///
+        /// This function is triggered by the publisher of a game which is not free. The new price
+        /// applies to new purchases only, and does not change the regional prices. A
+        /// `GamePriceSet` event is emitted once the price is successfully updated, followed by a
+        /// `WishlistedGamePriceDropped` event if the game got cheaper and is wishlisted.
+        #[pallet::call_index(20)]
+        #[pallet::weight(T::WeightInfo::game_price_set())]
+        pub fn game_price_set(
+            origin: OriginFor<T>,
+            game_id: GameId,
+            price: CurrencyOf<T>,
+        ) -> DispatchResult {
+            let publisher = ensure_signed(origin)?;
+            ensure!(!price.is_zero(), Error::<T>::GamePriceInvalid);
+            let old_price = Self::effective_price(&publisher, game_id);
+
+            PublishedGames::<T>::try_mutate(&publisher, game_id, |maybe_details| {
+                let details = maybe_details.as_mut().ok_or(Error::<T>::GameNotFound)?;
+                ensure!(details.distribution.set_price(price), Error::<T>::GamePriceInvalid);
+                Ok::<_, Error<T>>(())
+            })?;
+
+            Self::deposit_event(Event::GamePriceSet {
+                publisher: publisher.clone(),
+                game_id,
+                price,
+            });
+            Self::deposit_price_drop((publisher, game_id), old_price);
+            Ok(())
+        }
+
+        /// Sets or removes the discount of a game.
+        ///
+        /// This function is triggered by the publisher of a game which is not free. The discount
+        /// applies to the distribution and regional prices until it ends, and replaces any
+        /// previous discount. A `GameDiscountSet` event is emitted once the discount is
+        /// successfully set or removed, followed by a `WishlistedGamePriceDropped` event if the
+        /// game got cheaper and is wishlisted.
+        #[pallet::call_index(21)]
+        #[pallet::weight(T::WeightInfo::game_discount_set())]
+        pub fn game_discount_set(
+            origin: OriginFor<T>,
+            game_id: GameId,
+            discount: Option<DiscountOf<T>>,
+        ) -> DispatchResult {
+            let publisher = ensure_signed(origin)?;
+            let details =
+                PublishedGames::<T>::get(&publisher, game_id).ok_or(Error::<T>::GameNotFound)?;
+            ensure!(details.distribution.price().is_some(), Error::<T>::GamePriceInvalid);
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(
+                discount
+                    .as_ref()
+                    .is_none_or(|discount| discount.is_valid() && discount.is_active_at(&now)),
+                Error::<T>::DiscountInvalid
+            );
+            let old_price = Self::effective_price(&publisher, game_id);
+
+            GameDiscounts::<T>::set(&publisher, game_id, discount.clone());
+
+            Self::deposit_event(Event::GameDiscountSet {
+                publisher: publisher.clone(),
+                game_id,
+                discount,
+            });
+            Self::deposit_price_drop((publisher, game_id), old_price);
+            Ok(())
+        }
+
+        /// Removes a purchase receipt.
+        ///
+        /// This function is triggered by the buyer of a game to free the storage of their receipt.
+        /// The receipt deposit is released to the buyer. A `ReceiptRemoved` event is emitted once
+        /// the receipt is successfully removed.
+        #[pallet::call_index(22)]
+        #[pallet::weight(T::WeightInfo::receipt_remove())]
+        pub fn receipt_remove(
+            origin: OriginFor<T>,
+            publisher: PublisherId<T>,
+            game_id: GameId,
+        ) -> DispatchResult {
+            let buyer = ensure_signed(origin)?;
+            let receipt = Receipts::<T>::get(&buyer, (&publisher, game_id))
+                .ok_or(Error::<T>::ReceiptNotFound)?;
+
+            Self::receipt_deposit_release(&buyer, receipt.deposit)?;
+            Receipts::<T>::remove(&buyer, (&publisher, game_id));
+            PublisherReceipts::<T>::remove(&publisher, (game_id, &buyer));
+
+            Self::deposit_event(Event::ReceiptRemoved { buyer, publisher, game_id });
+            Ok(())
+        }
+
+        /// Reports whether the CID of a game can be fetched from IPFS.
+        ///
+        /// This unsigned function is submitted by the off-chain worker after fetching the CID of
+        /// the game from the configured IPFS gateway. The report is signed by a registered CID
+        /// reporter, which is checked when the transaction is validated. The game is flagged as
+        /// unreachable, or the flag is cleared once the CID can be fetched again. A
+        /// `GameUnreachable` or `GameReachable` event is emitted accordingly.
+        #[pallet::call_index(23)]
+        #[pallet::weight(T::WeightInfo::game_availability_report())]
+        pub fn game_availability_report(
+            origin: OriginFor<T>,
+            report: AvailabilityReportOf<T>,
+            _signature: T::Signature,
+        ) -> DispatchResult {
+            ensure_none(origin)?;
+            let AvailabilityReport { publisher, game_id, reachable, .. } = report;
+            ensure!(
+                Self::availability_changed(&publisher, game_id, reachable),
+                Error::<T>::AvailabilityUnchanged
+            );
+
+            if reachable {
+                UnreachableGames::<T>::remove((&publisher, game_id));
+                Self::deposit_event(Event::GameReachable { publisher, game_id });
+            } else {
+                let now = frame_system::Pallet::<T>::block_number();
+                UnreachableGames::<T>::insert((&publisher, game_id), now);
+                Self::deposit_event(Event::GameUnreachable { publisher, game_id });
+            }
+            Ok(())
+        }
+
+        /// Sets or removes the build manifest of a game.
+        ///
+        /// This function is triggered by the publisher of a game distributed through IPFS. The
+        /// manifest gives the size and the digest of the build archive under the CID of the game,
+        /// so that buyers can verify their download off-chain. The manifest is attested by the
+        /// signed transaction of the publisher, so no separate signature is stored. A
+        /// `GameBuildSet` event is emitted once the manifest is successfully set or removed.
+        #[pallet::call_index(24)]
+        #[pallet::weight(T::WeightInfo::game_build_set())]
+        pub fn game_build_set(
+            origin: OriginFor<T>,
+            game_id: GameId,
+            build: Option<BuildManifest>,
+        ) -> DispatchResult {
+            let publisher = ensure_signed(origin)?;
+            let details =
+                PublishedGames::<T>::get(&publisher, game_id).ok_or(Error::<T>::GameNotFound)?;
+            let cid = details.distribution.cid().ok_or(Error::<T>::BuildInvalid)?;
+            ensure!(
+                build.as_ref().is_none_or(|build| build.is_valid_for(cid)),
+                Error::<T>::BuildInvalid
+            );
+
+            GameBuilds::<T>::set(&publisher, game_id, build.clone());
+
+            Self::deposit_event(Event::GameBuildSet { publisher, game_id, build });
+            Ok(())
+        }
+
+        /// Settles the pre-order of a released or withdrawn game.
+        ///
+        /// This function is triggered by the buyer when they do not want to wait for their
+        /// pre-order to be settled in the idle time of the blocks. If the game was released, the
+        /// payment is transferred to the publisher and the game is added to the buyer's
+        /// collection, otherwise the payment is refunded. A refund which failed before is retried,
+        /// and the function fails if the refund fails again.
+        #[pallet::call_index(25)]
+        #[pallet::weight(T::WeightInfo::preorder_claim())]
+        pub fn preorder_claim(
+            origin: OriginFor<T>,
+            publisher: PublisherId<T>,
+            game_id: GameId,
+        ) -> DispatchResult {
+            let buyer = ensure_signed(origin)?;
+            let game = (publisher, game_id);
+
+            if let Some(order) = PreOrderFailedRefunds::<T>::take(&game, &buyer) {
+                return Self::refund_preorder(&game, buyer, order);
+            }
+            let settlement =
+                PreOrderSettlements::<T>::get(&game).ok_or(Error::<T>::PreOrderOpen)?;
+            let order = PreOrders::<T>::take(&game, &buyer).ok_or(Error::<T>::OrderNotFound)?;
+            Self::settle_preorder(&game, &settlement, buyer, order)
+        }
+
+        /// Allows or disallows an account to report the availability of games.
+        ///
+        /// This function is triggered by the `CidReporterOrigin`. The off-chain workers sign their
+        /// availability reports with the key of a reporter, and only the reports of the allowed
+        /// accounts are accepted. A `CidReporterSet` event is emitted once the account is set.
+        #[pallet::call_index(26)]
+        #[pallet::weight(T::WeightInfo::cid_reporter_set())]
+        pub fn cid_reporter_set(
+            origin: OriginFor<T>,
+            reporter: AccountIdOf<T>,
+            enabled: bool,
+        ) -> DispatchResult {
+            T::CidReporterOrigin::ensure_origin(origin)?;
+
+            if enabled {
+                CidReporters::<T>::insert(&reporter, ());
+            } else {
+                CidReporters::<T>::remove(&reporter);
+            }
+
+            Self::deposit_event(Event::CidReporterSet { reporter, enabled });
+            Ok(())
+        }
+    }
+
+    #[pallet::validate_unsigned]
+    impl<T: Config> ValidateUnsigned for Pallet<T> {
+        type Call = Call<T>;
+
+        /// Accepts the availability reports signed by a registered CID reporter, made within the
+        /// last check interval and changing the flag of a game. A reporter has at most one
+        /// pending report per game, which is dropped once the next check is due.
+        fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+            let Call::game_availability_report { report, signature } = call else {
+                return InvalidTransaction::Call.into();
+            };
+            let reporter = report.public.clone().into_account();
+            if !CidReporters::<T>::contains_key(&reporter) {
+                return InvalidTransaction::BadSigner.into();
+            }
+            if !SignedPayload::<T>::verify::<T::AuthorityId>(report, signature.clone()) {
+                return InvalidTransaction::BadProof.into();
+            }
+
+            let interval = T::CidCheckInterval::get();
+            let now = frame_system::Pallet::<T>::block_number();
+            if report.block_number > now {
+                return InvalidTransaction::Future.into();
+            }
+            if report.block_number.saturating_add(interval) < now {
+                return InvalidTransaction::Stale.into();
+            }
+            if !Self::availability_changed(&report.publisher, report.game_id, report.reachable) {
+                return InvalidTransaction::Stale.into();
+            }
+
+            ValidTransaction::with_tag_prefix("LiganiteGamesAvailability")
+                .priority(T::UnsignedPriority::get())
+                .and_provides((reporter, &report.publisher, report.game_id))
+                .longevity(interval.saturated_into::<u64>().max(1))
+                .propagate(true)
+                .build()
+        }
+    }
+
+    /// View functions, queried by metadata-aware clients.
+    #[pallet::view_functions]
+    impl<T: Config> Pallet<T> {
+        /// Returns the price of the game with its active discount applied, along with the asset it
+        /// is paid in, or `None` if the game is not found or is free.
+        pub fn game_price(
+            publisher: PublisherId<T>,
+            game_id: GameId,
+        ) -> Option<(CurrencyOf<T>, PaymentAssetOf<T>)> {
+            Self::effective_price(&publisher, game_id)
+        }
+
+        /// Returns whether the account owns the game.
+        pub fn owns_game(
+            account: AccountIdOf<T>,
+            publisher: PublisherId<T>,
+            game_id: GameId,
+        ) -> bool {
+            <Self as OwnershipManager>::is_owner(&account, &publisher, game_id)
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Returns whether the account has access to the game at the given block, either by owning
+    /// it or by an active subscription to a plan including it. Subscriptions give no access to a
+    /// game withdrawn from the catalogue.
+    pub fn has_access(who: &AccountIdOf<T>, game: &GlobalGameId<T>, at: BlockNumberFor<T>) -> bool {
+        let (publisher, game_id) = game;
+
+        OwnedGames::<T>::contains_key(who, game) ||
+            (PublishedGames::<T>::contains_key(publisher, game_id) &&
+                Subscriptions::<T>::iter_prefix(who).any(
+                    |((plan_publisher, plan_id), entitlement)| {
+                        &plan_publisher == publisher &&
+                            entitlement.is_active_at(&at) &&
+                            SubscriptionPlans::<T>::get(publisher, plan_id)
+                                .is_some_and(|plan| plan.games.contains(game_id))
+                    },
+                ))
+    }
+
+    /// Returns the details of the game, or `None` if the game is not found.
+    pub fn game_details(publisher: &PublisherId<T>, game_id: GameId) -> Option<GameDetailsOf<T>> {
+        PublishedGames::<T>::get(publisher, game_id)
+    }
+
+    /// Returns up to `limit` games of the publisher along with their details, continuing after
+    /// the `start_after` game if given.
+    pub fn games_by_publisher(
+        publisher: &PublisherId<T>,
+        start_after: Option<GameId>,
+        limit: u32,
+    ) -> Vec<(GameId, GameDetailsOf<T>)> {
+        let games = match start_after {
+            Some(game_id) => PublishedGames::<T>::iter_prefix_from(
+                publisher,
+                PublishedGames::<T>::hashed_key_for(publisher, game_id),
+            ),
+            None => PublishedGames::<T>::iter_prefix(publisher),
+        };
+
+        games.take(limit as usize).collect()
+    }
+
+    /// Returns the games owned by the account.
+    pub fn owned_games(who: &BuyerId<T>) -> Vec<GlobalGameId<T>> {
+        OwnedGames::<T>::iter_key_prefix(who).collect()
+    }
+
+    /// Returns the sales statistics of the game, by payment asset.
+    pub fn game_sales(
+        publisher: &PublisherId<T>,
+        game_id: GameId,
+    ) -> Vec<(PaymentAssetOf<T>, SalesStatsOf<T>)> {
+        GameSales::<T>::iter_prefix((publisher, game_id)).collect()
+    }
+
+    /// Returns the sales statistics of the publisher games, by payment asset.
+    pub fn publisher_sales(
+        publisher: &PublisherId<T>,
+    ) -> Vec<(PaymentAssetOf<T>, SalesStatsOf<T>)> {
+        PublisherSales::<T>::iter_prefix(publisher).collect()
+    }
+
+    /// Returns the build manifest of the game, or `None` if the game has none.
+    pub fn game_build(publisher: &PublisherId<T>, game_id: GameId) -> Option<BuildManifest> {
+        GameBuilds::<T>::get(publisher, game_id)
+    }
+
+    /// Returns the purchase receipts of the buyer.
+    pub fn receipts_by_buyer(buyer: &BuyerId<T>) -> Vec<(GlobalGameId<T>, ReceiptOf<T>)> {
+        Receipts::<T>::iter_prefix(buyer).collect()
+    }
+
+    /// Returns up to `limit` purchase receipts of the publisher games, along with their game ids
+    /// and buyers, continuing after the `start_after` purchase if given.
+    pub fn receipts_by_publisher(
+        publisher: &PublisherId<T>,
+        start_after: Option<(GameId, BuyerId<T>)>,
+        limit: u32,
+    ) -> Vec<(GameId, BuyerId<T>, ReceiptOf<T>)> {
+        let purchases = match start_after {
+            Some(purchase) => PublisherReceipts::<T>::iter_key_prefix_from(
+                publisher,
+                PublisherReceipts::<T>::hashed_key_for(publisher, purchase),
+            ),
+            None => PublisherReceipts::<T>::iter_key_prefix(publisher),
+        };
+
+        purchases
+            .filter_map(|(game_id, buyer)| {
+                let receipt = Receipts::<T>::get(&buyer, (publisher, game_id))?;
+                Some((game_id, buyer, receipt))
+            })
+            .take(limit as usize)
+            .collect()
+    }
+
+    /// Returns the orders waiting to be fulfilled by the publisher, along with their buyers.
+    pub fn pending_orders(
+        publisher: &PublisherId<T>,
+    ) -> Vec<(GameId, BuyerId<T>, OrderDetailsOf<T>)> {
+        PublisherOrders::<T>::iter_prefix(publisher)
+            .filter_map(|(game_id, buyer)| {
+                let order = BuyerOrders::<T>::get(&buyer, (publisher, game_id))?;
+                Some((game_id, buyer, order))
+            })
+            .collect()
+    }
+
+    /// Returns up to `limit` games suitable for the given age rating and having none of the
+    /// excluded content descriptors, continuing after the `start_after` game if given.
+    pub fn games_by_rating(
+        max_age: AgeRating,
+        excluded: ContentDescriptors,
+        start_after: Option<GlobalGameId<T>>,
+        limit: u32,
+    ) -> Vec<GlobalGameId<T>> {
+        let games = match start_after {
+            Some((publisher, game_id)) => PublishedGames::<T>::iter_from(
+                PublishedGames::<T>::hashed_key_for(publisher, game_id),
+            ),
+            None => PublishedGames::<T>::iter(),
+        };
+
+        games
+            .filter(|(_, _, details)| details.rating.is_allowed(max_age, excluded))
+            .map(|(publisher, game_id, _)| (publisher, game_id))
+            .take(limit as usize)
+            .collect()
+    }
+
+    /// Returns up to `limit` games tagged with all the given tags, continuing after the
+    /// `start_after` game if given. The games are ordered as indexed under the first tag.
+    pub fn games_by_tags(
+        tags: &[TagId],
+        start_after: Option<GlobalGameId<T>>,
+        limit: u32,
+    ) -> Vec<GlobalGameId<T>> {
+        let Some((first, others)) = tags.split_first() else { return Vec::new() };
+
+        let games = match start_after {
+            Some(game) => GamesByTag::<T>::iter_key_prefix_from(
+                first,
+                GamesByTag::<T>::hashed_key_for(first, game),
+            ),
+            None => GamesByTag::<T>::iter_key_prefix(first),
+        };
+
+        games
+            .filter(|game| others.iter().all(|tag_id| GamesByTag::<T>::contains_key(tag_id, game)))
+            .take(limit as usize)
+            .collect()
+    }
+
+    /// Returns up to `limit` games tagged with all the given tags along with their details,
+    /// continuing after the `start_after` game if given.
+    pub fn search_by_tags(
+        tags: &[TagId],
+        start_after: Option<GlobalGameId<T>>,
+        limit: u32,
+    ) -> Vec<(GlobalGameId<T>, GameDetailsOf<T>)> {
+        Self::games_by_tags(tags, start_after, limit)
+            .into_iter()
+            .filter_map(|(publisher, game_id)| {
+                let details = PublishedGames::<T>::get(&publisher, game_id)?;
+                Some(((publisher, game_id), details))
+            })
+            .collect()
+    }
+
+    /// Returns up to `limit` tags with the most community votes on the game, along with their
+    /// votes. Tags with the same number of votes are ordered by id, and a game withdrawn from the
+    /// catalogue has none.
+    pub fn top_tags(game: &GlobalGameId<T>, limit: u32) -> Vec<(TagId, u32)> {
+        let (publisher, game_id) = game;
+        if !PublishedGames::<T>::contains_key(publisher, game_id) {
+            return Vec::new();
+        }
+        let mut tags = TagVotes::<T>::iter_prefix(game).collect::<Vec<_>>();
+        tags.sort_by(|(a_id, a_votes), (b_id, b_votes)| b_votes.cmp(a_votes).then(a_id.cmp(b_id)));
+        tags.truncate(limit as usize);
+        tags
+    }
+
+    /// Returns the taxonomy of the tag, or `None` if the tag cannot be used by a newly added game.
+    pub fn tag_info(tag_id: &TagId) -> Option<TagInfo> {
+        if DeprecatedTags::<T>::contains_key(tag_id) {
+            return None;
+        }
+        TagTaxonomy::<T>::get(tag_id)
+    }
+
+    /// Returns the tags of the given category.
+    pub fn tags_by_category(category: TagCategory) -> Vec<(TagId, Tag)> {
+        TagsByCategory::<T>::iter_key_prefix(category)
+            .filter_map(|tag_id| Tags::<T>::get(tag_id).map(|tag| (tag_id, tag)))
+            .collect()
+    }
+
+    /// Returns all the tags with their names in the given locale, falling back to the default
+    /// name of the tags without a translation.
+    pub fn tags_for_locale(locale: &Locale) -> Vec<(TagId, Tag)> {
+        Tags::<T>::iter()
+            .map(|(tag_id, tag)| (tag_id, TagTranslations::<T>::get(locale, tag_id).unwrap_or(tag)))
+            .collect()
+    }
+
+    /// Returns the pending tag proposals.
+    pub fn tag_proposals() -> Vec<(TagProposalId, TagProposalOf<T>)> {
+        TagProposals::<T>::iter().collect()
+    }
+
+    /// Returns the games in the wishlist of the account, along with their current prices. Games
+    /// withdrawn from the catalogue are left out.
+    pub fn wishlist(who: &BuyerId<T>) -> Vec<WishlistEntryOf<T>> {
+        let now = frame_system::Pallet::<T>::block_number();
+
+        Wishlists::<T>::get(who)
+            .into_iter()
+            .filter(|(publisher, game_id)| PublishedGames::<T>::contains_key(publisher, game_id))
+            .map(|(publisher, game_id)| {
+                let discount = Self::active_discount(&publisher, game_id, now);
+                let price = Self::effective_price(&publisher, game_id);
+                WishlistEntry { publisher, game_id, price, discount }
+            })
+            .collect()
+    }
+
+    /// Returns the distribution price of the game with its active discount applied, along with
+    /// the asset it is paid in, or `None` if the game is not found or is free.
+    pub fn effective_price(
+        publisher: &PublisherId<T>,
+        game_id: GameId,
+    ) -> Option<(CurrencyOf<T>, PaymentAssetOf<T>)> {
+        let (price, asset) = PublishedGames::<T>::get(publisher, game_id)?.distribution.price()?;
+        let now = frame_system::Pallet::<T>::block_number();
+        let discount = Self::active_discount(publisher, game_id, now);
+        Some((Self::apply_discount(price, discount), asset))
+    }
+
+    /// Returns the percentage of the discount of the game active at the given block, if any.
+    fn active_discount(
+        publisher: &PublisherId<T>,
+        game_id: GameId,
+        at: BlockNumberFor<T>,
+    ) -> Option<Percent> {
+        GameDiscounts::<T>::get(publisher, game_id)
+            .filter(|discount| discount.is_active_at(&at))
+            .map(|discount| discount.percent)
+    }
+
+    /// Takes the discount off the price, rounding the discounted price up.
+    fn apply_discount(price: CurrencyOf<T>, discount: Option<Percent>) -> CurrencyOf<T> {
+        discount.map_or(price, |percent| price.saturating_sub(percent.mul_floor(price)))
+    }
+
+    /// Emits a `WishlistedGamePriceDropped` event if the effective price of a wishlisted game is
+    /// lower than the given previous price.
+    fn deposit_price_drop(
+        game: GlobalGameId<T>,
+        old_price: Option<(CurrencyOf<T>, PaymentAssetOf<T>)>,
+    ) {
+        let (publisher, game_id) = game;
+        let (Some((old_price, _)), Some((new_price, asset))) =
+            (old_price, Self::effective_price(&publisher, game_id))
+        else {
+            return;
+        };
+
+        if new_price < old_price && WishlistCounts::<T>::get((&publisher, game_id)) > 0 {
+            Self::deposit_event(Event::WishlistedGamePriceDropped {
+                publisher,
+                game_id,
+                old_price,
+                new_price,
+                asset,
+            });
+        }
+    }
+
+    /// Removes the game from the wishlist of the account. Returns `false` if the game was not
+    /// wishlisted.
+    fn wishlist_remove_game(who: &BuyerId<T>, game: &GlobalGameId<T>) -> bool {
+        let removed = Wishlists::<T>::mutate_exists(who, |maybe_games| {
+            let Some(games) = maybe_games else { return false };
+            let Some(index) = games.iter().position(|wishlisted| wishlisted == game) else {
+                return false;
+            };
+            games.remove(index);
+            if games.is_empty() {
+                *maybe_games = None;
+            }
+            true
+        });
+
+        if removed {
+            WishlistCounts::<T>::mutate_exists(game, |count| {
+                *count = count.map(|count| count.saturating_sub(1)).filter(|count| *count > 0);
+            });
+        }
+        removed
+    }
+
+    /// Adds a tag to the vocabulary, returning its id.
+    fn add_tag(tag: Tag, info: TagInfo) -> Result<TagId, DispatchError> {
+        ensure!(validate::is_non_empty_string(&tag), Error::<T>::TagInvalid);
+        ensure!(!TagIds::<T>::contains_key(&tag), Error::<T>::TagAlreadyExists);
+        ensure!(Self::is_valid_tag_info(&info), Error::<T>::TagInfoInvalid);
+
+        let tag_id = NextTagId::<T>::get();
+        let next_tag_id = tag_id.checked_add(1).ok_or(Error::<T>::TagIdOverflow)?;
+
+        Self::insert_tag(tag_id, tag.clone(), info);
+        NextTagId::<T>::put(next_tag_id);
+
+        Self::deposit_event(Event::TagAdded { tag_id, tag, info });
+        Ok(tag_id)
+    }
+
+    /// Removes an expired tag proposal and returns its deposit. Agenda entries are not removed
+    /// when a proposal is curated, so proposals no longer pending are skipped.
+    fn expire_tag_proposal(proposal_id: TagProposalId) {
+        let Some(proposal) = TagProposals::<T>::take(proposal_id) else { return };
+
+        let _ = T::Currency::release(
+            &HoldReason::TagProposal.into(),
+            &proposal.proposer,
+            proposal.deposit,
+            BestEffort,
+        );
+        Self::deposit_event(Event::TagProposalExpired { proposal_id });
+    }
+
+    /// Checks that the parent of a tag exists and belongs to the category above the tag's one.
+    fn is_valid_tag_info(info: &TagInfo) -> bool {
+        match (info.parent, info.category.parent_category()) {
+            (None, _) => true,
+            (Some(parent), Some(parent_category)) => TagTaxonomy::<T>::get(parent)
+                .is_some_and(|parent_info| parent_info.category == parent_category),
+            (Some(_), None) => false,
+        }
+    }
+
+    /// Stores the predefined tags under their parents, panicking if any tag id or name is
+    /// duplicated or if a parent is invalid. The next added tag gets the id following the highest
+    /// predefined one.
+    fn insert_genesis_tags(tags: &[(TagId, TagCategory, &str)], parents: &[(TagId, TagId)]) {
+        tags.iter().for_each(|(tag_id, category, tag)| {
+            let tag = Tag::try_from(tag.as_bytes().to_vec())
+                .expect("Failed to create tag at genesis build");
+            assert!(!Tags::<T>::contains_key(tag_id), "Duplicate tag id {tag_id} at genesis build");
+            assert!(!TagIds::<T>::contains_key(&tag), "Duplicate tag {tag:?} at genesis build");
+
+            let parent =
+                parents.iter().find(|(child, _)| child == tag_id).map(|(_, parent)| *parent);
+            Self::insert_tag(*tag_id, tag, TagInfo { category: *category, parent });
+        });
+        // The parents are checked once all the tags are stored, as they can be listed after their
+        // children
+        parents.iter().for_each(|(tag_id, _)| {
+            assert!(
+                TagTaxonomy::<T>::get(tag_id).is_some_and(|info| Self::is_valid_tag_info(&info)),
+                "Invalid parent of tag {tag_id} at genesis build"
+            );
+        });
+
+        let next_tag_id = tags.iter().map(|(tag_id, _, _)| tag_id.saturating_add(1)).max();
+        NextTagId::<T>::put(next_tag_id.unwrap_or_default());
+    }
+
+    /// Stores the tag with its taxonomy.
+    fn insert_tag(tag_id: TagId, tag: Tag, info: TagInfo) {
+        TagIds::<T>::insert(&tag, tag_id);
+        Tags::<T>::insert(tag_id, tag);
+        TagTaxonomy::<T>::insert(tag_id, info);
+        TagsByCategory::<T>::insert(info.category, tag_id, ());
+        if let Some(parent) = info.parent {
+            TagChildren::<T>::insert(parent, tag_id, ());
+        }
+    }
+
+    /// Adds the game to the pre-order agenda of the given block.
+    fn schedule_preorder(at: BlockNumberFor<T>, game: GlobalGameId<T>) -> DispatchResult {
+        PreOrderAgenda::<T>::try_mutate(at, |games| {
+            games.try_push(game).map_err(|_| Error::<T>::PreOrderAgendaFull.into())
+        })
+    }
+
+    /// Removes the game from the pre-order agenda of the given block.
+    fn unschedule_preorder(at: BlockNumberFor<T>, game: &GlobalGameId<T>) {
+        PreOrderAgenda::<T>::mutate(at, |games| games.retain(|scheduled| scheduled != game));
+    }
+
+    /// Processes a pre-order release or deadline due at the given block.
+    ///
+    /// The deadline is not removed from the agenda when the game is released before it or the
+    /// pre-orders are cancelled, so the schedule is checked against the block before anything is
+    /// done.
+    fn process_preorder_schedule(n: BlockNumberFor<T>, game: GlobalGameId<T>) {
+        let Some(schedule) = PreOrderSchedules::<T>::get(&game) else { return };
+        let (publisher, game_id) = game.clone();
+
+        match schedule.release {
+            Some(release) if release.at == n => {
+                PublishedGames::<T>::mutate(&publisher, game_id, |details| {
+                    if let Some(details) = details {
+                        if let Distribution::PreOrder { price, asset } =
+                            details.distribution.clone()
+                        {
+                            let cid = release.cid.clone();
+                            details.distribution = Distribution::Instant { price, asset, cid };
+                        }
+                    }
+                });
+                PreOrderSchedules::<T>::remove(&game);
+                PreOrderSettlements::<T>::insert(
+                    &game,
+                    PreOrderSettlement::Release { cid: release.cid },
+                );
+
+                Self::deposit_event(Event::PreOrderReleased { publisher, game_id });
+            },
+            None if schedule.deadline == n => {
+                PreOrderSchedules::<T>::remove(&game);
+                Self::withdraw_game(&publisher, game_id);
+                PreOrderSettlements::<T>::insert(&game, PreOrderSettlement::Refund);
+
+                Self::deposit_event(Event::PreOrderExpired { publisher, game_id });
+            },
+            _ => {},
+        }
+    }
+
+    /// Settles the pending pre-orders within the given weight limit, returning the weight used.
+    pub(crate) fn settle_preorders(limit: Weight) -> Weight {
+        let mut meter = WeightMeter::with_limit(limit);
+        let settle_weight = T::WeightInfo::preorder_settle();
+        let next_weight = T::DbWeight::get().reads_writes(1, 1);
+
+        while meter.try_consume(next_weight).is_ok() {
+            let Some((game, settlement)) = PreOrderSettlements::<T>::iter().next() else { break };
+
+            let mut orders = PreOrders::<T>::drain_prefix(&game);
+            loop {
+                if !meter.can_consume(settle_weight) {
+                    return meter.consumed();
+                }
+                let Some((buyer, order)) = orders.next() else { break };
+
+                meter.consume(settle_weight);
+                // A failed refund is kept aside for the buyer to claim
+                let _ = Self::settle_preorder(&game, &settlement, buyer, order);
+            }
+
+            PreOrderSettlements::<T>::remove(&game);
+        }
+
+        meter.consumed()
+    }
+
+    /// Settles a single pre-order. If the payment cannot be transferred to the publisher, the
+    /// pre-order is refunded instead.
+    fn settle_preorder(
+        game: &GlobalGameId<T>,
+        settlement: &PreOrderSettlement,
+        buyer: BuyerId<T>,
+        order: OrderDetailsOf<T>,
+    ) -> DispatchResult {
+        let (publisher, game_id) = game.clone();
+
+        if let PreOrderSettlement::Release { cid } = settlement {
+            let settled = Self::payment_settle(&buyer, &publisher, &order.asset, order.deposit);
+            if settled.is_ok() {
+                OwnedGames::<T>::insert(&buyer, game, ());
+                Self::sales_record(&publisher, game_id, &order.asset, |stats| {
+                    stats.record_order_sale(DistributionKind::PreOrder, order.deposit)
+                });
+                Self::receipt_issue(&buyer, game.clone(), &order, DistributionKind::PreOrder);
+
+                let cid = cid.clone();
+                let region = order.region;
+                Self::deposit_event(Event::GamePurchased {
+                    buyer,
+                    publisher,
+                    game_id,
+                    cid,
+                    region,
+                });
+                return Ok(());
+            }
+        }
+
+        Self::refund_preorder(game, buyer, order)
+    }
+
+    /// Returns the payment and the receipt deposit of a pre-order to the buyer. Whatever cannot
+    /// be returned is kept in `PreOrderFailedRefunds`, so the buyer can claim it again.
+    fn refund_preorder(
+        game: &GlobalGameId<T>,
+        buyer: BuyerId<T>,
+        mut order: OrderDetailsOf<T>,
+    ) -> DispatchResult {
+        let (publisher, game_id) = game.clone();
+
+        let payment = if order.deposit.is_zero() {
+            Ok(())
+        } else {
+            Self::payment_release(&buyer, &order.asset, order.deposit)
+        };
+        if payment.is_ok() && !order.deposit.is_zero() {
+            Self::sales_record(&publisher, game_id, &order.asset, |stats| {
+                stats.record_order_refund(order.deposit)
+            });
+            order.deposit = Zero::zero();
+        }
+        let receipt_deposit = Self::receipt_deposit_release(&buyer, order.receipt_deposit);
+        if receipt_deposit.is_ok() {
+            order.receipt_deposit = Zero::zero();
+        }
+
+        if let Err(error) = payment.and(receipt_deposit) {
+            Self::deposit_event(Event::PreOrderRefundFailed {
+                buyer: buyer.clone(),
+                publisher,
+                game_id,
+                amount: order.deposit,
+                asset: order.asset.clone(),
+                receipt_deposit: order.receipt_deposit,
+            });
+            PreOrderFailedRefunds::<T>::insert(game, &buyer, order);
+            return Err(error);
+        }
+
+        Self::deposit_event(Event::PreOrderRefunded { buyer, publisher, game_id });
+        Ok(())
+    }
+
+    /// Removes a game whose pre-orders are cancelled or expired from the catalogue, along with
+    /// its tag index entries, its discount and its wishlist count.
+    ///
+    /// The wishlists, tag votes and subscription plans referring to the game are not indexed by
+    /// game, so they are left in place and the withdrawn game is filtered out when they are read.
+    fn withdraw_game(publisher: &PublisherId<T>, game_id: GameId) {
+        if let Some(details) = PublishedGames::<T>::take(publisher, game_id) {
+            details.tags.iter().for_each(|tag_id| {
+                GamesByTag::<T>::remove(tag_id, (publisher, game_id));
+            });
+        }
+        GameDiscounts::<T>::remove(publisher, game_id);
+        WishlistCounts::<T>::remove((publisher, game_id));
+    }
+
+    /// Returns whether the report changes the availability flag of a game distributed through
+    /// IPFS.
+    pub(crate) fn availability_changed(
+        publisher: &PublisherId<T>,
+        game_id: GameId,
+        reachable: bool,
+    ) -> bool {
+        let has_cid = PublishedGames::<T>::get(publisher, game_id)
+            .is_some_and(|details| details.distribution.cid().is_some());
+        has_cid && UnreachableGames::<T>::contains_key((publisher, game_id)) == reachable
+    }
+
+    /// Updates the sales statistics of the game and of its publisher for the payment asset.
+    fn sales_record(
+        publisher: &PublisherId<T>,
+        game_id: GameId,
+        asset: &PaymentAssetOf<T>,
+        record: impl Fn(&mut SalesStatsOf<T>),
+    ) {
+        GameSales::<T>::mutate((publisher, game_id), asset, &record);
+        PublisherSales::<T>::mutate(publisher, asset, record);
+    }
+
+    /// Stores the receipt of a completed purchase, backed by the receipt deposit already held
+    /// from the buyer when the purchase was placed.
+    fn receipt_issue(
+        buyer: &BuyerId<T>,
+        game: GlobalGameId<T>,
+        payment: &OrderDetailsOf<T>,
+        kind: DistributionKind,
+    ) {
+        let receipt = Receipt {
+            price: payment.deposit,
+            publisher_amount: payment.deposit,
+            protocol_amount: Zero::zero(),
+            asset: payment.asset.clone(),
+            region: payment.region,
+            kind,
+            block: frame_system::Pallet::<T>::block_number(),
+            deposit: payment.receipt_deposit,
+        };
+        let (publisher, game_id) = game;
+        Receipts::<T>::insert(buyer, (&publisher, game_id), receipt);
+        PublisherReceipts::<T>::insert(&publisher, (game_id, buyer), ());
+    }
+
+    /// Holds the deposit of the receipt of a purchase from the buyer, returning its amount.
+    fn receipt_deposit_hold(buyer: &BuyerId<T>) -> Result<CurrencyOf<T>, DispatchError> {
+        let deposit = T::ReceiptDeposit::get();
+        if !deposit.is_zero() {
+            T::Currency::hold(&HoldReason::ReceiptDeposit.into(), buyer, deposit)?;
+        }
+        Ok(deposit)
+    }
+
+    /// Releases a deposit held by [`Self::receipt_deposit_hold`] back to the buyer.
+    fn receipt_deposit_release(buyer: &BuyerId<T>, deposit: CurrencyOf<T>) -> DispatchResult {
+        if !deposit.is_zero() {
+            T::Currency::release(&HoldReason::ReceiptDeposit.into(), buyer, deposit, BestEffort)?;
+        }
+        Ok(())
+    }
+
+    /// The account keeping the asset payments of open orders.
+    pub fn account_id() -> AccountIdOf<T> {
+        T::PalletId::get().into_account_truncating()
+    }
+
+    /// Transfers a payment from the buyer to the publisher.
+    fn payment_transfer(
+        buyer: &BuyerId<T>,
+        publisher: &PublisherId<T>,
+        asset: &PaymentAssetOf<T>,
+        amount: CurrencyOf<T>,
+    ) -> DispatchResult {
+        match asset {
+            PaymentAsset::Native => T::Currency::transfer(buyer, publisher, amount, Preserve),
+            PaymentAsset::Asset(id) => {
+                T::Assets::transfer(id.clone(), buyer, publisher, amount, Preserve)
+            },
+        }
+        .map(|_| ())
+    }
+
+    /// Puts a payment of the buyer aside until the order is settled. Native payments are held on
+    /// the buyer's account, while asset payments are kept by the pallet account.
+    fn payment_hold(
+        buyer: &BuyerId<T>,
+        asset: &PaymentAssetOf<T>,
+        amount: CurrencyOf<T>,
+    ) -> DispatchResult {
+        match asset {
+            PaymentAsset::Native => {
+                T::Currency::hold(&HoldReason::GamePayment.into(), buyer, amount)
+            },
+            PaymentAsset::Asset(id) => {
+                T::Assets::transfer(id.clone(), buyer, &Self::account_id(), amount, Preserve)
+                    .map(|_| ())
+            },
+        }
+    }
+
+    /// Returns a payment put aside by [`Self::payment_hold`] back to the buyer.
+    fn payment_release(
+        buyer: &BuyerId<T>,
+        asset: &PaymentAssetOf<T>,
+        amount: CurrencyOf<T>,
+    ) -> DispatchResult {
+        match asset {
+            PaymentAsset::Native => {
+                T::Currency::release(&HoldReason::GamePayment.into(), buyer, amount, BestEffort)
+            },
+            PaymentAsset::Asset(id) => {
+                T::Assets::transfer(id.clone(), &Self::account_id(), buyer, amount, Expendable)
+            },
+        }
+        .map(|_| ())
+    }
+
+    /// Transfers a payment put aside by [`Self::payment_hold`] to the publisher.
+    fn payment_settle(
+        buyer: &BuyerId<T>,
+        publisher: &PublisherId<T>,
+        asset: &PaymentAssetOf<T>,
+        amount: CurrencyOf<T>,
+    ) -> DispatchResult {
+        match asset {
+            PaymentAsset::Native => T::Currency::transfer_on_hold(
+                &HoldReason::GamePayment.into(),
+                buyer,
+                publisher,
+                amount,
+                BestEffort,
+                Free,
+                Polite,
+            ),
+            PaymentAsset::Asset(id) => {
+                T::Assets::transfer(id.clone(), &Self::account_id(), publisher, amount, Expendable)
+            },
+        }
+        .map(|_| ())
+    }
+}
+
+impl<T: Config> OwnershipManager for Pallet<T> {
+    type AccountId = AccountIdOf<T>;
+
+    fn is_owner(who: &AccountIdOf<T>, publisher: &PublisherId<T>, game_id: GameId) -> bool {
+        OwnedGames::<T>::contains_key(who, (publisher, game_id))
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn insert_owner(who: &AccountIdOf<T>, publisher: &PublisherId<T>, game_id: GameId) {
+        OwnedGames::<T>::insert(who, (publisher, game_id), ());
     }
 }
@@ -0,0 +1,179 @@
+//! Storage migrations of the games pallet.
+
+use super::*;
+use alloc::collections::BTreeMap;
+use frame_support::{migrations::VersionedMigration, traits::UncheckedOnRuntimeUpgrade};
+use liganite_primitives::types::{GameTags, Name};
+
+#[cfg(feature = "try-runtime")]
+use frame_support::sp_runtime::TryRuntimeError;
+
+/// The storage layout of the pallet before it was versioned.
+pub mod v0 {
+    use super::*;
+
+    #[derive(Clone, Eq, PartialEq, Debug, Encode, Decode)]
+    pub enum Distribution<Currency> {
+        Free { cid: Cid },
+        Instant { price: Currency, cid: Cid },
+        Individual { price: Currency },
+    }
+
+    impl<Currency> Distribution<Currency> {
+        /// Converts the distribution, priced in the native currency.
+        pub fn migrate<AssetId>(self) -> super::Distribution<Currency, AssetId> {
+            let asset = PaymentAsset::Native;
+            match self {
+                Distribution::Free { cid } => super::Distribution::Free { cid },
+                Distribution::Instant { price, cid } => {
+                    super::Distribution::Instant { price, asset, cid }
+                },
+                Distribution::Individual { price } => {
+                    super::Distribution::Individual { price, asset }
+                },
+            }
+        }
+    }
+
+    #[derive(Clone, Eq, PartialEq, Debug, Encode, Decode)]
+    pub struct GameDetails<Currency> {
+        pub name: Name,
+        pub tags: GameTags,
+        pub distribution: Distribution<Currency>,
+    }
+
+    #[derive(Clone, Eq, PartialEq, Debug, Encode, Decode)]
+    pub struct OrderDetails<Currency> {
+        pub deposit: Currency,
+    }
+}
+
+/// Migrates the storage from the unversioned layout, use [`MigrateV0ToV1`] instead.
+///
+/// - The predefined tags are indexed by name, category and parent. The duplicated tags removed from
+///   the list are merged into the tag of the same name.
+/// - The games are priced in the native currency, without regional prices nor content rating, and
+///   indexed by tag.
+/// - The orders are paid in the native currency, without a region nor a receipt deposit.
+/// - The pallet account is kept alive to receive asset payments.
+pub struct UncheckedMigrateV0ToV1<T>(PhantomData<T>);
+
+impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateV0ToV1<T> {
+    fn on_runtime_upgrade() -> Weight {
+        let mut reads = 0u64;
+        let mut writes = 0u64;
+
+        // The tags of the previous list are stored again under the ids of the current one, and
+        // the duplicated tags are merged into the tag of the same name
+        let ids = TAGS
+            .iter()
+            .map(|(tag_id, _, tag)| (tag.as_bytes(), *tag_id))
+            .collect::<BTreeMap<_, _>>();
+        let mut merged = BTreeMap::new();
+        let mut unknown = Vec::new();
+        for (tag_id, tag) in Tags::<T>::iter().collect::<Vec<_>>() {
+            reads.saturating_inc();
+            writes.saturating_inc();
+            Tags::<T>::remove(tag_id);
+            match ids.get(tag.as_slice()) {
+                Some(current_id) if *current_id != tag_id => {
+                    merged.insert(tag_id, *current_id);
+                },
+                Some(_) => {},
+                None => unknown.push((tag_id, tag)),
+            }
+        }
+        Pallet::<T>::insert_genesis_tags(TAGS, TAG_PARENTS);
+        writes.saturating_accrue((TAGS.len() * 4 + TAG_PARENTS.len()) as u64 + 1);
+
+        // Tags missing from the current list are kept for the games tagged with them, under a new
+        // id, but can no longer be given to a game
+        for (tag_id, tag) in unknown {
+            let current_id = NextTagId::<T>::mutate(|next_tag_id| {
+                let current_id = *next_tag_id;
+                *next_tag_id = next_tag_id.saturating_add(1);
+                current_id
+            });
+            let info = TagInfo { category: TagCategory::Other, parent: None };
+            Pallet::<T>::insert_tag(current_id, tag, info);
+            DeprecatedTags::<T>::insert(current_id, ());
+            merged.insert(tag_id, current_id);
+            writes.saturating_accrue(6);
+        }
+
+        PublishedGames::<T>::translate::<v0::GameDetails<CurrencyOf<T>>, _>(
+            |publisher, game_id, old| {
+                let mut tags = GameTags::new();
+                old.tags.iter().map(|tag_id| merged.get(tag_id).unwrap_or(tag_id)).for_each(
+                    |tag_id| {
+                        if !tags.contains(tag_id) {
+                            // Cannot exceed the bound, as no tag is added
+                            let _ = tags.try_push(*tag_id);
+                        }
+                    },
+                );
+                tags.iter().for_each(|tag_id| {
+                    GamesByTag::<T>::insert(tag_id, (&publisher, game_id), ());
+                });
+                reads.saturating_inc();
+                writes.saturating_accrue(1 + tags.len() as u64);
+
+                Some(GameDetails {
+                    name: old.name,
+                    tags,
+                    distribution: old.distribution.migrate(),
+                    regional_prices: Default::default(),
+                    rating: Default::default(),
+                })
+            },
+        );
+
+        BuyerOrders::<T>::translate::<v0::OrderDetails<CurrencyOf<T>>, _>(|_, _, old| {
+            reads.saturating_inc();
+            writes.saturating_inc();
+            Some(OrderDetails {
+                deposit: old.deposit,
+                asset: PaymentAsset::Native,
+                region: None,
+                receipt_deposit: Zero::zero(),
+            })
+        });
+
+        frame_system::Pallet::<T>::inc_providers(&Pallet::<T>::account_id());
+        writes.saturating_inc();
+
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+        let games = PublishedGames::<T>::iter_keys().count() as u32;
+        let orders = BuyerOrders::<T>::iter_keys().count() as u32;
+        Ok((games, orders).encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+        let (games, orders) = <(u32, u32)>::decode(&mut &state[..])
+            .map_err(|_| TryRuntimeError::Other("Failed to decode the pre-upgrade state"))?;
+
+        ensure!(
+            PublishedGames::<T>::iter().count() as u32 == games,
+            TryRuntimeError::Other("Games could not be migrated")
+        );
+        ensure!(
+            BuyerOrders::<T>::iter().count() as u32 == orders,
+            TryRuntimeError::Other("Orders could not be migrated")
+        );
+        Ok(())
+    }
+}
+
+/// Migrates the storage from the unversioned layout to version 1.
+pub type MigrateV0ToV1<T> = VersionedMigration<
+    0,
+    1,
+    UncheckedMigrateV0ToV1<T>,
+    Pallet<T>,
+    <T as frame_system::Config>::DbWeight,
+>;
@@ -1,14 +1,26 @@
 use crate as liganite_games;
 use frame_support::{
-    derive_impl,
-    traits::{ConstU64, VariantCountOf},
+    derive_impl, parameter_types,
+    traits::{AsEnsureOriginWithArg, ConstU32, ConstU64, Hooks, VariantCountOf},
+    weights::Weight,
+    PalletId,
 };
+use frame_system::{EnsureRoot, EnsureSigned};
 use liganite_primitives::{
     publisher::PublisherManager,
     testing::bounded_vec,
     types::{BuyerId, PublisherDetails, PublisherId},
+    validate::CidPolicy,
+};
+use sp_core::offchain::{
+    testing::{TestOffchainExt, TestTransactionPoolExt},
+    OffchainDbExt, OffchainWorkerExt, TransactionPoolExt,
+};
+use sp_runtime::{
+    testing::{TestSignature, UintAuthorityId},
+    transaction_validity::TransactionPriority,
+    BuildStorage,
 };
-use sp_runtime::BuildStorage;
 
 type Block = frame_system::mocking::MockBlock<Test>;
 pub type Balance = u64;
@@ -39,9 +51,12 @@ mod runtime {
     pub type Balances = pallet_balances::Pallet<Test>;
 
     #[runtime::pallet_index(2)]
-    pub type Publish = liganite_publish::Pallet<Test>;
+    pub type Assets = pallet_assets::Pallet<Test>;
 
     #[runtime::pallet_index(3)]
+    pub type Publish = liganite_publish::Pallet<Test>;
+
+    #[runtime::pallet_index(4)]
     pub type Games = liganite_games::Pallet<Test>;
 }
 
@@ -68,6 +83,14 @@ impl pallet_balances::Config for Test {
     type DoneSlashHandler = ();
 }
 
+#[derive_impl(pallet_assets::config_preludes::TestDefaultConfig)]
+impl pallet_assets::Config for Test {
+    type Balance = Balance;
+    type Currency = Balances;
+    type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<Self::AccountId>>;
+    type ForceOrigin = EnsureRoot<Self::AccountId>;
+}
+
 impl liganite_publish::Config for Test {
     type WeightInfo = ();
     type RuntimeEvent = RuntimeEvent;
@@ -80,9 +103,73 @@ impl liganite_games::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type RuntimeHoldReason = RuntimeHoldReason;
     type Currency = Balances;
+    type AssetId = u32;
+    type Assets = Assets;
+    type PalletId = GamesPalletId;
     type PublisherManager = Publish;
+    type PreOrderDeadline = ConstU64<PRE_ORDER_DEADLINE>;
+    type MaxPreOrdersScheduledPerBlock = ConstU32<2>;
+    type TagOrigin = EnsureRoot<Self::AccountId>;
+    type TagCuratorOrigin = EnsureRoot<Self::AccountId>;
+    type TagProposalDeposit = ConstU64<TAG_PROPOSAL_DEPOSIT>;
+    type TagProposalPeriod = ConstU64<TAG_PROPOSAL_PERIOD>;
+    type MaxTagProposalsExpiringPerBlock = ConstU32<2>;
+    type MaxTagVotesPerGame = ConstU32<2>;
+    type MaxWishlistSize = ConstU32<2>;
+    type ReceiptDeposit = ReceiptDeposit;
+    type CidPolicy = GamesCidPolicy;
+    type CidCheckInterval = ConstU64<CID_CHECK_INTERVAL>;
+    type MaxCidChecksPerRun = ConstU32<2>;
+    type UnsignedPriority = ConstU64<{ TransactionPriority::MAX }>;
+    type AuthorityId = TestAuthId;
+    type CidReporterOrigin = EnsureRoot<Self::AccountId>;
+}
+
+/// The key signing the availability reports in the tests.
+pub struct TestAuthId;
+
+impl frame_system::offchain::AppCrypto<UintAuthorityId, TestSignature> for TestAuthId {
+    type RuntimeAppPublic = UintAuthorityId;
+    type GenericPublic = UintAuthorityId;
+    type GenericSignature = TestSignature;
+}
+
+impl frame_system::offchain::SigningTypes for Test {
+    type Public = UintAuthorityId;
+    type Signature = TestSignature;
+}
+
+pub type Extrinsic = sp_runtime::testing::TestXt<RuntimeCall, ()>;
+
+impl<LocalCall> frame_system::offchain::CreateTransactionBase<LocalCall> for Test
+where
+    RuntimeCall: From<LocalCall>,
+{
+    type Extrinsic = Extrinsic;
+    type RuntimeCall = RuntimeCall;
 }
 
+impl<LocalCall> frame_system::offchain::CreateInherent<LocalCall> for Test
+where
+    RuntimeCall: From<LocalCall>,
+{
+    fn create_inherent(call: RuntimeCall) -> Extrinsic {
+        Extrinsic::new_bare(call)
+    }
+}
+
+parameter_types! {
+    pub const GamesPalletId: PalletId = PalletId(*b"lg/games");
+    pub static ReceiptDeposit: Balance = 0;
+    pub const GamesCidPolicy: CidPolicy<'static> = CidPolicy::PINNING;
+}
+
+pub const PRE_ORDER_DEADLINE: u64 = 100;
+pub const ASSET_ID: u32 = 1;
+pub const TAG_PROPOSAL_DEPOSIT: Balance = 10_000;
+pub const TAG_PROPOSAL_PERIOD: u64 = 50;
+pub const CID_CHECK_INTERVAL: u64 = 10;
+
 pub const PUBLISHER_DEPOSIT: Balance = 1_000_000;
 pub const INITIAL_BALANCE: Balance = 1_000_000_000;
 
@@ -90,6 +177,7 @@ pub const INVALID_PUBLISHER: PublisherId<Test> = 0;
 pub const PUBLISHER: PublisherId<Test> = 1;
 pub const FUNDED_BUYER: BuyerId<Test> = 11;
 pub const NON_FUNDED_BUYER: BuyerId<Test> = 12;
+pub const REPORTER: u64 = 21;
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -102,6 +190,17 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
     .assimilate_storage(&mut storage)
     .unwrap();
 
+    pallet_assets::GenesisConfig::<Test> {
+        assets: vec![(ASSET_ID, PUBLISHER, true, 1)],
+        accounts: vec![
+            (ASSET_ID, PUBLISHER, INITIAL_BALANCE),
+            (ASSET_ID, FUNDED_BUYER, INITIAL_BALANCE),
+        ],
+        ..Default::default()
+    }
+    .assimilate_storage(&mut storage)
+    .unwrap();
+
     liganite_publish::GenesisConfig::<Test> { publisher_deposit: PUBLISHER_DEPOSIT }
         .assimilate_storage(&mut storage)
         .unwrap();
@@ -125,3 +224,27 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
     ext.execute_with(|| System::set_block_number(1));
     ext
 }
+
+/// Builds the genesis storage with the offchain worker extensions, serving the HTTP requests
+/// from the stub `offchain` and submitting the transactions to the stub `pool`. The keystore
+/// holds the key of the [`REPORTER`].
+pub fn new_offchain_test_ext(
+    offchain: TestOffchainExt,
+    pool: TestTransactionPoolExt,
+) -> sp_io::TestExternalities {
+    UintAuthorityId::set_all_keys(vec![REPORTER]);
+    let mut ext = new_test_ext();
+    ext.register_extension(OffchainDbExt::new(offchain.clone()));
+    ext.register_extension(OffchainWorkerExt::new(offchain));
+    ext.register_extension(TransactionPoolExt::new(pool));
+    ext
+}
+
+/// Runs the pallet hooks up to the given block.
+pub fn run_to_block(n: u64) {
+    while System::block_number() < n {
+        Games::on_idle(System::block_number(), Weight::MAX);
+        System::set_block_number(System::block_number() + 1);
+        Games::on_initialize(System::block_number());
+    }
+}
@@ -0,0 +1,171 @@
+//! Off-chain worker checking that the CIDs of the games can be fetched from IPFS.
+//!
+//! Every `CidCheckInterval` blocks, the CIDs of up to `MaxCidChecksPerRun` games are fetched from
+//! the IPFS gateway set in the offchain storage, continuing after the last checked game. The games
+//! whose availability changed are reported with unsigned transactions, carrying a payload signed
+//! with the [`KEY_TYPE`] key of a reporter registered on-chain. The CIDs are not checked if the
+//! keystore of the node holds no such key.
+
+use super::*;
+use alloc::{format, string::String};
+use frame_support::sp_runtime::{
+    offchain::{
+        http,
+        storage::StorageValueRef,
+        storage_lock::{StorageLock, Time},
+        Duration, StorageKind,
+    },
+    KeyTypeId,
+};
+use frame_system::offchain::{SendUnsignedTransaction, SignedPayload, Signer, SigningTypes};
+
+/// The key of the persistent offchain storage holding the base URL of the IPFS gateway the CIDs
+/// are fetched from, such as `http://127.0.0.1:8080`. The CIDs are not checked if it is unset.
+pub const IPFS_GATEWAY_KEY: &[u8] = b"liganite-games::ipfs-gateway";
+
+/// The key of the persistent offchain storage holding the last checked game.
+const CURSOR_KEY: &[u8] = b"liganite-games::cid-check-cursor";
+
+/// The key of the lock preventing overlapping checks.
+const LOCK_KEY: &[u8] = b"liganite-games::cid-check-lock";
+
+/// The time allowed to the gateway to answer a request, in milliseconds.
+const FETCH_TIMEOUT: u64 = 10_000;
+
+/// The key type of the keys signing the availability reports.
+pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"lgcr");
+
+/// The sr25519 keys signing the availability reports.
+pub mod crypto {
+    use super::KEY_TYPE;
+    use frame_support::sp_runtime::{
+        app_crypto::{app_crypto, sr25519},
+        MultiSignature, MultiSigner,
+    };
+
+    app_crypto!(sr25519, KEY_TYPE);
+
+    /// The key of a CID reporter, as used by a runtime with [`MultiSignature`] signatures.
+    pub struct ReporterId;
+
+    impl frame_system::offchain::AppCrypto<MultiSigner, MultiSignature> for ReporterId {
+        type RuntimeAppPublic = Public;
+        type GenericPublic = sr25519::Public;
+        type GenericSignature = sr25519::Signature;
+    }
+}
+
+/// The report of the availability of a game, signed by a CID reporter.
+#[derive(Clone, Eq, PartialEq, Debug, Encode, Decode, DecodeWithMemTracking, TypeInfo)]
+pub struct AvailabilityReport<Public, AccountId, BlockNumber> {
+    /// The publisher of the game.
+    pub publisher: AccountId,
+    /// The game id.
+    pub game_id: GameId,
+    /// Whether the CID of the game could be fetched.
+    pub reachable: bool,
+    /// The block at which the CID was fetched.
+    pub block_number: BlockNumber,
+    /// The key of the reporter signing the report.
+    pub public: Public,
+}
+
+impl<T: SigningTypes> SignedPayload<T>
+    for AvailabilityReport<T::Public, T::AccountId, BlockNumberFor<T>>
+{
+    fn public(&self) -> T::Public {
+        self.public.clone()
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Fetches the CIDs of the next games and reports the changes of their availability.
+    pub(crate) fn cids_check(block_number: BlockNumberFor<T>) {
+        let Some(gateway) =
+            sp_io::offchain::local_storage_get(StorageKind::PERSISTENT, IPFS_GATEWAY_KEY)
+        else {
+            return;
+        };
+        let signer = Signer::<T, T::AuthorityId>::any_account();
+        if !signer.can_sign() {
+            return;
+        }
+
+        let max = T::MaxCidChecksPerRun::get();
+        let expiration = Duration::from_millis(FETCH_TIMEOUT.saturating_mul(max.into()));
+        let mut lock = StorageLock::<Time>::with_deadline(LOCK_KEY, expiration);
+        let Ok(_guard) = lock.try_lock() else { return };
+
+        let cursor = StorageValueRef::persistent(CURSOR_KEY);
+        let games = match cursor.get::<GlobalGameId<T>>() {
+            Ok(Some((publisher, game_id))) => PublishedGames::<T>::iter_from(
+                PublishedGames::<T>::hashed_key_for(publisher, game_id),
+            ),
+            _ => PublishedGames::<T>::iter(),
+        };
+        let games: Vec<_> = games.take(max as usize).collect();
+
+        let mut last = None;
+        let checked = games.len();
+        for (publisher, game_id, details) in games {
+            let reachable =
+                details.distribution.cid().and_then(|cid| Self::cid_fetch(&gateway, cid));
+            if let Some(reachable) = reachable {
+                if Self::availability_changed(&publisher, game_id, reachable) {
+                    let game = (publisher.clone(), game_id);
+                    Self::availability_submit(&signer, game, reachable, block_number);
+                }
+            }
+            last = Some((publisher, game_id));
+        }
+
+        // Start over from the first game once all the games have been checked
+        match last {
+            Some(last) if checked == max as usize => cursor.set(&last),
+            _ => cursor.clear(),
+        }
+    }
+
+    /// Fetches the CID from the gateway, returning whether it is reachable, or `None` if the
+    /// gateway cannot be queried.
+    fn cid_fetch(gateway: &[u8], cid: &Cid) -> Option<bool> {
+        let url = format!(
+            "{}/ipfs/{}",
+            String::from_utf8_lossy(gateway).trim_end_matches('/'),
+            String::from_utf8_lossy(cid)
+        );
+        let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(FETCH_TIMEOUT));
+        let pending = http::Request::new(&url)
+            .method(http::Method::Other("HEAD"))
+            .deadline(deadline)
+            .send()
+            .ok()?;
+
+        match pending.try_wait(deadline) {
+            Ok(Ok(response)) => Some((200..300).contains(&response.code)),
+            Ok(Err(http::Error::DeadlineReached)) | Err(_) => Some(false),
+            Ok(Err(_)) => None,
+        }
+    }
+
+    /// Submits an unsigned transaction reporting the availability of the game, signed by the
+    /// first key of the signer.
+    fn availability_submit(
+        signer: &Signer<T, T::AuthorityId>,
+        game: GlobalGameId<T>,
+        reachable: bool,
+        block_number: BlockNumberFor<T>,
+    ) {
+        let (publisher, game_id) = game;
+        let _ = signer.send_unsigned_transaction(
+            |account| AvailabilityReport {
+                publisher: publisher.clone(),
+                game_id,
+                reachable,
+                block_number,
+                public: account.public.clone(),
+            },
+            |report, signature| Call::game_availability_report { report, signature },
+        );
+    }
+}
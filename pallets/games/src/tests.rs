@@ -1,12 +1,64 @@
 use crate::{
-    mock::*, BuyerOrders, Error, Event, HoldReason, OwnedGames, PublishedGames, PublisherOrders,
+    migrations::{v0, MigrateV0ToV1},
+    mock::*,
+    AvailabilityReport, BuyerOrders, CidReporters, DeprecatedTags, Error, Event, GameBuilds,
+    GameDiscounts, GameSales, GamesByTag, HoldReason, NextTagId, OwnedGames, OwnerTagVotes,
+    PreOrderAgenda, PreOrderFailedRefunds, PreOrderSchedules, PreOrderSettlements, PreOrders,
+    PublishedGames, PublisherOrders, PublisherReceipts, PublisherSales, Receipts,
+    SubscriptionPlans, Subscriptions, TagChildren, TagIds, TagTaxonomy, TagTranslations, TagVotes,
+    Tags, TagsByCategory, UnreachableGames, WishlistCounts, Wishlists, IPFS_GATEWAY_KEY,
+};
+use frame_support::{
+    assert_noop, assert_ok,
+    pallet_prelude::{InvalidTransaction, TransactionSource, ValidateUnsigned},
+    storage::unhashed,
+    traits::{fungible, fungibles, GetStorageVersion, Hooks, OnRuntimeUpgrade, StorageVersion},
 };
-use frame_support::{assert_noop, assert_ok, traits::fungible};
 use liganite_primitives::{
+    ownership::OwnershipManager,
+    tags::{TAGS, TAG_PARENTS},
     testing::bounded_vec,
-    types::{Distribution, GameDetails, OrderDetails},
+    types::{
+        AgeRating, BuildManifest, BuyerId, Cid, ContentDescriptors, ContentRating, Discount,
+        Distribution, DistributionKind, GameDetails, GameId, Locale, Name, OrderDetails,
+        PaymentAsset, PlanId, PreOrderRelease, PreOrderSchedule, PreOrderSettlement, PublisherId,
+        Receipt, SalesStats, SubscriptionPlan, Tag, TagCategory, TagId, TagInfo, TagProposal,
+        UnitsSold, WishlistEntry,
+    },
+};
+use scale_codec::{Decode, Encode};
+use sp_core::offchain::{
+    testing::{OffchainState, PendingRequest, TestOffchainExt, TestTransactionPoolExt},
+    StorageKind,
 };
-use sp_runtime::TokenError;
+use sp_runtime::{
+    testing::{TestSignature, UintAuthorityId},
+    DispatchError, Percent, TokenError,
+};
+
+/// The CID of the games distributed through IPFS.
+const GAME_CID: &[u8] = b"QmYwAPJzv5CZsnAztbCxjRMoa6zFzFG8pGzLFZxojtL8MX";
+
+/// Publishes a game of the publisher, distributed the given way at the given price in the native
+/// currency. Games distributed through IPFS are given [`GAME_CID`].
+fn add_game(game_id: GameId, kind: DistributionKind, price: Balance) {
+    let cid: Cid = bounded_vec(GAME_CID);
+    let asset = PaymentAsset::Native;
+    let distribution = match kind {
+        DistributionKind::Free => Distribution::Free { cid },
+        DistributionKind::Instant => Distribution::Instant { price, asset, cid },
+        DistributionKind::Individual => Distribution::Individual { price, asset },
+        DistributionKind::PreOrder => Distribution::PreOrder { price, asset },
+    };
+    let details = GameDetails {
+        name: bounded_vec(b"Example Game"),
+        tags: Default::default(),
+        distribution,
+        regional_prices: Default::default(),
+        rating: Default::default(),
+    };
+    assert_ok!(Games::game_add(RuntimeOrigin::signed(PUBLISHER), game_id, details));
+}
 
 #[test]
 fn test_game_add() {
@@ -17,8 +69,11 @@ fn test_game_add() {
             tags: bounded_vec(&[1, 2, 3]),
             distribution: Distribution::Instant {
                 price: 1234,
+                asset: PaymentAsset::Native,
                 cid: bounded_vec(b"QmYwAPJzv5CZsnAztbCxjRMoa6zFzFG8pGzLFZxojtL8MX"),
             },
+            regional_prices: Default::default(),
+            rating: Default::default(),
         };
         assert_ok!(Games::game_add(RuntimeOrigin::signed(PUBLISHER), game_id, details.clone()));
 
@@ -36,8 +91,11 @@ fn test_game_add_game_already_exists() {
             tags: bounded_vec(&[1, 2, 3]),
             distribution: Distribution::Instant {
                 price: 1234,
+                asset: PaymentAsset::Native,
                 cid: bounded_vec(b"QmYwAPJzv5CZsnAztbCxjRMoa6zFzFG8pGzLFZxojtL8MX"),
             },
+            regional_prices: Default::default(),
+            rating: Default::default(),
         };
         PublishedGames::<Test>::insert(PUBLISHER, game_id, details.clone());
 
@@ -57,8 +115,11 @@ fn test_game_add_invalid_publisher() {
             tags: bounded_vec(&[1, 2, 3]),
             distribution: Distribution::Instant {
                 price: 1234,
+                asset: PaymentAsset::Native,
                 cid: bounded_vec(b"QmYwAPJzv5CZsnAztbCxjRMoa6zFzFG8pGzLFZxojtL8MX"),
             },
+            regional_prices: Default::default(),
+            rating: Default::default(),
         };
         assert_noop!(
             Games::game_add(RuntimeOrigin::signed(INVALID_PUBLISHER), game_id, details),
@@ -67,6 +128,26 @@ fn test_game_add_invalid_publisher() {
     });
 }
 
+#[test]
+fn test_game_add_cid_policy() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: bounded_vec(&[1, 2, 3]),
+            distribution: Distribution::Free {
+                cid: bounded_vec(b"bafyreigh2akiscaildcqabsyg3dfr6chu3fgpregiymsck7e7aqa4s52zy"),
+            },
+            regional_prices: Default::default(),
+            rating: Default::default(),
+        };
+        assert_noop!(
+            Games::game_add(RuntimeOrigin::signed(PUBLISHER), game_id, details),
+            Error::<Test>::GameDetailsInvalid
+        );
+    });
+}
+
 #[test]
 fn test_game_add_empty_name() {
     new_test_ext().execute_with(|| {
@@ -76,8 +157,11 @@ fn test_game_add_empty_name() {
             tags: bounded_vec(&[1, 2, 3]),
             distribution: Distribution::Instant {
                 price: 1234,
+                asset: PaymentAsset::Native,
                 cid: bounded_vec(b"QmYwAPJzv5CZsnAztbCxjRMoa6zFzFG8pGzLFZxojtL8MX"),
             },
+            regional_prices: Default::default(),
+            rating: Default::default(),
         };
         assert_noop!(
             Games::game_add(RuntimeOrigin::signed(PUBLISHER), game_id, details),
@@ -95,14 +179,23 @@ fn test_game_buy_free() {
             name: bounded_vec(b"Example Game"),
             tags: Default::default(),
             distribution: Distribution::Free { cid: cid.clone() },
+            regional_prices: Default::default(),
+            rating: Default::default(),
         };
         PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
 
-        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id, None));
         assert_eq!(OwnedGames::<Test>::get(FUNDED_BUYER, (PUBLISHER, game_id)), Some(()));
 
         System::assert_last_event(
-            Event::GamePurchased { buyer: FUNDED_BUYER, publisher: PUBLISHER, game_id, cid }.into(),
+            Event::GamePurchased {
+                buyer: FUNDED_BUYER,
+                publisher: PUBLISHER,
+                game_id,
+                cid,
+                region: None,
+            }
+            .into(),
         );
     });
 }
@@ -116,11 +209,17 @@ fn test_game_buy_instant() {
         let details = GameDetails {
             name: bounded_vec(b"Example Game"),
             tags: Default::default(),
-            distribution: Distribution::Instant { price, cid: cid.clone() },
+            distribution: Distribution::Instant {
+                price,
+                asset: PaymentAsset::Native,
+                cid: cid.clone(),
+            },
+            regional_prices: Default::default(),
+            rating: Default::default(),
         };
         PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
 
-        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id, None));
         assert_eq!(OwnedGames::<Test>::get(FUNDED_BUYER, (PUBLISHER, game_id)), Some(()));
         assert_eq!(
             <Balances as fungible::Inspect<_>>::balance(&FUNDED_BUYER),
@@ -132,7 +231,14 @@ fn test_game_buy_instant() {
         );
 
         System::assert_last_event(
-            Event::GamePurchased { buyer: FUNDED_BUYER, publisher: PUBLISHER, game_id, cid }.into(),
+            Event::GamePurchased {
+                buyer: FUNDED_BUYER,
+                publisher: PUBLISHER,
+                game_id,
+                cid,
+                region: None,
+            }
+            .into(),
         );
     });
 }
@@ -145,13 +251,20 @@ fn test_game_buy_individual() {
         let details = GameDetails {
             name: bounded_vec(b"Example Game"),
             tags: Default::default(),
-            distribution: Distribution::Individual { price },
+            distribution: Distribution::Individual { price, asset: PaymentAsset::Native },
+            regional_prices: Default::default(),
+            rating: Default::default(),
         };
         PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
 
-        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id, None));
 
-        let expected = OrderDetails { deposit: price };
+        let expected = OrderDetails {
+            deposit: price,
+            asset: PaymentAsset::Native,
+            region: None,
+            receipt_deposit: 0,
+        };
         assert_eq!(BuyerOrders::<Test>::get(FUNDED_BUYER, (PUBLISHER, game_id)), Some(expected));
         assert_eq!(PublisherOrders::<Test>::get(PUBLISHER, game_id), Some(FUNDED_BUYER));
         assert_eq!(
@@ -166,7 +279,360 @@ fn test_game_buy_individual() {
             price
         );
         System::assert_last_event(
-            Event::OrderPlaced { buyer: FUNDED_BUYER, publisher: PUBLISHER, game_id }.into(),
+            Event::OrderPlaced { buyer: FUNDED_BUYER, publisher: PUBLISHER, game_id, region: None }
+                .into(),
+        );
+    })
+}
+
+#[test]
+fn test_game_buy_instant_asset() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let price = 12345;
+        let cid = bounded_vec(b"QmYwAPJzv5CZsnAztbCxjRMoa6zFzFG8pGzLFZxojtL8MX");
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: Default::default(),
+            distribution: Distribution::Instant {
+                price,
+                asset: PaymentAsset::Asset(ASSET_ID),
+                cid: cid.clone(),
+            },
+            regional_prices: Default::default(),
+            rating: Default::default(),
+        };
+        PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
+
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id, None));
+        assert_eq!(OwnedGames::<Test>::get(FUNDED_BUYER, (PUBLISHER, game_id)), Some(()));
+        assert_eq!(Assets::balance(ASSET_ID, FUNDED_BUYER), INITIAL_BALANCE - price);
+        assert_eq!(Assets::balance(ASSET_ID, PUBLISHER), INITIAL_BALANCE + price);
+        assert_eq!(<Balances as fungible::Inspect<_>>::balance(&FUNDED_BUYER), INITIAL_BALANCE);
+
+        System::assert_last_event(
+            Event::GamePurchased {
+                buyer: FUNDED_BUYER,
+                publisher: PUBLISHER,
+                game_id,
+                cid,
+                region: None,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn test_game_buy_individual_asset() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let price = 12345;
+        let asset = PaymentAsset::Asset(ASSET_ID);
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: Default::default(),
+            distribution: Distribution::Individual { price, asset },
+            regional_prices: Default::default(),
+            rating: Default::default(),
+        };
+        PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
+
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id, None));
+
+        let expected = OrderDetails { deposit: price, asset, region: None, receipt_deposit: 0 };
+        assert_eq!(BuyerOrders::<Test>::get(FUNDED_BUYER, (PUBLISHER, game_id)), Some(expected));
+        assert_eq!(Assets::balance(ASSET_ID, FUNDED_BUYER), INITIAL_BALANCE - price);
+        assert_eq!(Assets::balance(ASSET_ID, Games::account_id()), price);
+
+        assert_ok!(Games::order_cancel(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
+        assert_eq!(Assets::balance(ASSET_ID, FUNDED_BUYER), INITIAL_BALANCE);
+        assert_eq!(Assets::balance(ASSET_ID, Games::account_id()), 0);
+
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id, None));
+        assert_ok!(Games::order_fulfill(RuntimeOrigin::signed(PUBLISHER), game_id, FUNDED_BUYER));
+        assert_eq!(OwnedGames::<Test>::get(FUNDED_BUYER, (PUBLISHER, game_id)), Some(()));
+        assert_eq!(Assets::balance(ASSET_ID, FUNDED_BUYER), INITIAL_BALANCE - price);
+        assert_eq!(Assets::balance(ASSET_ID, PUBLISHER), INITIAL_BALANCE + price);
+        assert_eq!(Assets::balance(ASSET_ID, Games::account_id()), 0);
+    })
+}
+
+#[test]
+fn test_game_buy_asset_no_funds() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: Default::default(),
+            distribution: Distribution::Individual {
+                price: 12345,
+                asset: PaymentAsset::Asset(ASSET_ID),
+            },
+            regional_prices: Default::default(),
+            rating: Default::default(),
+        };
+        PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
+
+        assert_noop!(
+            Games::game_buy(RuntimeOrigin::signed(NON_FUNDED_BUYER), PUBLISHER, game_id, None),
+            TokenError::FundsUnavailable
+        );
+    })
+}
+
+#[test]
+fn test_game_buy_regional_price() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let price = 12345;
+        let regional_price = 2345;
+        let cid = bounded_vec(b"QmYwAPJzv5CZsnAztbCxjRMoa6zFzFG8pGzLFZxojtL8MX");
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: Default::default(),
+            distribution: Distribution::Instant {
+                price,
+                asset: PaymentAsset::Native,
+                cid: cid.clone(),
+            },
+            regional_prices: bounded_vec(&[(1, regional_price), (5, 5000)]),
+            rating: Default::default(),
+        };
+        PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
+
+        assert_ok!(Games::game_buy(
+            RuntimeOrigin::signed(FUNDED_BUYER),
+            PUBLISHER,
+            game_id,
+            Some(1)
+        ));
+        assert_eq!(
+            <Balances as fungible::Inspect<_>>::balance(&FUNDED_BUYER),
+            INITIAL_BALANCE - regional_price
+        );
+        System::assert_last_event(
+            Event::GamePurchased {
+                buyer: FUNDED_BUYER,
+                publisher: PUBLISHER,
+                game_id,
+                cid: cid.clone(),
+                region: Some(1),
+            }
+            .into(),
+        );
+
+        // A region without a price is charged the distribution price
+        let buyer = NON_FUNDED_BUYER;
+        assert_ok!(<Balances as fungible::Mutate<_>>::mint_into(&buyer, INITIAL_BALANCE));
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(buyer), PUBLISHER, game_id, Some(2)));
+        assert_eq!(<Balances as fungible::Inspect<_>>::balance(&buyer), INITIAL_BALANCE - price);
+        System::assert_last_event(
+            Event::GamePurchased { buyer, publisher: PUBLISHER, game_id, cid, region: None }.into(),
+        );
+    })
+}
+
+#[test]
+fn test_game_buy_regional_price_order() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let regional_price = 2345;
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: Default::default(),
+            distribution: Distribution::Individual { price: 12345, asset: PaymentAsset::Native },
+            regional_prices: bounded_vec(&[(3, regional_price)]),
+            rating: Default::default(),
+        };
+        PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
+
+        assert_ok!(Games::game_buy(
+            RuntimeOrigin::signed(FUNDED_BUYER),
+            PUBLISHER,
+            game_id,
+            Some(3)
+        ));
+
+        let expected = OrderDetails {
+            deposit: regional_price,
+            asset: PaymentAsset::Native,
+            region: Some(3),
+            receipt_deposit: 0,
+        };
+        assert_eq!(BuyerOrders::<Test>::get(FUNDED_BUYER, (PUBLISHER, game_id)), Some(expected));
+        assert_eq!(
+            <Balances as fungible::hold::Inspect<_>>::balance_on_hold(
+                &HoldReason::GamePayment.into(),
+                &FUNDED_BUYER
+            ),
+            regional_price
+        );
+        System::assert_last_event(
+            Event::OrderPlaced {
+                buyer: FUNDED_BUYER,
+                publisher: PUBLISHER,
+                game_id,
+                region: Some(3),
+            }
+            .into(),
+        );
+    })
+}
+
+#[test]
+fn test_game_add_regional_prices_invalid() {
+    new_test_ext().execute_with(|| {
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: Default::default(),
+            distribution: Distribution::Individual { price: 12345, asset: PaymentAsset::Native },
+            regional_prices: bounded_vec(&[(3, 100), (1, 200)]),
+            rating: Default::default(),
+        };
+
+        assert_noop!(
+            Games::game_add(RuntimeOrigin::signed(PUBLISHER), 1, details),
+            Error::<Test>::GameDetailsInvalid
+        );
+    })
+}
+
+#[test]
+fn test_game_add_rating_invalid() {
+    new_test_ext().execute_with(|| {
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: Default::default(),
+            distribution: Distribution::Individual { price: 12345, asset: PaymentAsset::Native },
+            regional_prices: Default::default(),
+            rating: ContentRating {
+                age: AgeRating::Unrated,
+                descriptors: ContentDescriptors::VIOLENCE,
+            },
+        };
+
+        assert_noop!(
+            Games::game_add(RuntimeOrigin::signed(PUBLISHER), 1, details),
+            Error::<Test>::GameDetailsInvalid
+        );
+    })
+}
+
+#[test]
+fn test_games_by_rating() {
+    new_test_ext().execute_with(|| {
+        let ratings = [
+            (1, AgeRating::Age3, ContentDescriptors::empty()),
+            (2, AgeRating::Age12, ContentDescriptors::VIOLENCE),
+            (3, AgeRating::Age18, ContentDescriptors::GAMBLING),
+            (4, AgeRating::Age7, ContentDescriptors::IN_GAME_PURCHASES),
+            (5, AgeRating::Unrated, ContentDescriptors::empty()),
+        ];
+        for (game_id, age, descriptors) in ratings {
+            let details = GameDetails {
+                name: bounded_vec(b"Example Game"),
+                tags: Default::default(),
+                distribution: Distribution::Individual {
+                    price: 12345,
+                    asset: PaymentAsset::Native,
+                },
+                regional_prices: Default::default(),
+                rating: ContentRating { age, descriptors },
+            };
+            assert_ok!(Games::game_add(RuntimeOrigin::signed(PUBLISHER), game_id, details));
+        }
+
+        let sorted = |mut games: Vec<(PublisherId<Test>, GameId)>| {
+            games.sort();
+            games
+        };
+        let none = ContentDescriptors::empty();
+
+        assert_eq!(
+            sorted(Games::games_by_rating(AgeRating::Age12, none, None, 10)),
+            vec![(PUBLISHER, 1), (PUBLISHER, 2), (PUBLISHER, 4)]
+        );
+        assert_eq!(
+            sorted(Games::games_by_rating(
+                AgeRating::Age18,
+                ContentDescriptors::VIOLENCE.union(ContentDescriptors::IN_GAME_PURCHASES),
+                None,
+                10
+            )),
+            vec![(PUBLISHER, 1), (PUBLISHER, 3)]
+        );
+        assert_eq!(Games::games_by_rating(AgeRating::Unrated, none, None, 10).len(), 5);
+
+        // Paginate over all the games
+        let first = Games::games_by_rating(AgeRating::Unrated, none, None, 3);
+        assert_eq!(first.len(), 3);
+        let rest = Games::games_by_rating(AgeRating::Unrated, none, first.last().cloned(), 3);
+        assert_eq!(rest.len(), 2);
+        assert_eq!(
+            sorted([first, rest].concat()),
+            (1..=5).map(|id| (PUBLISHER, id)).collect::<Vec<_>>()
+        );
+    })
+}
+
+#[test]
+fn test_games_by_tags() {
+    new_test_ext().execute_with(|| {
+        let (roguelike, pixel_graphics, singleplayer, co_op) = (58, 159, 347, 340);
+        let games: [(GameId, &[TagId]); 4] = [
+            (1, &[roguelike, pixel_graphics, singleplayer]),
+            (2, &[roguelike, co_op]),
+            (3, &[pixel_graphics, singleplayer]),
+            (4, &[roguelike, singleplayer]),
+        ];
+        for (game_id, tags) in games {
+            let details = GameDetails {
+                name: bounded_vec(b"Example Game"),
+                tags: bounded_vec(tags),
+                distribution: Distribution::Individual {
+                    price: 12345,
+                    asset: PaymentAsset::Native,
+                },
+                regional_prices: Default::default(),
+                rating: Default::default(),
+            };
+            assert_ok!(Games::game_add(RuntimeOrigin::signed(PUBLISHER), game_id, details));
+        }
+        assert_eq!(GamesByTag::<Test>::get(co_op, (PUBLISHER, 2)), Some(()));
+
+        let sorted = |mut games: Vec<(PublisherId<Test>, GameId)>| {
+            games.sort();
+            games
+        };
+
+        assert_eq!(
+            sorted(Games::games_by_tags(&[roguelike], None, 10)),
+            vec![(PUBLISHER, 1), (PUBLISHER, 2), (PUBLISHER, 4)]
+        );
+        assert_eq!(
+            sorted(Games::games_by_tags(&[roguelike, singleplayer], None, 10)),
+            vec![(PUBLISHER, 1), (PUBLISHER, 4)]
+        );
+        assert_eq!(
+            Games::games_by_tags(&[singleplayer, pixel_graphics, roguelike], None, 10),
+            vec![(PUBLISHER, 1)]
+        );
+        assert!(Games::games_by_tags(&[co_op, pixel_graphics], None, 10).is_empty());
+        assert!(Games::games_by_tags(&[], None, 10).is_empty());
+        assert_eq!(
+            Games::search_by_tags(&[singleplayer, pixel_graphics, roguelike], None, 10),
+            vec![((PUBLISHER, 1), PublishedGames::<Test>::get(PUBLISHER, 1).unwrap())]
+        );
+
+        // Paginate over all the games tagged singleplayer
+        let first = Games::games_by_tags(&[singleplayer], None, 2);
+        assert_eq!(first.len(), 2);
+        let rest = Games::games_by_tags(&[singleplayer], first.last().cloned(), 2);
+        assert_eq!(rest.len(), 1);
+        assert_eq!(
+            sorted([first, rest].concat()),
+            vec![(PUBLISHER, 1), (PUBLISHER, 3), (PUBLISHER, 4)]
         );
     })
 }
@@ -175,7 +641,7 @@ fn test_game_buy_individual() {
 fn test_game_buy_invalid_game() {
     new_test_ext().execute_with(|| {
         assert_noop!(
-            Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, 1),
+            Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, 1, None),
             Error::<Test>::GameNotFound
         );
     })
@@ -191,13 +657,16 @@ fn test_game_buy_no_funds() {
             tags: Default::default(),
             distribution: Distribution::Instant {
                 price,
+                asset: PaymentAsset::Native,
                 cid: bounded_vec(b"QmYwAPJzv5CZsnAztbCxjRMoa6zFzFG8pGzLFZxojtL8MX"),
             },
+            regional_prices: Default::default(),
+            rating: Default::default(),
         };
         PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
 
         assert_noop!(
-            Games::game_buy(RuntimeOrigin::signed(NON_FUNDED_BUYER), PUBLISHER, game_id),
+            Games::game_buy(RuntimeOrigin::signed(NON_FUNDED_BUYER), PUBLISHER, game_id, None),
             TokenError::FundsUnavailable
         );
     })
@@ -211,12 +680,14 @@ fn test_order_place_no_funds() {
         let details = GameDetails {
             name: bounded_vec(b"Example Game"),
             tags: Default::default(),
-            distribution: Distribution::Individual { price },
+            distribution: Distribution::Individual { price, asset: PaymentAsset::Native },
+            regional_prices: Default::default(),
+            rating: Default::default(),
         };
         PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
 
         assert_noop!(
-            Games::game_buy(RuntimeOrigin::signed(NON_FUNDED_BUYER), PUBLISHER, game_id),
+            Games::game_buy(RuntimeOrigin::signed(NON_FUNDED_BUYER), PUBLISHER, game_id, None),
             TokenError::FundsUnavailable
         );
     })
@@ -230,14 +701,16 @@ fn test_order_place_already_placed() {
         let details = GameDetails {
             name: bounded_vec(b"Example Game"),
             tags: Default::default(),
-            distribution: Distribution::Individual { price },
+            distribution: Distribution::Individual { price, asset: PaymentAsset::Native },
+            regional_prices: Default::default(),
+            rating: Default::default(),
         };
         PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
 
-        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id, None));
 
         assert_noop!(
-            Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id),
+            Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id, None),
             Error::<Test>::OrderAlreadyPlaced
         );
     })
@@ -250,7 +723,7 @@ fn test_order_place_owned_game() {
         OwnedGames::<Test>::insert(FUNDED_BUYER, (PUBLISHER, game_id), ());
 
         assert_noop!(
-            Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id),
+            Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id, None),
             Error::<Test>::GameAlreadyExists
         );
     })
@@ -264,7 +737,9 @@ fn test_order_place_multiple() {
         let details = GameDetails {
             name: bounded_vec(b"Example Game"),
             tags: Default::default(),
-            distribution: Distribution::Individual { price: price_1 },
+            distribution: Distribution::Individual { price: price_1, asset: PaymentAsset::Native },
+            regional_prices: Default::default(),
+            rating: Default::default(),
         };
         PublishedGames::<Test>::insert(PUBLISHER, game_id_1, details);
 
@@ -273,20 +748,42 @@ fn test_order_place_multiple() {
         let details = GameDetails {
             name: bounded_vec(b"Example Game"),
             tags: Default::default(),
-            distribution: Distribution::Individual { price: price_2 },
+            distribution: Distribution::Individual { price: price_2, asset: PaymentAsset::Native },
+            regional_prices: Default::default(),
+            rating: Default::default(),
         };
         PublishedGames::<Test>::insert(PUBLISHER, game_id_2, details);
 
-        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id_1));
-        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id_2));
+        assert_ok!(Games::game_buy(
+            RuntimeOrigin::signed(FUNDED_BUYER),
+            PUBLISHER,
+            game_id_1,
+            None
+        ));
+        assert_ok!(Games::game_buy(
+            RuntimeOrigin::signed(FUNDED_BUYER),
+            PUBLISHER,
+            game_id_2,
+            None
+        ));
 
         assert_eq!(
             BuyerOrders::<Test>::get(FUNDED_BUYER, (PUBLISHER, game_id_1)),
-            Some(OrderDetails { deposit: price_1 })
+            Some(OrderDetails {
+                deposit: price_1,
+                asset: PaymentAsset::Native,
+                region: None,
+                receipt_deposit: 0
+            })
         );
         assert_eq!(
             BuyerOrders::<Test>::get(FUNDED_BUYER, (PUBLISHER, game_id_2)),
-            Some(OrderDetails { deposit: price_2 })
+            Some(OrderDetails {
+                deposit: price_2,
+                asset: PaymentAsset::Native,
+                region: None,
+                receipt_deposit: 0
+            })
         );
         assert_eq!(PublisherOrders::<Test>::get(PUBLISHER, game_id_1), Some(FUNDED_BUYER));
         assert_eq!(PublisherOrders::<Test>::get(PUBLISHER, game_id_2), Some(FUNDED_BUYER));
@@ -314,11 +811,13 @@ fn test_order_cancel() {
         let details = GameDetails {
             name: bounded_vec(b"Example Game"),
             tags: Default::default(),
-            distribution: Distribution::Individual { price },
+            distribution: Distribution::Individual { price, asset: PaymentAsset::Native },
+            regional_prices: Default::default(),
+            rating: Default::default(),
         };
         PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
 
-        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id, None));
 
         assert_ok!(Games::order_cancel(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
 
@@ -357,11 +856,13 @@ fn test_order_fulfill() {
         let details = GameDetails {
             name: bounded_vec(b"Example Game"),
             tags: Default::default(),
-            distribution: Distribution::Individual { price },
+            distribution: Distribution::Individual { price, asset: PaymentAsset::Native },
+            regional_prices: Default::default(),
+            rating: Default::default(),
         };
         PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
 
-        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id, None));
 
         assert_ok!(Games::order_fulfill(RuntimeOrigin::signed(PUBLISHER), game_id, FUNDED_BUYER));
 
@@ -407,11 +908,13 @@ fn test_order_fulfill_invalid_game() {
         let details = GameDetails {
             name: bounded_vec(b"Example Game"),
             tags: Default::default(),
-            distribution: Distribution::Individual { price },
+            distribution: Distribution::Individual { price, asset: PaymentAsset::Native },
+            regional_prices: Default::default(),
+            rating: Default::default(),
         };
         PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
 
-        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id, None));
 
         let invalid_game_id = game_id + 1;
         assert_noop!(
@@ -420,3 +923,2396 @@ fn test_order_fulfill_invalid_game() {
         );
     })
 }
+
+fn held_payment(buyer: BuyerId<Test>) -> Balance {
+    <Balances as fungible::hold::Inspect<_>>::balance_on_hold(
+        &HoldReason::GamePayment.into(),
+        &buyer,
+    )
+}
+
+#[test]
+fn test_game_add_pre_order() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        add_game(game_id, DistributionKind::PreOrder, 12345);
+
+        assert_eq!(
+            PreOrderSchedules::<Test>::get((PUBLISHER, game_id)),
+            Some(PreOrderSchedule { deadline: 1 + PRE_ORDER_DEADLINE, release: None })
+        );
+    })
+}
+
+#[test]
+fn test_game_buy_pre_order() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let price = 12345;
+        add_game(game_id, DistributionKind::PreOrder, price);
+
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id, None));
+
+        assert_eq!(
+            PreOrders::<Test>::get((PUBLISHER, game_id), FUNDED_BUYER),
+            Some(OrderDetails {
+                deposit: price,
+                asset: PaymentAsset::Native,
+                region: None,
+                receipt_deposit: 0
+            })
+        );
+        assert_eq!(held_payment(FUNDED_BUYER), price);
+        System::assert_last_event(
+            Event::PreOrderPlaced {
+                buyer: FUNDED_BUYER,
+                publisher: PUBLISHER,
+                game_id,
+                region: None,
+            }
+            .into(),
+        );
+
+        assert_noop!(
+            Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id, None),
+            Error::<Test>::OrderAlreadyPlaced
+        );
+    })
+}
+
+#[test]
+fn test_game_buy_pre_order_closed() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: Default::default(),
+            distribution: Distribution::PreOrder { price: 12345, asset: PaymentAsset::Native },
+            regional_prices: Default::default(),
+            rating: Default::default(),
+        };
+        PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
+
+        assert_noop!(
+            Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id, None),
+            Error::<Test>::PreOrderClosed
+        );
+    })
+}
+
+#[test]
+fn test_preorder_release() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let price = 12345;
+        let cid = bounded_vec(b"QmYwAPJzv5CZsnAztbCxjRMoa6zFzFG8pGzLFZxojtL8MX");
+        add_game(game_id, DistributionKind::PreOrder, price);
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id, None));
+
+        assert_ok!(Games::preorder_release(
+            RuntimeOrigin::signed(PUBLISHER),
+            game_id,
+            5,
+            cid.clone()
+        ));
+        System::assert_last_event(
+            Event::PreOrderReleaseScheduled { publisher: PUBLISHER, game_id, at: 5 }.into(),
+        );
+        assert_eq!(
+            PreOrderSchedules::<Test>::get((PUBLISHER, game_id)).and_then(|s| s.release),
+            Some(PreOrderRelease { at: 5, cid: cid.clone() })
+        );
+
+        run_to_block(5);
+
+        assert_eq!(PreOrderSchedules::<Test>::get((PUBLISHER, game_id)), None);
+        assert_eq!(
+            PreOrderSettlements::<Test>::get((PUBLISHER, game_id)),
+            Some(PreOrderSettlement::Release { cid: cid.clone() })
+        );
+        assert_eq!(
+            PublishedGames::<Test>::get(PUBLISHER, game_id).map(|d| d.distribution),
+            Some(Distribution::Instant { price, asset: PaymentAsset::Native, cid: cid.clone() })
+        );
+
+        run_to_block(6);
+
+        assert_eq!(PreOrderSettlements::<Test>::get((PUBLISHER, game_id)), None);
+        assert_eq!(PreOrders::<Test>::get((PUBLISHER, game_id), FUNDED_BUYER), None);
+        assert_eq!(OwnedGames::<Test>::get(FUNDED_BUYER, (PUBLISHER, game_id)), Some(()));
+        assert_eq!(held_payment(FUNDED_BUYER), 0);
+        assert_eq!(
+            <Balances as fungible::Inspect<_>>::balance(&PUBLISHER),
+            INITIAL_BALANCE + price
+        );
+        System::assert_has_event(
+            Event::GamePurchased {
+                buyer: FUNDED_BUYER,
+                publisher: PUBLISHER,
+                game_id,
+                cid,
+                region: None,
+            }
+            .into(),
+        );
+    })
+}
+
+#[test]
+fn test_game_buy_released_before_settlement() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let price = 12345;
+        let cid = bounded_vec(b"QmYwAPJzv5CZsnAztbCxjRMoa6zFzFG8pGzLFZxojtL8MX");
+        add_game(game_id, DistributionKind::PreOrder, price);
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id, None));
+        assert_ok!(Games::preorder_release(RuntimeOrigin::signed(PUBLISHER), game_id, 5, cid));
+
+        // Released but not settled yet
+        System::set_block_number(5);
+        Games::on_initialize(5);
+        assert!(PreOrderSettlements::<Test>::contains_key((PUBLISHER, game_id)));
+
+        assert_noop!(
+            Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id, None),
+            Error::<Test>::OrderAlreadyPlaced
+        );
+        assert_eq!(held_payment(FUNDED_BUYER), price);
+    })
+}
+
+#[test]
+fn test_preorder_release_invalid_block() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let cid = bounded_vec(b"QmYwAPJzv5CZsnAztbCxjRMoa6zFzFG8pGzLFZxojtL8MX");
+        add_game(game_id, DistributionKind::PreOrder, 12345);
+
+        assert_noop!(
+            Games::preorder_release(RuntimeOrigin::signed(PUBLISHER), game_id, 1, cid.clone()),
+            Error::<Test>::PreOrderReleaseInvalid
+        );
+        assert_noop!(
+            Games::preorder_release(
+                RuntimeOrigin::signed(PUBLISHER),
+                game_id,
+                2 + PRE_ORDER_DEADLINE,
+                cid
+            ),
+            Error::<Test>::PreOrderReleaseInvalid
+        );
+    })
+}
+
+#[test]
+fn test_preorder_release_reschedule() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let game = (PUBLISHER, game_id);
+        let cid: Cid = bounded_vec(b"QmYwAPJzv5CZsnAztbCxjRMoa6zFzFG8pGzLFZxojtL8MX");
+        add_game(game_id, DistributionKind::PreOrder, 12345);
+        let deadline = 1 + PRE_ORDER_DEADLINE;
+
+        for at in [5, 7, 7] {
+            assert_ok!(Games::preorder_release(
+                RuntimeOrigin::signed(PUBLISHER),
+                game_id,
+                at,
+                cid.clone()
+            ));
+        }
+        assert!(PreOrderAgenda::<Test>::get(5).is_empty());
+        assert_eq!(PreOrderAgenda::<Test>::get(7).into_inner(), vec![game]);
+        assert_eq!(PreOrderAgenda::<Test>::get(deadline).into_inner(), vec![game]);
+
+        // Releasing at the deadline reuses its agenda entry
+        assert_ok!(Games::preorder_release(
+            RuntimeOrigin::signed(PUBLISHER),
+            game_id,
+            deadline,
+            cid.clone()
+        ));
+        assert!(PreOrderAgenda::<Test>::get(7).is_empty());
+        assert_eq!(PreOrderAgenda::<Test>::get(deadline).into_inner(), vec![game]);
+
+        run_to_block(deadline);
+        assert_eq!(
+            PreOrderSettlements::<Test>::get(game),
+            Some(PreOrderSettlement::Release { cid })
+        );
+    })
+}
+
+#[test]
+fn test_preorder_release_missing_game() {
+    new_test_ext().execute_with(|| {
+        let cid = bounded_vec(b"QmYwAPJzv5CZsnAztbCxjRMoa6zFzFG8pGzLFZxojtL8MX");
+        assert_noop!(
+            Games::preorder_release(RuntimeOrigin::signed(PUBLISHER), 1, 5, cid),
+            Error::<Test>::PreOrderClosed
+        );
+    })
+}
+
+#[test]
+fn test_preorder_cancel() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        add_game(game_id, DistributionKind::PreOrder, 12345);
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id, None));
+
+        assert_ok!(Games::preorder_cancel(RuntimeOrigin::signed(PUBLISHER), game_id));
+        System::assert_last_event(
+            Event::PreOrderCancelled { publisher: PUBLISHER, game_id }.into(),
+        );
+        assert_eq!(PublishedGames::<Test>::get(PUBLISHER, game_id), None);
+        assert_noop!(
+            Games::game_buy(RuntimeOrigin::signed(NON_FUNDED_BUYER), PUBLISHER, game_id, None),
+            Error::<Test>::GameNotFound
+        );
+
+        run_to_block(2);
+
+        assert_eq!(PreOrders::<Test>::get((PUBLISHER, game_id), FUNDED_BUYER), None);
+        assert_eq!(OwnedGames::<Test>::get(FUNDED_BUYER, (PUBLISHER, game_id)), None);
+        assert_eq!(held_payment(FUNDED_BUYER), 0);
+        assert_eq!(<Balances as fungible::Inspect<_>>::balance(&FUNDED_BUYER), INITIAL_BALANCE);
+        System::assert_has_event(
+            Event::PreOrderRefunded { buyer: FUNDED_BUYER, publisher: PUBLISHER, game_id }.into(),
+        );
+    })
+}
+
+#[test]
+fn test_preorder_cancel_references() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let game = (PUBLISHER, game_id);
+        add_game(game_id, DistributionKind::PreOrder, 12345);
+        let plan = SubscriptionPlan {
+            name: bounded_vec(b"Example Plan"),
+            price: 100,
+            asset: PaymentAsset::Native,
+            period: 10,
+            games: bounded_vec(&[game_id]),
+        };
+        assert_ok!(Games::plan_add(RuntimeOrigin::signed(PUBLISHER), 1, plan));
+        assert_ok!(Games::subscription_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, 1));
+        assert_ok!(Games::wishlist_add(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
+        OwnedGames::<Test>::insert(NON_FUNDED_BUYER, game, ());
+        assert_ok!(Games::tag_vote(RuntimeOrigin::signed(NON_FUNDED_BUYER), PUBLISHER, game_id, 0));
+
+        assert!(Games::has_access(&FUNDED_BUYER, &game, 1));
+        assert_eq!(Games::wishlist(&FUNDED_BUYER).len(), 1);
+        assert_eq!(Games::top_tags(&game, 10), vec![(0, 1)]);
+
+        assert_ok!(Games::preorder_cancel(RuntimeOrigin::signed(PUBLISHER), game_id));
+
+        // The withdrawn game is no longer reachable through the references left behind
+        assert!(!Games::has_access(&FUNDED_BUYER, &game, 1));
+        assert!(Games::has_access(&NON_FUNDED_BUYER, &game, 1));
+        assert_eq!(WishlistCounts::<Test>::get(game), 0);
+        assert!(Games::wishlist(&FUNDED_BUYER).is_empty());
+        assert!(Games::top_tags(&game, 10).is_empty());
+    })
+}
+
+#[test]
+fn test_preorder_expired() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        add_game(game_id, DistributionKind::PreOrder, 12345);
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id, None));
+
+        run_to_block(1 + PRE_ORDER_DEADLINE);
+
+        assert_eq!(PreOrderSchedules::<Test>::get((PUBLISHER, game_id)), None);
+        assert_eq!(PublishedGames::<Test>::get(PUBLISHER, game_id), None);
+        System::assert_has_event(Event::PreOrderExpired { publisher: PUBLISHER, game_id }.into());
+
+        run_to_block(2 + PRE_ORDER_DEADLINE);
+
+        assert_eq!(held_payment(FUNDED_BUYER), 0);
+        assert_eq!(<Balances as fungible::Inspect<_>>::balance(&FUNDED_BUYER), INITIAL_BALANCE);
+        System::assert_has_event(
+            Event::PreOrderRefunded { buyer: FUNDED_BUYER, publisher: PUBLISHER, game_id }.into(),
+        );
+    })
+}
+
+#[test]
+fn test_preorder_cancel_readd_unsettled() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        add_game(game_id, DistributionKind::PreOrder, 12345);
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id, None));
+        assert_ok!(Games::preorder_cancel(RuntimeOrigin::signed(PUBLISHER), game_id));
+
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: Default::default(),
+            distribution: Distribution::PreOrder { price: 12345, asset: PaymentAsset::Native },
+            regional_prices: Default::default(),
+            rating: Default::default(),
+        };
+        assert_noop!(
+            Games::game_add(RuntimeOrigin::signed(PUBLISHER), game_id, details.clone()),
+            Error::<Test>::PreOrderUnsettled
+        );
+
+        run_to_block(2);
+
+        assert_ok!(Games::game_add(RuntimeOrigin::signed(PUBLISHER), game_id, details));
+    })
+}
+
+#[test]
+fn test_preorder_claim() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let price = 12345;
+        let cid = bounded_vec(b"QmYwAPJzv5CZsnAztbCxjRMoa6zFzFG8pGzLFZxojtL8MX");
+        add_game(game_id, DistributionKind::PreOrder, price);
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id, None));
+        assert_ok!(Games::preorder_release(
+            RuntimeOrigin::signed(PUBLISHER),
+            game_id,
+            5,
+            cid.clone()
+        ));
+
+        assert_noop!(
+            Games::preorder_claim(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id),
+            Error::<Test>::PreOrderOpen
+        );
+
+        // Released but not settled yet
+        System::set_block_number(5);
+        Games::on_initialize(5);
+
+        assert_noop!(
+            Games::preorder_claim(RuntimeOrigin::signed(NON_FUNDED_BUYER), PUBLISHER, game_id),
+            Error::<Test>::OrderNotFound
+        );
+        assert_ok!(Games::preorder_claim(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
+
+        assert_eq!(PreOrders::<Test>::get((PUBLISHER, game_id), FUNDED_BUYER), None);
+        assert_eq!(OwnedGames::<Test>::get(FUNDED_BUYER, (PUBLISHER, game_id)), Some(()));
+        assert_eq!(held_payment(FUNDED_BUYER), 0);
+        System::assert_last_event(
+            Event::GamePurchased {
+                buyer: FUNDED_BUYER,
+                publisher: PUBLISHER,
+                game_id,
+                cid,
+                region: None,
+            }
+            .into(),
+        );
+    })
+}
+
+#[test]
+fn test_preorder_claim_refund() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        add_game(game_id, DistributionKind::PreOrder, 12345);
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id, None));
+        assert_ok!(Games::preorder_cancel(RuntimeOrigin::signed(PUBLISHER), game_id));
+
+        assert_ok!(Games::preorder_claim(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
+
+        assert_eq!(held_payment(FUNDED_BUYER), 0);
+        assert_eq!(<Balances as fungible::Inspect<_>>::balance(&FUNDED_BUYER), INITIAL_BALANCE);
+        System::assert_last_event(
+            Event::PreOrderRefunded { buyer: FUNDED_BUYER, publisher: PUBLISHER, game_id }.into(),
+        );
+    })
+}
+
+#[test]
+fn test_preorder_refund_failed() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let price = 12345;
+        let asset = PaymentAsset::Asset(ASSET_ID);
+        // The payment was never put aside in the pallet account
+        let order =
+            OrderDetails { deposit: price, asset: asset.clone(), region: None, receipt_deposit: 0 };
+        PreOrders::<Test>::insert((PUBLISHER, game_id), FUNDED_BUYER, &order);
+        PreOrderSettlements::<Test>::insert((PUBLISHER, game_id), PreOrderSettlement::Refund);
+
+        run_to_block(2);
+
+        assert_eq!(PreOrderSettlements::<Test>::get((PUBLISHER, game_id)), None);
+        assert_eq!(
+            PreOrderFailedRefunds::<Test>::get((PUBLISHER, game_id), FUNDED_BUYER),
+            Some(order)
+        );
+        System::assert_has_event(
+            Event::PreOrderRefundFailed {
+                buyer: FUNDED_BUYER,
+                publisher: PUBLISHER,
+                game_id,
+                amount: price,
+                asset,
+                receipt_deposit: 0,
+            }
+            .into(),
+        );
+    })
+}
+
+#[test]
+fn test_preorder_claim_refund_failed() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let price = 12345;
+        let asset = PaymentAsset::Asset(ASSET_ID);
+        // The payment was never put aside in the pallet account
+        let order =
+            OrderDetails { deposit: price, asset: asset.clone(), region: None, receipt_deposit: 0 };
+        PreOrders::<Test>::insert((PUBLISHER, game_id), FUNDED_BUYER, &order);
+        PreOrderSettlements::<Test>::insert((PUBLISHER, game_id), PreOrderSettlement::Refund);
+
+        assert!(
+            Games::preorder_claim(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id).is_err()
+        );
+        assert_eq!(PreOrders::<Test>::get((PUBLISHER, game_id), FUNDED_BUYER), Some(order));
+        assert_eq!(PreOrderFailedRefunds::<Test>::get((PUBLISHER, game_id), FUNDED_BUYER), None);
+    })
+}
+
+#[test]
+fn test_preorder_refund_retry() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let price = 12345;
+        let receipt_deposit = 100;
+        let asset = PaymentAsset::Asset(ASSET_ID);
+        // The receipt deposit is held, but the payment was never put aside in the pallet account
+        assert_ok!(<Balances as fungible::hold::Mutate<_>>::hold(
+            &HoldReason::ReceiptDeposit.into(),
+            &FUNDED_BUYER,
+            receipt_deposit
+        ));
+        let order =
+            OrderDetails { deposit: price, asset: asset.clone(), region: None, receipt_deposit };
+        PreOrders::<Test>::insert((PUBLISHER, game_id), FUNDED_BUYER, order);
+        PreOrderSettlements::<Test>::insert((PUBLISHER, game_id), PreOrderSettlement::Refund);
+
+        run_to_block(2);
+
+        // The receipt deposit is returned while the payment is left aside
+        assert_eq!(<Balances as fungible::Inspect<_>>::balance(&FUNDED_BUYER), INITIAL_BALANCE);
+        let remaining =
+            OrderDetails { deposit: price, asset: asset.clone(), region: None, receipt_deposit: 0 };
+        assert_eq!(
+            PreOrderFailedRefunds::<Test>::get((PUBLISHER, game_id), FUNDED_BUYER),
+            Some(remaining)
+        );
+        System::assert_has_event(
+            Event::PreOrderRefundFailed {
+                buyer: FUNDED_BUYER,
+                publisher: PUBLISHER,
+                game_id,
+                amount: price,
+                asset,
+                receipt_deposit: 0,
+            }
+            .into(),
+        );
+        assert!(
+            Games::preorder_claim(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id).is_err()
+        );
+
+        assert_ok!(<Assets as fungibles::Mutate<_>>::mint_into(
+            ASSET_ID,
+            &Games::account_id(),
+            price
+        ));
+        assert_ok!(Games::preorder_claim(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
+
+        assert_eq!(PreOrderFailedRefunds::<Test>::get((PUBLISHER, game_id), FUNDED_BUYER), None);
+        assert_eq!(Assets::balance(ASSET_ID, FUNDED_BUYER), INITIAL_BALANCE + price);
+        System::assert_last_event(
+            Event::PreOrderRefunded { buyer: FUNDED_BUYER, publisher: PUBLISHER, game_id }.into(),
+        );
+    })
+}
+
+fn add_plan(plan_id: PlanId, price: Balance, period: u64, games: &[GameId]) {
+    for game_id in games {
+        add_game(*game_id, DistributionKind::Individual, 12345);
+    }
+    let plan = SubscriptionPlan {
+        name: bounded_vec(b"Example Plan"),
+        price,
+        asset: PaymentAsset::Native,
+        period,
+        games: bounded_vec(games),
+    };
+    assert_ok!(Games::plan_add(RuntimeOrigin::signed(PUBLISHER), plan_id, plan));
+}
+
+#[test]
+fn test_plan_add() {
+    new_test_ext().execute_with(|| {
+        let plan_id = 1;
+        add_plan(plan_id, 100, 10, &[1, 2]);
+
+        assert!(SubscriptionPlans::<Test>::contains_key(PUBLISHER, plan_id));
+        System::assert_last_event(Event::PlanAdded { publisher: PUBLISHER, plan_id }.into());
+    })
+}
+
+#[test]
+fn test_plan_add_unpublished_game() {
+    new_test_ext().execute_with(|| {
+        let plan = SubscriptionPlan {
+            name: bounded_vec(b"Example Plan"),
+            price: 100,
+            asset: PaymentAsset::Native,
+            period: 10,
+            games: bounded_vec(&[1]),
+        };
+        assert_noop!(
+            Games::plan_add(RuntimeOrigin::signed(PUBLISHER), 1, plan),
+            Error::<Test>::PlanDetailsInvalid
+        );
+    })
+}
+
+#[test]
+fn test_plan_add_already_exists() {
+    new_test_ext().execute_with(|| {
+        let plan_id = 1;
+        add_plan(plan_id, 100, 10, &[1]);
+
+        let plan = SubscriptionPlans::<Test>::get(PUBLISHER, plan_id).unwrap();
+        assert_noop!(
+            Games::plan_add(RuntimeOrigin::signed(PUBLISHER), plan_id, plan),
+            Error::<Test>::PlanAlreadyExists
+        );
+    })
+}
+
+#[test]
+fn test_subscription_buy() {
+    new_test_ext().execute_with(|| {
+        let plan_id = 1;
+        let price = 100;
+        add_plan(plan_id, price, 10, &[1, 2]);
+
+        assert_ok!(Games::subscription_buy(
+            RuntimeOrigin::signed(FUNDED_BUYER),
+            PUBLISHER,
+            plan_id
+        ));
+
+        assert_eq!(
+            Subscriptions::<Test>::get(FUNDED_BUYER, (PUBLISHER, plan_id)),
+            Some(Entitlement { starts_at: 1, expires_at: 11 })
+        );
+        assert_eq!(
+            <Balances as fungible::Inspect<_>>::balance(&FUNDED_BUYER),
+            INITIAL_BALANCE - price
+        );
+        assert_eq!(
+            <Balances as fungible::Inspect<_>>::balance(&PUBLISHER),
+            INITIAL_BALANCE + price
+        );
+        System::assert_last_event(
+            Event::SubscriptionPurchased {
+                buyer: FUNDED_BUYER,
+                publisher: PUBLISHER,
+                plan_id,
+                expires_at: 11,
+            }
+            .into(),
+        );
+    })
+}
+
+#[test]
+fn test_subscription_buy_extend_and_renew() {
+    new_test_ext().execute_with(|| {
+        let plan_id = 1;
+        add_plan(plan_id, 100, 10, &[1]);
+
+        assert_ok!(Games::subscription_buy(
+            RuntimeOrigin::signed(FUNDED_BUYER),
+            PUBLISHER,
+            plan_id
+        ));
+        System::set_block_number(5);
+        assert_ok!(Games::subscription_buy(
+            RuntimeOrigin::signed(FUNDED_BUYER),
+            PUBLISHER,
+            plan_id
+        ));
+        assert_eq!(
+            Subscriptions::<Test>::get(FUNDED_BUYER, (PUBLISHER, plan_id)),
+            Some(Entitlement { starts_at: 1, expires_at: 21 })
+        );
+
+        System::set_block_number(30);
+        assert_ok!(Games::subscription_buy(
+            RuntimeOrigin::signed(FUNDED_BUYER),
+            PUBLISHER,
+            plan_id
+        ));
+        assert_eq!(
+            Subscriptions::<Test>::get(FUNDED_BUYER, (PUBLISHER, plan_id)),
+            Some(Entitlement { starts_at: 30, expires_at: 40 })
+        );
+    })
+}
+
+#[test]
+fn test_subscription_buy_missing_plan() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Games::subscription_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, 1),
+            Error::<Test>::PlanNotFound
+        );
+    })
+}
+
+#[test]
+fn test_subscription_buy_no_funds() {
+    new_test_ext().execute_with(|| {
+        let plan_id = 1;
+        add_plan(plan_id, 100, 10, &[1]);
+
+        assert_noop!(
+            Games::subscription_buy(RuntimeOrigin::signed(NON_FUNDED_BUYER), PUBLISHER, plan_id),
+            TokenError::FundsUnavailable
+        );
+    })
+}
+
+#[test]
+fn test_has_access() {
+    new_test_ext().execute_with(|| {
+        let plan_id = 1;
+        add_plan(plan_id, 100, 10, &[1]);
+        OwnedGames::<Test>::insert(FUNDED_BUYER, (PUBLISHER, 3), ());
+
+        assert!(Games::has_access(&FUNDED_BUYER, &(PUBLISHER, 3), 1));
+        assert!(!Games::has_access(&FUNDED_BUYER, &(PUBLISHER, 1), 1));
+
+        assert_ok!(Games::subscription_buy(
+            RuntimeOrigin::signed(FUNDED_BUYER),
+            PUBLISHER,
+            plan_id
+        ));
+
+        assert!(Games::has_access(&FUNDED_BUYER, &(PUBLISHER, 1), 1));
+        assert!(Games::has_access(&FUNDED_BUYER, &(PUBLISHER, 1), 10));
+        assert!(!Games::has_access(&FUNDED_BUYER, &(PUBLISHER, 1), 11));
+        assert!(!Games::has_access(&FUNDED_BUYER, &(PUBLISHER, 2), 1));
+        assert!(!Games::has_access(&NON_FUNDED_BUYER, &(PUBLISHER, 1), 1));
+    })
+}
+
+#[test]
+fn test_tag_add() {
+    new_test_ext().execute_with(|| {
+        let tag_id = NextTagId::<Test>::get();
+        let tag: Tag = bounded_vec(b"Extraction Shooter");
+        // "Action" is a top-level genre
+        let info = TagInfo { category: TagCategory::Genre, parent: Some(0) };
+        assert_eq!(Some(tag_id), TAGS.iter().map(|(tag_id, _, _)| tag_id + 1).max());
+
+        assert_noop!(
+            Games::tag_add(RuntimeOrigin::signed(PUBLISHER), tag.clone(), info),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Games::tag_add(RuntimeOrigin::root(), bounded_vec(b""), info),
+            Error::<Test>::TagInvalid
+        );
+
+        assert_noop!(
+            Games::tag_add(RuntimeOrigin::root(), bounded_vec(b"Action"), info),
+            Error::<Test>::TagAlreadyExists
+        );
+
+        assert_ok!(Games::tag_add(RuntimeOrigin::root(), tag.clone(), info));
+        assert_eq!(Tags::<Test>::get(tag_id), Some(tag.clone()));
+        assert_eq!(TagIds::<Test>::get(&tag), Some(tag_id));
+        assert_eq!(TagTaxonomy::<Test>::get(tag_id), Some(info));
+        assert!(TagsByCategory::<Test>::contains_key(TagCategory::Genre, tag_id));
+        assert_eq!(NextTagId::<Test>::get(), tag_id + 1);
+        System::assert_last_event(Event::TagAdded { tag_id, tag, info }.into());
+    })
+}
+
+#[test]
+fn test_tag_rename() {
+    new_test_ext().execute_with(|| {
+        let tag: Tag = bounded_vec(b"Role-Playing");
+
+        assert_noop!(
+            Games::tag_rename(RuntimeOrigin::root(), NextTagId::<Test>::get(), tag.clone()),
+            Error::<Test>::TagNotFound
+        );
+
+        assert_noop!(
+            Games::tag_rename(RuntimeOrigin::root(), 6, bounded_vec(b"Action")),
+            Error::<Test>::TagAlreadyExists
+        );
+
+        assert_ok!(Games::tag_rename(RuntimeOrigin::root(), 6, tag.clone()));
+        assert_eq!(Tags::<Test>::get(6), Some(tag.clone()));
+        assert_eq!(TagIds::<Test>::get(&tag), Some(6));
+        assert_eq!(TagIds::<Test>::get(Tag::truncate_from(b"RPG".to_vec())), None);
+        System::assert_last_event(Event::TagRenamed { tag_id: 6, tag }.into());
+    })
+}
+
+#[test]
+fn test_tag_deprecate() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: bounded_vec(&[1, 2, 3]),
+            distribution: Distribution::Individual { price: 12345, asset: PaymentAsset::Native },
+            regional_prices: Default::default(),
+            rating: Default::default(),
+        };
+        assert_ok!(Games::game_add(RuntimeOrigin::signed(PUBLISHER), game_id, details.clone()));
+
+        assert_ok!(Games::tag_deprecate(RuntimeOrigin::root(), 2));
+        assert!(DeprecatedTags::<Test>::contains_key(2));
+        System::assert_last_event(Event::TagDeprecated { tag_id: 2 }.into());
+        assert_noop!(
+            Games::tag_deprecate(RuntimeOrigin::root(), 2),
+            Error::<Test>::TagAlreadyDeprecated
+        );
+
+        // The deprecated tag stays on the existing game, but new games cannot use it
+        assert_eq!(PublishedGames::<Test>::get(PUBLISHER, game_id), Some(details.clone()));
+        assert_noop!(
+            Games::game_add(RuntimeOrigin::signed(PUBLISHER), game_id + 1, details),
+            Error::<Test>::GameDetailsInvalid
+        );
+    })
+}
+
+#[test]
+fn test_tag_add_invalid_parent() {
+    new_test_ext().execute_with(|| {
+        let tag: Tag = bounded_vec(b"Extraction Shooter");
+        // "Action RPG" is a genre, not a top-level genre
+        let invalid_infos = [
+            TagInfo { category: TagCategory::Genre, parent: Some(11) },
+            TagInfo { category: TagCategory::Theme, parent: Some(0) },
+            TagInfo { category: TagCategory::Genre, parent: Some(NextTagId::<Test>::get()) },
+        ];
+
+        for info in invalid_infos {
+            assert_noop!(
+                Games::tag_add(RuntimeOrigin::root(), tag.clone(), info),
+                Error::<Test>::TagInfoInvalid
+            );
+        }
+    })
+}
+
+#[test]
+fn test_tag_classify() {
+    new_test_ext().execute_with(|| {
+        // "Action RPG" becomes a child of "Action"
+        let info = TagInfo { category: TagCategory::Genre, parent: Some(0) };
+        assert_eq!(
+            TagTaxonomy::<Test>::get(11).map(|info| info.category),
+            Some(TagCategory::Genre)
+        );
+
+        assert_ok!(Games::tag_classify(RuntimeOrigin::root(), 11, info));
+        assert_eq!(TagTaxonomy::<Test>::get(11), Some(info));
+        System::assert_last_event(Event::TagClassified { tag_id: 11, info }.into());
+
+        assert!(TagChildren::<Test>::contains_key(0, 11));
+
+        // "Action RPG" is the parent of "Souls-like", so it stays a genre
+        let info = TagInfo { category: TagCategory::Theme, parent: None };
+        assert!(TagChildren::<Test>::contains_key(11, 125));
+        assert_noop!(
+            Games::tag_classify(RuntimeOrigin::root(), 11, info),
+            Error::<Test>::TagHasChildren
+        );
+
+        // "Arcade" has no children
+        assert_ok!(Games::tag_classify(RuntimeOrigin::root(), 13, info));
+        assert!(!TagsByCategory::<Test>::contains_key(TagCategory::Genre, 13));
+        assert!(TagsByCategory::<Test>::contains_key(TagCategory::Theme, 13));
+
+        // "Souls-like" moves from "Action RPG" to "Action-Adventure"
+        let info = TagInfo { category: TagCategory::SubGenre, parent: Some(12) };
+        assert_ok!(Games::tag_classify(RuntimeOrigin::root(), 125, info));
+        assert!(!TagChildren::<Test>::contains_key(11, 125));
+        assert!(TagChildren::<Test>::contains_key(12, 125));
+
+        assert_noop!(
+            Games::tag_classify(
+                RuntimeOrigin::root(),
+                NextTagId::<Test>::get(),
+                TagInfo::default()
+            ),
+            Error::<Test>::TagNotFound
+        );
+        assert_noop!(
+            Games::tag_classify(
+                RuntimeOrigin::root(),
+                0,
+                TagInfo { category: TagCategory::TopLevelGenre, parent: Some(0) }
+            ),
+            Error::<Test>::TagInfoInvalid
+        );
+    })
+}
+
+#[test]
+fn test_game_add_tag_taxonomy() {
+    new_test_ext().execute_with(|| {
+        // "Action RPG" implies "Action"
+        let info = TagInfo { category: TagCategory::Genre, parent: Some(0) };
+        assert_ok!(Games::tag_classify(RuntimeOrigin::root(), 11, info));
+
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: bounded_vec(&[11]),
+            distribution: Distribution::Individual { price: 12345, asset: PaymentAsset::Native },
+            regional_prices: Default::default(),
+            rating: Default::default(),
+        };
+        assert_noop!(
+            Games::game_add(RuntimeOrigin::signed(PUBLISHER), 1, details.clone()),
+            Error::<Test>::GameDetailsInvalid
+        );
+
+        // Too many top-level genres
+        let too_many_genres =
+            GameDetails { tags: bounded_vec(&[0, 1, 2, 4, 11]), ..details.clone() };
+        assert_noop!(
+            Games::game_add(RuntimeOrigin::signed(PUBLISHER), 1, too_many_genres),
+            Error::<Test>::GameDetailsInvalid
+        );
+
+        let details = GameDetails { tags: bounded_vec(&[0, 11]), ..details };
+        assert_ok!(Games::game_add(RuntimeOrigin::signed(PUBLISHER), 1, details));
+    })
+}
+
+#[test]
+fn test_tags_by_category() {
+    new_test_ext().execute_with(|| {
+        let mut top_level_genres = Games::tags_by_category(TagCategory::TopLevelGenre);
+        top_level_genres.sort();
+
+        let expected = TAGS
+            .iter()
+            .filter(|(_, category, _)| *category == TagCategory::TopLevelGenre)
+            .map(|(tag_id, _, tag)| (*tag_id, bounded_vec(tag.as_bytes())))
+            .collect::<Vec<(TagId, Tag)>>();
+        assert_eq!(top_level_genres, expected);
+        assert_eq!(top_level_genres.len(), 11);
+    })
+}
+
+#[test]
+fn test_tag_translate() {
+    new_test_ext().execute_with(|| {
+        let locale: Locale = bounded_vec(b"pt-BR");
+        let name: Tag = bounded_vec("Ação".as_bytes());
+
+        assert_noop!(
+            Games::tag_translate(
+                RuntimeOrigin::signed(FUNDED_BUYER),
+                0,
+                locale.clone(),
+                Some(name.clone())
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Games::tag_translate(
+                RuntimeOrigin::root(),
+                0,
+                bounded_vec(b"pt_BR"),
+                Some(name.clone())
+            ),
+            Error::<Test>::LocaleInvalid
+        );
+        assert_noop!(
+            Games::tag_translate(
+                RuntimeOrigin::root(),
+                TagId::MAX,
+                locale.clone(),
+                Some(name.clone())
+            ),
+            Error::<Test>::TagNotFound
+        );
+        assert_noop!(
+            Games::tag_translate(RuntimeOrigin::root(), 0, locale.clone(), Some(Tag::default())),
+            Error::<Test>::TagInvalid
+        );
+
+        assert_ok!(Games::tag_translate(
+            RuntimeOrigin::root(),
+            0,
+            locale.clone(),
+            Some(name.clone())
+        ));
+        assert_eq!(TagTranslations::<Test>::get(&locale, 0), Some(name.clone()));
+        System::assert_last_event(
+            Event::TagTranslated { tag_id: 0, locale: locale.clone(), name: Some(name) }.into(),
+        );
+
+        assert_ok!(Games::tag_translate(RuntimeOrigin::root(), 0, locale.clone(), None));
+        assert_eq!(TagTranslations::<Test>::get(&locale, 0), None);
+        System::assert_last_event(Event::TagTranslated { tag_id: 0, locale, name: None }.into());
+    })
+}
+
+#[test]
+fn test_tags_for_locale() {
+    new_test_ext().execute_with(|| {
+        let locale: Locale = bounded_vec(b"de");
+        let name: Tag = bounded_vec(b"Rollenspiel");
+        assert_ok!(Games::tag_translate(
+            RuntimeOrigin::root(),
+            6,
+            locale.clone(),
+            Some(name.clone())
+        ));
+
+        let tags = Games::tags_for_locale(&locale);
+        assert_eq!(tags.len(), TAGS.len());
+        assert!(tags.contains(&(6, name)));
+        assert!(tags.contains(&(0, bounded_vec(b"Action"))));
+
+        let tags = Games::tags_for_locale(&bounded_vec(b"fr"));
+        assert!(tags.contains(&(6, bounded_vec(b"RPG"))));
+    })
+}
+
+#[test]
+fn test_genesis_tags() {
+    new_test_ext().execute_with(|| {
+        for (tag_id, _, tag) in TAGS.iter() {
+            let tag: Tag = bounded_vec(tag.as_bytes());
+            assert_eq!(TagIds::<Test>::get(&tag), Some(*tag_id));
+            assert_eq!(Tags::<Test>::get(tag_id), Some(tag));
+        }
+        assert_eq!(Tags::<Test>::count() as usize, TAGS.len());
+
+        for (tag_id, parent) in TAG_PARENTS.iter() {
+            assert_eq!(
+                TagTaxonomy::<Test>::get(tag_id).and_then(|info| info.parent),
+                Some(*parent)
+            );
+            assert!(TagChildren::<Test>::contains_key(parent, tag_id));
+        }
+        // "Metroidvania" is a platformer, and "Wargame" has no parent
+        assert_eq!(TagTaxonomy::<Test>::get(110).and_then(|info| info.parent), Some(55));
+        assert_eq!(TagTaxonomy::<Test>::get(139).and_then(|info| info.parent), None);
+    })
+}
+
+#[test]
+#[should_panic(expected = "Duplicate tag")]
+fn test_genesis_tags_duplicate_name() {
+    new_test_ext().execute_with(|| {
+        Games::insert_genesis_tags(
+            &[(1000, TagCategory::Other, "Duplicate"), (1001, TagCategory::Other, "Duplicate")],
+            &[],
+        );
+    })
+}
+
+#[test]
+#[should_panic(expected = "Duplicate tag id")]
+fn test_genesis_tags_duplicate_id() {
+    new_test_ext().execute_with(|| {
+        Games::insert_genesis_tags(&[(0, TagCategory::Other, "Unique")], &[]);
+    })
+}
+
+#[test]
+#[should_panic(expected = "Invalid parent of tag 1001")]
+fn test_genesis_tags_invalid_parent() {
+    new_test_ext().execute_with(|| {
+        // A sub-genre cannot be the child of a theme
+        Games::insert_genesis_tags(
+            &[(1000, TagCategory::Theme, "Parent"), (1001, TagCategory::SubGenre, "Child")],
+            &[(1001, 1000)],
+        );
+    })
+}
+
+fn tag_proposal_hold(who: &PublisherId<Test>) -> Balance {
+    <Balances as fungible::hold::Inspect<_>>::balance_on_hold(&HoldReason::TagProposal.into(), who)
+}
+
+#[test]
+fn test_tag_propose() {
+    new_test_ext().execute_with(|| {
+        let tag: Tag = bounded_vec(b"Deckbuilder");
+        let info = TagInfo { category: TagCategory::Genre, parent: Some(0) };
+
+        assert_noop!(
+            Games::tag_propose(RuntimeOrigin::signed(INVALID_PUBLISHER), tag.clone(), info),
+            Error::<Test>::InvalidPublisher
+        );
+        assert_noop!(
+            Games::tag_propose(RuntimeOrigin::signed(PUBLISHER), bounded_vec(b"Action"), info),
+            Error::<Test>::TagAlreadyExists
+        );
+        assert_noop!(
+            Games::tag_propose(
+                RuntimeOrigin::signed(PUBLISHER),
+                tag.clone(),
+                TagInfo { category: TagCategory::SubGenre, parent: Some(0) }
+            ),
+            Error::<Test>::TagInfoInvalid
+        );
+
+        let balance = <Balances as fungible::Inspect<_>>::balance(&PUBLISHER);
+        assert_ok!(Games::tag_propose(RuntimeOrigin::signed(PUBLISHER), tag.clone(), info));
+        assert_eq!(
+            <Balances as fungible::Inspect<_>>::balance(&PUBLISHER),
+            balance - TAG_PROPOSAL_DEPOSIT
+        );
+        assert_eq!(tag_proposal_hold(&PUBLISHER), TAG_PROPOSAL_DEPOSIT);
+        assert_eq!(
+            Games::tag_proposals(),
+            vec![(
+                0,
+                TagProposal {
+                    proposer: PUBLISHER,
+                    tag: tag.clone(),
+                    info,
+                    deposit: TAG_PROPOSAL_DEPOSIT,
+                    expires_at: 1 + TAG_PROPOSAL_PERIOD,
+                }
+            )]
+        );
+        assert_eq!(TagIds::<Test>::get(&tag), None);
+        System::assert_last_event(
+            Event::TagProposed { proposal_id: 0, proposer: PUBLISHER, tag, info }.into(),
+        );
+    })
+}
+
+#[test]
+fn test_tag_propose_agenda_full() {
+    new_test_ext().execute_with(|| {
+        let info = TagInfo::default();
+        assert_ok!(Games::tag_propose(RuntimeOrigin::signed(PUBLISHER), bounded_vec(b"A"), info));
+        assert_ok!(Games::tag_propose(RuntimeOrigin::signed(PUBLISHER), bounded_vec(b"B"), info));
+        assert_noop!(
+            Games::tag_propose(RuntimeOrigin::signed(PUBLISHER), bounded_vec(b"C"), info),
+            Error::<Test>::TagProposalAgendaFull
+        );
+    })
+}
+
+#[test]
+fn test_tag_proposal_approve() {
+    new_test_ext().execute_with(|| {
+        let tag: Tag = bounded_vec(b"Deckbuilder");
+        let info = TagInfo { category: TagCategory::Genre, parent: Some(0) };
+        let balance = <Balances as fungible::Inspect<_>>::balance(&PUBLISHER);
+        assert_ok!(Games::tag_propose(RuntimeOrigin::signed(PUBLISHER), tag.clone(), info));
+
+        assert_noop!(
+            Games::tag_proposal_approve(RuntimeOrigin::signed(PUBLISHER), 0),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Games::tag_proposal_approve(RuntimeOrigin::root(), 1),
+            Error::<Test>::TagProposalNotFound
+        );
+
+        let tag_id = NextTagId::<Test>::get();
+        assert_ok!(Games::tag_proposal_approve(RuntimeOrigin::root(), 0));
+        assert_eq!(TagIds::<Test>::get(&tag), Some(tag_id));
+        assert_eq!(TagTaxonomy::<Test>::get(tag_id), Some(info));
+        assert_eq!(<Balances as fungible::Inspect<_>>::balance(&PUBLISHER), balance);
+        assert_eq!(tag_proposal_hold(&PUBLISHER), 0);
+        assert!(Games::tag_proposals().is_empty());
+        System::assert_has_event(Event::TagAdded { tag_id, tag, info }.into());
+        System::assert_last_event(Event::TagProposalApproved { proposal_id: 0, tag_id }.into());
+
+        assert_noop!(
+            Games::tag_proposal_approve(RuntimeOrigin::root(), 0),
+            Error::<Test>::TagProposalNotFound
+        );
+    })
+}
+
+#[test]
+fn test_tag_proposal_approve_existing_tag() {
+    new_test_ext().execute_with(|| {
+        let tag: Tag = bounded_vec(b"Deckbuilder");
+        let info = TagInfo::default();
+        assert_ok!(Games::tag_propose(RuntimeOrigin::signed(PUBLISHER), tag.clone(), info));
+        assert_ok!(Games::tag_add(RuntimeOrigin::root(), tag, info));
+
+        assert_noop!(
+            Games::tag_proposal_approve(RuntimeOrigin::root(), 0),
+            Error::<Test>::TagAlreadyExists
+        );
+        assert_ok!(Games::tag_proposal_reject(RuntimeOrigin::root(), 0));
+    })
+}
+
+#[test]
+fn test_tag_proposal_reject() {
+    new_test_ext().execute_with(|| {
+        let tag: Tag = bounded_vec(b"Deckbuilder");
+        let balance = <Balances as fungible::Inspect<_>>::balance(&PUBLISHER);
+        assert_ok!(Games::tag_propose(
+            RuntimeOrigin::signed(PUBLISHER),
+            tag.clone(),
+            TagInfo::default()
+        ));
+
+        assert_noop!(
+            Games::tag_proposal_reject(RuntimeOrigin::signed(PUBLISHER), 0),
+            DispatchError::BadOrigin
+        );
+
+        assert_ok!(Games::tag_proposal_reject(RuntimeOrigin::root(), 0));
+        assert_eq!(TagIds::<Test>::get(&tag), None);
+        assert_eq!(
+            <Balances as fungible::Inspect<_>>::balance(&PUBLISHER),
+            balance - TAG_PROPOSAL_DEPOSIT
+        );
+        assert_eq!(tag_proposal_hold(&PUBLISHER), 0);
+        assert!(Games::tag_proposals().is_empty());
+        System::assert_last_event(
+            Event::TagProposalRejected { proposal_id: 0, slashed: TAG_PROPOSAL_DEPOSIT }.into(),
+        );
+
+        assert_noop!(
+            Games::tag_proposal_reject(RuntimeOrigin::root(), 0),
+            Error::<Test>::TagProposalNotFound
+        );
+    })
+}
+
+#[test]
+fn test_tag_proposal_expire() {
+    new_test_ext().execute_with(|| {
+        let balance = <Balances as fungible::Inspect<_>>::balance(&PUBLISHER);
+        let info = TagInfo::default();
+        assert_ok!(Games::tag_propose(RuntimeOrigin::signed(PUBLISHER), bounded_vec(b"A"), info));
+        assert_ok!(Games::tag_propose(RuntimeOrigin::signed(PUBLISHER), bounded_vec(b"B"), info));
+        assert_ok!(Games::tag_proposal_reject(RuntimeOrigin::root(), 1));
+
+        run_to_block(TAG_PROPOSAL_PERIOD);
+        assert_eq!(Games::tag_proposals().len(), 1);
+
+        run_to_block(1 + TAG_PROPOSAL_PERIOD);
+        assert!(Games::tag_proposals().is_empty());
+        assert_eq!(TagIds::<Test>::get(Tag::truncate_from(b"A".to_vec())), None);
+        assert_eq!(tag_proposal_hold(&PUBLISHER), 0);
+        assert_eq!(
+            <Balances as fungible::Inspect<_>>::balance(&PUBLISHER),
+            balance - TAG_PROPOSAL_DEPOSIT
+        );
+        System::assert_last_event(Event::TagProposalExpired { proposal_id: 0 }.into());
+    })
+}
+
+#[test]
+fn test_tag_vote() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let (roguelike, pixel_graphics, singleplayer) = (58, 159, 347);
+
+        assert_noop!(
+            Games::tag_vote(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id, roguelike),
+            Error::<Test>::GameNotOwned
+        );
+
+        OwnedGames::<Test>::insert(FUNDED_BUYER, (PUBLISHER, game_id), ());
+        assert_noop!(
+            Games::tag_vote(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id, TagId::MAX),
+            Error::<Test>::TagNotFound
+        );
+
+        assert_ok!(Games::tag_vote(
+            RuntimeOrigin::signed(FUNDED_BUYER),
+            PUBLISHER,
+            game_id,
+            roguelike
+        ));
+        assert_eq!(TagVotes::<Test>::get((PUBLISHER, game_id), roguelike), 1);
+        System::assert_last_event(
+            Event::TagVoted {
+                voter: FUNDED_BUYER,
+                publisher: PUBLISHER,
+                game_id,
+                tag_id: roguelike,
+            }
+            .into(),
+        );
+
+        assert_noop!(
+            Games::tag_vote(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id, roguelike),
+            Error::<Test>::TagVoteAlreadyCast
+        );
+
+        assert_ok!(Games::tag_vote(
+            RuntimeOrigin::signed(FUNDED_BUYER),
+            PUBLISHER,
+            game_id,
+            pixel_graphics
+        ));
+        assert_noop!(
+            Games::tag_vote(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id, singleplayer),
+            Error::<Test>::TagVoteLimitReached
+        );
+        assert_eq!(
+            OwnerTagVotes::<Test>::get(FUNDED_BUYER, (PUBLISHER, game_id)).into_inner(),
+            vec![roguelike, pixel_graphics]
+        );
+    })
+}
+
+#[test]
+fn test_tag_vote_deprecated_tag() {
+    new_test_ext().execute_with(|| {
+        OwnedGames::<Test>::insert(FUNDED_BUYER, (PUBLISHER, 1), ());
+        assert_ok!(Games::tag_deprecate(RuntimeOrigin::root(), 0));
+
+        assert_noop!(
+            Games::tag_vote(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, 1, 0),
+            Error::<Test>::TagNotFound
+        );
+    })
+}
+
+#[test]
+fn test_tag_vote_withdraw() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let (roguelike, singleplayer) = (58, 347);
+        OwnedGames::<Test>::insert(FUNDED_BUYER, (PUBLISHER, game_id), ());
+        OwnedGames::<Test>::insert(NON_FUNDED_BUYER, (PUBLISHER, game_id), ());
+        for voter in [FUNDED_BUYER, NON_FUNDED_BUYER] {
+            assert_ok!(Games::tag_vote(
+                RuntimeOrigin::signed(voter),
+                PUBLISHER,
+                game_id,
+                roguelike
+            ));
+        }
+
+        assert_noop!(
+            Games::tag_vote_withdraw(
+                RuntimeOrigin::signed(FUNDED_BUYER),
+                PUBLISHER,
+                game_id,
+                singleplayer
+            ),
+            Error::<Test>::TagVoteNotFound
+        );
+
+        assert_ok!(Games::tag_vote_withdraw(
+            RuntimeOrigin::signed(FUNDED_BUYER),
+            PUBLISHER,
+            game_id,
+            roguelike
+        ));
+        assert_eq!(TagVotes::<Test>::get((PUBLISHER, game_id), roguelike), 1);
+        assert!(!OwnerTagVotes::<Test>::contains_key(FUNDED_BUYER, (PUBLISHER, game_id)));
+        System::assert_last_event(
+            Event::TagVoteWithdrawn {
+                voter: FUNDED_BUYER,
+                publisher: PUBLISHER,
+                game_id,
+                tag_id: roguelike,
+            }
+            .into(),
+        );
+
+        assert_noop!(
+            Games::tag_vote_withdraw(
+                RuntimeOrigin::signed(FUNDED_BUYER),
+                PUBLISHER,
+                game_id,
+                roguelike
+            ),
+            Error::<Test>::TagVoteNotFound
+        );
+
+        assert_ok!(Games::tag_vote_withdraw(
+            RuntimeOrigin::signed(NON_FUNDED_BUYER),
+            PUBLISHER,
+            game_id,
+            roguelike
+        ));
+        assert!(!TagVotes::<Test>::contains_key((PUBLISHER, game_id), roguelike));
+    })
+}
+
+#[test]
+fn test_top_tags() {
+    new_test_ext().execute_with(|| {
+        let game = (PUBLISHER, 1);
+        let (roguelike, pixel_graphics, singleplayer, co_op) = (58, 159, 347, 340);
+        let votes = [
+            (FUNDED_BUYER, [singleplayer, roguelike]),
+            (NON_FUNDED_BUYER, [roguelike, co_op]),
+            (PUBLISHER, [pixel_graphics, singleplayer]),
+        ];
+        for (voter, tags) in votes {
+            OwnedGames::<Test>::insert(voter, &game, ());
+            for tag_id in tags {
+                assert_ok!(Games::tag_vote(RuntimeOrigin::signed(voter), PUBLISHER, 1, tag_id));
+            }
+        }
+
+        assert_eq!(
+            Games::top_tags(&game, 3),
+            vec![(roguelike, 2), (singleplayer, 2), (pixel_graphics, 1)]
+        );
+        assert_eq!(Games::top_tags(&game, 10).len(), 4);
+        assert!(Games::top_tags(&(PUBLISHER, 2), 10).is_empty());
+    })
+}
+
+/// Publishes a game sold individually at the given price.
+#[test]
+fn test_wishlist_add() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Games::wishlist_add(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, 1),
+            Error::<Test>::GameNotFound
+        );
+
+        add_game(1, DistributionKind::Individual, 1000);
+        add_game(2, DistributionKind::Individual, 1000);
+        add_game(3, DistributionKind::Individual, 1000);
+
+        assert_ok!(Games::wishlist_add(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, 1));
+        assert_eq!(Wishlists::<Test>::get(FUNDED_BUYER).into_inner(), vec![(PUBLISHER, 1)]);
+        assert_eq!(WishlistCounts::<Test>::get((PUBLISHER, 1)), 1);
+        System::assert_last_event(
+            Event::WishlistAdded { who: FUNDED_BUYER, publisher: PUBLISHER, game_id: 1 }.into(),
+        );
+
+        assert_noop!(
+            Games::wishlist_add(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, 1),
+            Error::<Test>::GameAlreadyWishlisted
+        );
+
+        assert_ok!(Games::wishlist_add(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, 2));
+        assert_noop!(
+            Games::wishlist_add(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, 3),
+            Error::<Test>::WishlistFull
+        );
+
+        // Owned games cannot be wishlisted
+        OwnedGames::<Test>::insert(NON_FUNDED_BUYER, (PUBLISHER, 3), ());
+        assert_noop!(
+            Games::wishlist_add(RuntimeOrigin::signed(NON_FUNDED_BUYER), PUBLISHER, 3),
+            Error::<Test>::GameAlreadyExists
+        );
+    })
+}
+
+#[test]
+fn test_wishlist_remove() {
+    new_test_ext().execute_with(|| {
+        add_game(1, DistributionKind::Individual, 1000);
+        assert_ok!(Games::wishlist_add(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, 1));
+        assert_ok!(Games::wishlist_add(RuntimeOrigin::signed(NON_FUNDED_BUYER), PUBLISHER, 1));
+        assert_eq!(WishlistCounts::<Test>::get((PUBLISHER, 1)), 2);
+
+        assert_ok!(Games::wishlist_remove(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, 1));
+        assert!(!Wishlists::<Test>::contains_key(FUNDED_BUYER));
+        assert_eq!(WishlistCounts::<Test>::get((PUBLISHER, 1)), 1);
+        System::assert_last_event(
+            Event::WishlistRemoved { who: FUNDED_BUYER, publisher: PUBLISHER, game_id: 1 }.into(),
+        );
+
+        assert_noop!(
+            Games::wishlist_remove(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, 1),
+            Error::<Test>::GameNotWishlisted
+        );
+
+        assert_ok!(Games::wishlist_remove(RuntimeOrigin::signed(NON_FUNDED_BUYER), PUBLISHER, 1));
+        assert!(!WishlistCounts::<Test>::contains_key((PUBLISHER, 1)));
+    })
+}
+
+#[test]
+fn test_game_buy_removes_from_wishlist() {
+    new_test_ext().execute_with(|| {
+        add_game(1, DistributionKind::Individual, 1000);
+        assert_ok!(Games::wishlist_add(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, 1));
+
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, 1, None));
+        assert!(!Wishlists::<Test>::contains_key(FUNDED_BUYER));
+        assert!(!WishlistCounts::<Test>::contains_key((PUBLISHER, 1)));
+    })
+}
+
+#[test]
+fn test_game_price_set() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Games::game_price_set(RuntimeOrigin::signed(PUBLISHER), 1, 800),
+            Error::<Test>::GameNotFound
+        );
+
+        add_game(1, DistributionKind::Individual, 1000);
+        assert_noop!(
+            Games::game_price_set(RuntimeOrigin::signed(PUBLISHER), 1, 0),
+            Error::<Test>::GamePriceInvalid
+        );
+
+        // Price drops of games in no wishlist are not notified
+        assert_ok!(Games::game_price_set(RuntimeOrigin::signed(PUBLISHER), 1, 900));
+        System::assert_last_event(
+            Event::GamePriceSet { publisher: PUBLISHER, game_id: 1, price: 900 }.into(),
+        );
+
+        assert_ok!(Games::wishlist_add(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, 1));
+        assert_ok!(Games::game_price_set(RuntimeOrigin::signed(PUBLISHER), 1, 800));
+        assert_eq!(Games::effective_price(&PUBLISHER, 1), Some((800, PaymentAsset::Native)));
+        System::assert_last_event(
+            Event::WishlistedGamePriceDropped {
+                publisher: PUBLISHER,
+                game_id: 1,
+                old_price: 900,
+                new_price: 800,
+                asset: PaymentAsset::Native,
+            }
+            .into(),
+        );
+
+        // Price rises are not notified
+        assert_ok!(Games::game_price_set(RuntimeOrigin::signed(PUBLISHER), 1, 1200));
+        System::assert_last_event(
+            Event::GamePriceSet { publisher: PUBLISHER, game_id: 1, price: 1200 }.into(),
+        );
+    })
+}
+
+#[test]
+fn test_game_price_set_free_game() {
+    new_test_ext().execute_with(|| {
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: Default::default(),
+            distribution: Distribution::Free {
+                cid: bounded_vec(b"QmYwAPJzv5CZsnAztbCxjRMoa6zFzFG8pGzLFZxojtL8MX"),
+            },
+            regional_prices: Default::default(),
+            rating: Default::default(),
+        };
+        PublishedGames::<Test>::insert(PUBLISHER, 1, details);
+
+        assert_noop!(
+            Games::game_price_set(RuntimeOrigin::signed(PUBLISHER), 1, 800),
+            Error::<Test>::GamePriceInvalid
+        );
+        let discount = Discount { percent: Percent::from_percent(20), ends_at: 10 };
+        assert_noop!(
+            Games::game_discount_set(RuntimeOrigin::signed(PUBLISHER), 1, Some(discount)),
+            Error::<Test>::GamePriceInvalid
+        );
+    })
+}
+
+#[test]
+fn test_game_discount_set() {
+    new_test_ext().execute_with(|| {
+        add_game(1, DistributionKind::Individual, 1000);
+        assert_ok!(Games::wishlist_add(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, 1));
+
+        let invalid = [
+            Discount { percent: Percent::from_percent(0), ends_at: 10 },
+            Discount { percent: Percent::from_percent(100), ends_at: 10 },
+            Discount { percent: Percent::from_percent(20), ends_at: 1 },
+        ];
+        for discount in invalid {
+            assert_noop!(
+                Games::game_discount_set(RuntimeOrigin::signed(PUBLISHER), 1, Some(discount)),
+                Error::<Test>::DiscountInvalid
+            );
+        }
+
+        let discount = Discount { percent: Percent::from_percent(25), ends_at: 10 };
+        assert_ok!(Games::game_discount_set(
+            RuntimeOrigin::signed(PUBLISHER),
+            1,
+            Some(discount.clone())
+        ));
+        assert_eq!(GameDiscounts::<Test>::get(PUBLISHER, 1), Some(discount.clone()));
+        assert_eq!(Games::effective_price(&PUBLISHER, 1), Some((750, PaymentAsset::Native)));
+        System::assert_has_event(
+            Event::GameDiscountSet { publisher: PUBLISHER, game_id: 1, discount: Some(discount) }
+                .into(),
+        );
+        System::assert_last_event(
+            Event::WishlistedGamePriceDropped {
+                publisher: PUBLISHER,
+                game_id: 1,
+                old_price: 1000,
+                new_price: 750,
+                asset: PaymentAsset::Native,
+            }
+            .into(),
+        );
+
+        // The discount no longer applies once it ends
+        System::set_block_number(10);
+        assert_eq!(Games::effective_price(&PUBLISHER, 1), Some((1000, PaymentAsset::Native)));
+
+        assert_ok!(Games::game_discount_set(RuntimeOrigin::signed(PUBLISHER), 1, None));
+        assert!(!GameDiscounts::<Test>::contains_key(PUBLISHER, 1));
+        System::assert_last_event(
+            Event::GameDiscountSet { publisher: PUBLISHER, game_id: 1, discount: None }.into(),
+        );
+    })
+}
+
+#[test]
+fn test_game_buy_discounted() {
+    new_test_ext().execute_with(|| {
+        add_game(1, DistributionKind::Individual, 1000);
+        let discount = Discount { percent: Percent::from_percent(25), ends_at: 10 };
+        assert_ok!(Games::game_discount_set(RuntimeOrigin::signed(PUBLISHER), 1, Some(discount)));
+
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, 1, None));
+        assert_eq!(
+            BuyerOrders::<Test>::get(FUNDED_BUYER, (PUBLISHER, 1)),
+            Some(OrderDetails {
+                deposit: 750,
+                asset: PaymentAsset::Native,
+                region: None,
+                receipt_deposit: 0
+            })
+        );
+    })
+}
+
+#[test]
+fn test_wishlist() {
+    new_test_ext().execute_with(|| {
+        add_game(1, DistributionKind::Individual, 1000);
+        add_game(2, DistributionKind::Individual, 999);
+        assert_ok!(Games::wishlist_add(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, 1));
+        assert_ok!(Games::wishlist_add(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, 2));
+        let discount = Discount { percent: Percent::from_percent(10), ends_at: 10 };
+        assert_ok!(Games::game_discount_set(RuntimeOrigin::signed(PUBLISHER), 2, Some(discount)));
+
+        assert_eq!(
+            Games::wishlist(&FUNDED_BUYER),
+            vec![
+                WishlistEntry {
+                    publisher: PUBLISHER,
+                    game_id: 1,
+                    price: Some((1000, PaymentAsset::Native)),
+                    discount: None,
+                },
+                // The discounted price is rounded up
+                WishlistEntry {
+                    publisher: PUBLISHER,
+                    game_id: 2,
+                    price: Some((900, PaymentAsset::Native)),
+                    discount: Some(Percent::from_percent(10)),
+                },
+            ]
+        );
+        assert_eq!(Games::wishlist(&NON_FUNDED_BUYER), vec![]);
+    })
+}
+
+#[test]
+fn test_games_by_publisher() {
+    new_test_ext().execute_with(|| {
+        for game_id in 1..=5 {
+            add_game(game_id, DistributionKind::Individual, 1000);
+        }
+
+        let first = Games::games_by_publisher(&PUBLISHER, None, 3);
+        assert_eq!(first.len(), 3);
+        let last = first.last().map(|(game_id, _)| *game_id);
+        let rest = Games::games_by_publisher(&PUBLISHER, last, 3);
+        assert_eq!(rest.len(), 2);
+
+        let mut game_ids =
+            first.iter().chain(rest.iter()).map(|(game_id, _)| *game_id).collect::<Vec<_>>();
+        game_ids.sort();
+        assert_eq!(game_ids, vec![1, 2, 3, 4, 5]);
+        assert_eq!(Some(&first[0].1), Games::game_details(&PUBLISHER, first[0].0).as_ref());
+
+        assert_eq!(Games::games_by_publisher(&FUNDED_BUYER, None, 3), vec![]);
+        assert_eq!(Games::game_details(&PUBLISHER, 6), None);
+    })
+}
+
+#[test]
+fn test_owned_games() {
+    new_test_ext().execute_with(|| {
+        add_game(1, DistributionKind::Individual, 1000);
+        add_game(2, DistributionKind::Individual, 1000);
+        OwnedGames::<Test>::insert(FUNDED_BUYER, (PUBLISHER, 1), ());
+        OwnedGames::<Test>::insert(FUNDED_BUYER, (PUBLISHER, 2), ());
+
+        let mut games = Games::owned_games(&FUNDED_BUYER);
+        games.sort();
+        assert_eq!(games, vec![(PUBLISHER, 1), (PUBLISHER, 2)]);
+        assert!(Games::is_owner(&FUNDED_BUYER, &PUBLISHER, 1));
+        assert!(!Games::is_owner(&NON_FUNDED_BUYER, &PUBLISHER, 1));
+        assert_eq!(Games::owned_games(&NON_FUNDED_BUYER), vec![]);
+    })
+}
+
+#[test]
+fn test_pending_orders() {
+    new_test_ext().execute_with(|| {
+        add_game(1, DistributionKind::Individual, 1000);
+        assert_eq!(Games::pending_orders(&PUBLISHER), vec![]);
+
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, 1, None));
+        assert_eq!(
+            Games::pending_orders(&PUBLISHER),
+            vec![(
+                1,
+                FUNDED_BUYER,
+                OrderDetails {
+                    deposit: 1000,
+                    asset: PaymentAsset::Native,
+                    region: None,
+                    receipt_deposit: 0
+                }
+            )]
+        );
+
+        assert_ok!(Games::order_fulfill(RuntimeOrigin::signed(PUBLISHER), 1, FUNDED_BUYER));
+        assert_eq!(Games::pending_orders(&PUBLISHER), vec![]);
+    })
+}
+
+#[test]
+fn test_game_price() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(Games::game_price(PUBLISHER, 1), None);
+
+        add_game(1, DistributionKind::Individual, 1000);
+        assert_eq!(Games::game_price(PUBLISHER, 1), Some((1000, PaymentAsset::Native)));
+
+        let discount = Discount { percent: Percent::from_percent(25), ends_at: 10 };
+        GameDiscounts::<Test>::insert(PUBLISHER, 1, discount);
+        assert_eq!(Games::game_price(PUBLISHER, 1), Some((750, PaymentAsset::Native)));
+
+        System::set_block_number(10);
+        assert_eq!(Games::game_price(PUBLISHER, 1), Some((1000, PaymentAsset::Native)));
+    })
+}
+
+#[test]
+fn test_owns_game() {
+    new_test_ext().execute_with(|| {
+        add_game(1, DistributionKind::Free, 0);
+        assert!(!Games::owns_game(FUNDED_BUYER, PUBLISHER, 1));
+
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, 1, None));
+        assert!(Games::owns_game(FUNDED_BUYER, PUBLISHER, 1));
+        assert!(!Games::owns_game(NON_FUNDED_BUYER, PUBLISHER, 1));
+    })
+}
+
+#[test]
+fn test_receipt_game_buy() {
+    new_test_ext().execute_with(|| {
+        ReceiptDeposit::set(100);
+        let price = 12345;
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: Default::default(),
+            distribution: Distribution::Instant {
+                price,
+                asset: PaymentAsset::Native,
+                cid: bounded_vec(b"QmYwAPJzv5CZsnAztbCxjRMoa6zFzFG8pGzLFZxojtL8MX"),
+            },
+            regional_prices: Default::default(),
+            rating: Default::default(),
+        };
+        PublishedGames::<Test>::insert(PUBLISHER, 1, details);
+
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, 1, None));
+
+        let receipt = Receipt {
+            price,
+            publisher_amount: price,
+            protocol_amount: 0,
+            asset: PaymentAsset::Native,
+            region: None,
+            kind: DistributionKind::Instant,
+            block: 1,
+            deposit: 100,
+        };
+        assert_eq!(Receipts::<Test>::get(FUNDED_BUYER, (PUBLISHER, 1)), Some(receipt.clone()));
+        assert_eq!(PublisherReceipts::<Test>::get(PUBLISHER, (1, FUNDED_BUYER)), Some(()));
+        assert_eq!(Games::receipts_by_buyer(&FUNDED_BUYER), vec![((PUBLISHER, 1), receipt)]);
+        assert_eq!(
+            <Balances as fungible::hold::Inspect<_>>::balance_on_hold(
+                &HoldReason::ReceiptDeposit.into(),
+                &FUNDED_BUYER
+            ),
+            100
+        );
+    })
+}
+
+#[test]
+fn test_receipt_game_buy_free() {
+    new_test_ext().execute_with(|| {
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: Default::default(),
+            distribution: Distribution::Free {
+                cid: bounded_vec(b"QmYwAPJzv5CZsnAztbCxjRMoa6zFzFG8pGzLFZxojtL8MX"),
+            },
+            regional_prices: Default::default(),
+            rating: Default::default(),
+        };
+        PublishedGames::<Test>::insert(PUBLISHER, 1, details);
+        ReceiptDeposit::set(100);
+
+        // Free games need no funds, so no receipt deposit is held
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(NON_FUNDED_BUYER), PUBLISHER, 1, None));
+        assert_eq!(
+            <Balances as fungible::hold::Inspect<_>>::balance_on_hold(
+                &HoldReason::ReceiptDeposit.into(),
+                &NON_FUNDED_BUYER
+            ),
+            0
+        );
+        assert_eq!(
+            Receipts::<Test>::get(NON_FUNDED_BUYER, (PUBLISHER, 1)),
+            Some(Receipt {
+                price: 0,
+                publisher_amount: 0,
+                protocol_amount: 0,
+                asset: PaymentAsset::Native,
+                region: None,
+                kind: DistributionKind::Free,
+                block: 1,
+                deposit: 0,
+            })
+        );
+    })
+}
+
+#[test]
+fn test_receipt_order_fulfill() {
+    new_test_ext().execute_with(|| {
+        ReceiptDeposit::set(100);
+        add_game(1, DistributionKind::Individual, 1000);
+
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, 1, None));
+        assert_eq!(Receipts::<Test>::get(FUNDED_BUYER, (PUBLISHER, 1)), None);
+        assert_eq!(
+            <Balances as fungible::hold::Inspect<_>>::balance_on_hold(
+                &HoldReason::ReceiptDeposit.into(),
+                &FUNDED_BUYER
+            ),
+            100
+        );
+
+        System::set_block_number(5);
+        assert_ok!(Games::order_fulfill(RuntimeOrigin::signed(PUBLISHER), 1, FUNDED_BUYER));
+
+        assert_eq!(
+            Receipts::<Test>::get(FUNDED_BUYER, (PUBLISHER, 1)),
+            Some(Receipt {
+                price: 1000,
+                publisher_amount: 1000,
+                protocol_amount: 0,
+                asset: PaymentAsset::Native,
+                region: None,
+                kind: DistributionKind::Individual,
+                block: 5,
+                deposit: 100,
+            })
+        );
+        assert_eq!(
+            <Balances as fungible::hold::Inspect<_>>::balance_on_hold(
+                &HoldReason::ReceiptDeposit.into(),
+                &PUBLISHER
+            ),
+            0
+        );
+    })
+}
+
+#[test]
+fn test_receipt_game_buy_no_funds() {
+    new_test_ext().execute_with(|| {
+        ReceiptDeposit::set(2 * INITIAL_BALANCE);
+        add_game(1, DistributionKind::Individual, 1000);
+
+        assert_noop!(
+            Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, 1, None),
+            TokenError::FundsUnavailable
+        );
+    })
+}
+
+#[test]
+fn test_receipt_order_cancel() {
+    new_test_ext().execute_with(|| {
+        ReceiptDeposit::set(100);
+        add_game(1, DistributionKind::Individual, 1000);
+
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, 1, None));
+        assert_ok!(Games::order_cancel(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, 1));
+        assert_eq!(Balances::free_balance(FUNDED_BUYER), INITIAL_BALANCE);
+        assert_eq!(Receipts::<Test>::get(FUNDED_BUYER, (PUBLISHER, 1)), None);
+    })
+}
+
+#[test]
+fn test_receipt_preorder_release() {
+    new_test_ext().execute_with(|| {
+        ReceiptDeposit::set(100);
+        add_game(1, DistributionKind::PreOrder, 1000);
+
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, 1, None));
+        assert_ok!(Games::preorder_release(
+            RuntimeOrigin::signed(PUBLISHER),
+            1,
+            5,
+            bounded_vec(GAME_CID)
+        ));
+        run_to_block(6);
+
+        assert_eq!(
+            Receipts::<Test>::get(FUNDED_BUYER, (PUBLISHER, 1)),
+            Some(Receipt {
+                price: 1000,
+                publisher_amount: 1000,
+                protocol_amount: 0,
+                asset: PaymentAsset::Native,
+                region: None,
+                kind: DistributionKind::PreOrder,
+                block: 5,
+                deposit: 100,
+            })
+        );
+        assert_eq!(PublisherReceipts::<Test>::get(PUBLISHER, (1, FUNDED_BUYER)), Some(()));
+    })
+}
+
+#[test]
+fn test_receipt_preorder_cancel() {
+    new_test_ext().execute_with(|| {
+        ReceiptDeposit::set(100);
+        add_game(1, DistributionKind::PreOrder, 1000);
+
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, 1, None));
+        assert_ok!(Games::preorder_cancel(RuntimeOrigin::signed(PUBLISHER), 1));
+        run_to_block(2);
+
+        assert_eq!(Balances::free_balance(FUNDED_BUYER), INITIAL_BALANCE);
+        assert_eq!(Receipts::<Test>::get(FUNDED_BUYER, (PUBLISHER, 1)), None);
+    })
+}
+
+#[test]
+fn test_receipt_remove() {
+    new_test_ext().execute_with(|| {
+        ReceiptDeposit::set(100);
+        add_game(1, DistributionKind::Individual, 1000);
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, 1, None));
+        assert_ok!(Games::order_fulfill(RuntimeOrigin::signed(PUBLISHER), 1, FUNDED_BUYER));
+
+        assert_noop!(
+            Games::receipt_remove(RuntimeOrigin::signed(PUBLISHER), PUBLISHER, 1),
+            Error::<Test>::ReceiptNotFound
+        );
+        assert_noop!(
+            Games::receipt_remove(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, 2),
+            Error::<Test>::ReceiptNotFound
+        );
+
+        assert_ok!(Games::receipt_remove(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, 1));
+        assert_eq!(Receipts::<Test>::get(FUNDED_BUYER, (PUBLISHER, 1)), None);
+        assert_eq!(PublisherReceipts::<Test>::get(PUBLISHER, (1, FUNDED_BUYER)), None);
+        assert_eq!(
+            <Balances as fungible::hold::Inspect<_>>::balance_on_hold(
+                &HoldReason::ReceiptDeposit.into(),
+                &FUNDED_BUYER
+            ),
+            0
+        );
+        System::assert_last_event(
+            Event::ReceiptRemoved { buyer: FUNDED_BUYER, publisher: PUBLISHER, game_id: 1 }.into(),
+        );
+        assert_eq!(OwnedGames::<Test>::get(FUNDED_BUYER, (PUBLISHER, 1)), Some(()));
+    })
+}
+
+#[test]
+fn test_receipts_by_publisher() {
+    new_test_ext().execute_with(|| {
+        add_game(1, DistributionKind::Individual, 1000);
+        add_game(2, DistributionKind::Individual, 2000);
+        for game_id in [1, 2] {
+            assert_ok!(Games::game_buy(
+                RuntimeOrigin::signed(FUNDED_BUYER),
+                PUBLISHER,
+                game_id,
+                None
+            ));
+            assert_ok!(Games::order_fulfill(
+                RuntimeOrigin::signed(PUBLISHER),
+                game_id,
+                FUNDED_BUYER
+            ));
+        }
+
+        let receipts = Games::receipts_by_publisher(&PUBLISHER, None, 10);
+        assert_eq!(receipts.len(), 2);
+        assert!(receipts.iter().all(|(_, buyer, receipt)| {
+            *buyer == FUNDED_BUYER && receipt.kind == DistributionKind::Individual
+        }));
+
+        let (game_id, buyer, _) = receipts[0].clone();
+        let rest = Games::receipts_by_publisher(&PUBLISHER, Some((game_id, buyer)), 10);
+        assert_eq!(rest, receipts[1..].to_vec());
+        assert_eq!(Games::receipts_by_publisher(&PUBLISHER, None, 1), receipts[..1].to_vec());
+    })
+}
+
+#[test]
+fn test_game_sales() {
+    new_test_ext().execute_with(|| {
+        add_game(1, DistributionKind::Individual, 1000);
+        assert_eq!(Games::game_sales(&PUBLISHER, 1), vec![]);
+
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, 1, None));
+        assert_eq!(GameSales::<Test>::get((PUBLISHER, 1), PaymentAsset::Native).open_orders, 1);
+
+        assert_ok!(Games::order_cancel(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, 1));
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, 1, None));
+        assert_ok!(Games::order_fulfill(RuntimeOrigin::signed(PUBLISHER), 1, FUNDED_BUYER));
+
+        let individual = SalesStats {
+            units: UnitsSold { individual: 1, ..Default::default() },
+            revenue: 1000,
+            refunds: 1,
+            refunded: 1000,
+            open_orders: 0,
+        };
+        assert_eq!(Games::game_sales(&PUBLISHER, 1), vec![(PaymentAsset::Native, individual)]);
+
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: Default::default(),
+            distribution: Distribution::Instant {
+                price: 500,
+                asset: PaymentAsset::Native,
+                cid: bounded_vec(b"QmYwAPJzv5CZsnAztbCxjRMoa6zFzFG8pGzLFZxojtL8MX"),
+            },
+            regional_prices: Default::default(),
+            rating: Default::default(),
+        };
+        PublishedGames::<Test>::insert(PUBLISHER, 2, details);
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, 2, None));
+
+        assert_eq!(
+            Games::publisher_sales(&PUBLISHER),
+            vec![(
+                PaymentAsset::Native,
+                SalesStats {
+                    units: UnitsSold { instant: 1, individual: 1, ..Default::default() },
+                    revenue: 1500,
+                    refunds: 1,
+                    refunded: 1000,
+                    open_orders: 0,
+                }
+            )]
+        );
+    })
+}
+
+#[test]
+fn test_game_sales_preorder() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        add_game(game_id, DistributionKind::PreOrder, 12345);
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id, None));
+        assert_eq!(
+            GameSales::<Test>::get((PUBLISHER, game_id), PaymentAsset::Native).open_orders,
+            1
+        );
+
+        assert_ok!(Games::preorder_cancel(RuntimeOrigin::signed(PUBLISHER), game_id));
+        run_to_block(2);
+
+        assert_eq!(
+            PublisherSales::<Test>::get(PUBLISHER, PaymentAsset::Native),
+            SalesStats { refunds: 1, refunded: 12345, ..Default::default() }
+        );
+    })
+}
+
+/// Builds a report of the availability of the game made at the given block, signed by the
+/// [`REPORTER`].
+fn availability_report(
+    game_id: GameId,
+    reachable: bool,
+    block_number: u64,
+) -> (AvailabilityReport<UintAuthorityId, u64, u64>, TestSignature) {
+    let report = AvailabilityReport {
+        publisher: PUBLISHER,
+        game_id,
+        reachable,
+        block_number,
+        public: UintAuthorityId(REPORTER),
+    };
+    let signature = TestSignature(REPORTER, report.encode());
+    (report, signature)
+}
+
+/// Expects a request of the CID to the stub IPFS gateway.
+fn expect_cid_request(state: &mut OffchainState, cid: &Cid) {
+    state.expect_request(PendingRequest {
+        method: "HEAD".into(),
+        uri: format!("http://127.0.0.1:8080/ipfs/{}", String::from_utf8_lossy(cid)),
+        sent: true,
+        response: Some(vec![]),
+        ..Default::default()
+    });
+}
+
+#[test]
+fn test_game_availability_report() {
+    new_test_ext().execute_with(|| {
+        add_game(1, DistributionKind::Free, 0);
+        add_game(2, DistributionKind::Individual, 1000);
+
+        let (report, signature) = availability_report(1, false, 1);
+        assert_noop!(
+            Games::game_availability_report(
+                RuntimeOrigin::signed(PUBLISHER),
+                report.clone(),
+                signature.clone()
+            ),
+            DispatchError::BadOrigin
+        );
+        let (other_game, other_signature) = availability_report(2, false, 1);
+        assert_noop!(
+            Games::game_availability_report(RuntimeOrigin::none(), other_game, other_signature),
+            Error::<Test>::AvailabilityUnchanged
+        );
+        let (reachable, reachable_signature) = availability_report(1, true, 1);
+        assert_noop!(
+            Games::game_availability_report(
+                RuntimeOrigin::none(),
+                reachable.clone(),
+                reachable_signature.clone()
+            ),
+            Error::<Test>::AvailabilityUnchanged
+        );
+
+        assert_ok!(Games::game_availability_report(RuntimeOrigin::none(), report, signature));
+        assert_eq!(UnreachableGames::<Test>::get((PUBLISHER, 1)), Some(1));
+        System::assert_last_event(
+            Event::GameUnreachable { publisher: PUBLISHER, game_id: 1 }.into(),
+        );
+
+        assert_ok!(Games::game_availability_report(
+            RuntimeOrigin::none(),
+            reachable,
+            reachable_signature
+        ));
+        assert_eq!(UnreachableGames::<Test>::get((PUBLISHER, 1)), None);
+        System::assert_last_event(Event::GameReachable { publisher: PUBLISHER, game_id: 1 }.into());
+    })
+}
+
+#[test]
+fn test_game_availability_report_validate_unsigned() {
+    new_test_ext().execute_with(|| {
+        add_game(1, DistributionKind::Free, 0);
+        let now = 2 * CID_CHECK_INTERVAL;
+        System::set_block_number(now);
+        let validate = |(report, signature)| {
+            let call = crate::Call::game_availability_report { report, signature };
+            Games::validate_unsigned(TransactionSource::External, &call)
+        };
+
+        let signed = availability_report(1, false, now);
+        assert_eq!(validate(signed.clone()), InvalidTransaction::BadSigner.into());
+        assert_ok!(Games::cid_reporter_set(RuntimeOrigin::root(), REPORTER, true));
+
+        let (report, signature) = signed.clone();
+        let forged = (AvailabilityReport { reachable: true, ..report }, signature);
+        assert_eq!(validate(forged), InvalidTransaction::BadProof.into());
+
+        let valid = validate(signed.clone()).unwrap();
+        assert!(valid.propagate);
+        assert_eq!(
+            valid.provides,
+            vec![("LiganiteGamesAvailability", (REPORTER, PUBLISHER, 1 as GameId)).encode()]
+        );
+        assert_eq!(valid.longevity, CID_CHECK_INTERVAL);
+
+        let old = availability_report(1, false, CID_CHECK_INTERVAL - 1);
+        assert_eq!(validate(old), InvalidTransaction::Stale.into());
+        let future = availability_report(1, false, now + 1);
+        assert_eq!(validate(future), InvalidTransaction::Future.into());
+
+        UnreachableGames::<Test>::insert((PUBLISHER, 1), 1);
+        assert_eq!(validate(signed), InvalidTransaction::Stale.into());
+    })
+}
+
+#[test]
+fn test_cid_reporter_set() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Games::cid_reporter_set(RuntimeOrigin::signed(REPORTER), REPORTER, true),
+            DispatchError::BadOrigin
+        );
+
+        assert_ok!(Games::cid_reporter_set(RuntimeOrigin::root(), REPORTER, true));
+        assert_eq!(CidReporters::<Test>::get(REPORTER), Some(()));
+        System::assert_last_event(
+            Event::CidReporterSet { reporter: REPORTER, enabled: true }.into(),
+        );
+
+        assert_ok!(Games::cid_reporter_set(RuntimeOrigin::root(), REPORTER, false));
+        assert_eq!(CidReporters::<Test>::get(REPORTER), None);
+        System::assert_last_event(
+            Event::CidReporterSet { reporter: REPORTER, enabled: false }.into(),
+        );
+    })
+}
+
+#[test]
+fn test_offchain_worker_reports_reachable_game() {
+    let (offchain, offchain_state) = TestOffchainExt::new();
+    let (pool, pool_state) = TestTransactionPoolExt::new();
+    new_offchain_test_ext(offchain, pool).execute_with(|| {
+        add_game(1, DistributionKind::Free, 0);
+        let cid: Cid = bounded_vec(GAME_CID);
+        UnreachableGames::<Test>::insert((PUBLISHER, 1), 1);
+        sp_io::offchain::local_storage_set(
+            StorageKind::PERSISTENT,
+            IPFS_GATEWAY_KEY,
+            b"http://127.0.0.1:8080/",
+        );
+        expect_cid_request(&mut offchain_state.write(), &cid);
+
+        Games::offchain_worker(CID_CHECK_INTERVAL);
+
+        let tx = pool_state.write().transactions.pop().unwrap();
+        let tx = Extrinsic::decode(&mut &*tx).unwrap();
+        let (report, signature) = availability_report(1, true, CID_CHECK_INTERVAL);
+        assert_eq!(
+            tx.function,
+            RuntimeCall::Games(crate::Call::game_availability_report { report, signature })
+        );
+        assert!(pool_state.read().transactions.is_empty());
+    })
+}
+
+#[test]
+fn test_offchain_worker_skips_unchanged_games() {
+    let (offchain, offchain_state) = TestOffchainExt::new();
+    let (pool, pool_state) = TestTransactionPoolExt::new();
+    new_offchain_test_ext(offchain, pool).execute_with(|| {
+        add_game(1, DistributionKind::Free, 0);
+        let cid: Cid = bounded_vec(GAME_CID);
+
+        // No request is sent without a gateway, without a reporter key, or between the checks
+        Games::offchain_worker(CID_CHECK_INTERVAL);
+        sp_io::offchain::local_storage_set(
+            StorageKind::PERSISTENT,
+            IPFS_GATEWAY_KEY,
+            b"http://127.0.0.1:8080",
+        );
+        UintAuthorityId::set_all_keys(Vec::<u64>::new());
+        Games::offchain_worker(CID_CHECK_INTERVAL);
+        UintAuthorityId::set_all_keys(vec![REPORTER]);
+        Games::offchain_worker(CID_CHECK_INTERVAL + 1);
+
+        expect_cid_request(&mut offchain_state.write(), &cid);
+        Games::offchain_worker(CID_CHECK_INTERVAL);
+        assert!(pool_state.read().transactions.is_empty());
+    })
+}
+
+#[test]
+fn test_offchain_worker_resumes_after_last_checked_game() {
+    let (offchain, offchain_state) = TestOffchainExt::new();
+    let (pool, _) = TestTransactionPoolExt::new();
+    new_offchain_test_ext(offchain, pool).execute_with(|| {
+        add_game(1, DistributionKind::Free, 0);
+        let cid: Cid = bounded_vec(GAME_CID);
+        add_game(2, DistributionKind::Free, 0);
+        add_game(3, DistributionKind::Free, 0);
+        sp_io::offchain::local_storage_set(
+            StorageKind::PERSISTENT,
+            IPFS_GATEWAY_KEY,
+            b"http://127.0.0.1:8080",
+        );
+
+        // The first check fetches the first two games, the second one the last game, and the
+        // third one starts over from the first game
+        for _ in 0..5 {
+            expect_cid_request(&mut offchain_state.write(), &cid);
+        }
+        Games::offchain_worker(CID_CHECK_INTERVAL);
+        assert_eq!(offchain_state.read().requests.len(), 2);
+        Games::offchain_worker(2 * CID_CHECK_INTERVAL);
+        assert_eq!(offchain_state.read().requests.len(), 3);
+        Games::offchain_worker(3 * CID_CHECK_INTERVAL);
+        assert_eq!(offchain_state.read().requests.len(), 5);
+    })
+}
+
+#[test]
+fn test_game_build_set() {
+    new_test_ext().execute_with(|| {
+        add_game(1, DistributionKind::Free, 0);
+        let cid: Cid = bounded_vec(GAME_CID);
+        let build = BuildManifest { cid, size: 1024, digest: [1; 32] };
+
+        assert_ok!(Games::game_build_set(RuntimeOrigin::signed(PUBLISHER), 1, Some(build.clone())));
+        assert_eq!(GameBuilds::<Test>::get(PUBLISHER, 1), Some(build.clone()));
+        assert_eq!(Games::game_build(&PUBLISHER, 1), Some(build.clone()));
+        System::assert_last_event(
+            Event::GameBuildSet { publisher: PUBLISHER, game_id: 1, build: Some(build) }.into(),
+        );
+
+        assert_ok!(Games::game_build_set(RuntimeOrigin::signed(PUBLISHER), 1, None));
+        assert_eq!(GameBuilds::<Test>::get(PUBLISHER, 1), None);
+        System::assert_last_event(
+            Event::GameBuildSet { publisher: PUBLISHER, game_id: 1, build: None }.into(),
+        );
+    })
+}
+
+#[test]
+fn test_game_build_set_invalid() {
+    new_test_ext().execute_with(|| {
+        add_game(1, DistributionKind::Free, 0);
+        let cid: Cid = bounded_vec(GAME_CID);
+        add_game(2, DistributionKind::Individual, 1000);
+        let build = BuildManifest { cid: cid.clone(), size: 1024, digest: [1; 32] };
+
+        assert_noop!(
+            Games::game_build_set(RuntimeOrigin::signed(PUBLISHER), 3, Some(build.clone())),
+            Error::<Test>::GameNotFound
+        );
+        assert_noop!(
+            Games::game_build_set(RuntimeOrigin::signed(PUBLISHER), 2, Some(build.clone())),
+            Error::<Test>::BuildInvalid
+        );
+
+        let other_cid = BuildManifest {
+            cid: bounded_vec(b"QmRJzSVrU5kMkXzDCrePyx3TX7gGu8cXsogX5xLyfMuNPG"),
+            ..build.clone()
+        };
+        let empty = BuildManifest { size: 0, ..build };
+        for manifest in [other_cid, empty] {
+            assert_noop!(
+                Games::game_build_set(RuntimeOrigin::signed(PUBLISHER), 1, Some(manifest)),
+                Error::<Test>::BuildInvalid
+            );
+        }
+    })
+}
+
+#[test]
+fn test_migrate_v0_to_v1() {
+    new_test_ext().execute_with(|| {
+        // The unversioned storage only holds the tag names
+        let _ = Tags::<Test>::clear(u32::MAX, None);
+        let _ = TagIds::<Test>::clear(u32::MAX, None);
+        let _ = TagTaxonomy::<Test>::clear(u32::MAX, None);
+        let _ = TagsByCategory::<Test>::clear(u32::MAX, None);
+        let _ = TagChildren::<Test>::clear(u32::MAX, None);
+        NextTagId::<Test>::kill();
+        StorageVersion::new(0).put::<Games>();
+
+        let action: Tag = bounded_vec(b"Action");
+        let retired: Tag = bounded_vec(b"Retired");
+        Tags::<Test>::insert(0, action.clone());
+        Tags::<Test>::insert(500, action);
+        Tags::<Test>::insert(501, retired.clone());
+
+        let name: Name = bounded_vec(b"Game");
+        let game = v0::GameDetails {
+            name: name.clone(),
+            tags: bounded_vec(&[0, 500, 501]),
+            distribution: v0::Distribution::Individual { price: 1000 },
+        };
+        unhashed::put(&PublishedGames::<Test>::hashed_key_for(PUBLISHER, 1), &game);
+        let order = v0::OrderDetails { deposit: 1000 };
+        unhashed::put(&BuyerOrders::<Test>::hashed_key_for(FUNDED_BUYER, (PUBLISHER, 1)), &order);
+
+        MigrateV0ToV1::<Test>::on_runtime_upgrade();
+        assert_eq!(Games::on_chain_storage_version(), StorageVersion::new(1));
+
+        // The duplicated tag is merged, and the unknown one kept as deprecated under a new id
+        let retired_id = TAGS.iter().map(|(tag_id, _, _)| tag_id + 1).max().unwrap();
+        assert_eq!(Tags::<Test>::count(), TAGS.len() as u32 + 1);
+        assert_eq!(TagIds::<Test>::get(&retired), Some(retired_id));
+        assert!(DeprecatedTags::<Test>::contains_key(retired_id));
+        assert_eq!(NextTagId::<Test>::get(), retired_id + 1);
+        assert_eq!(
+            TagTaxonomy::<Test>::get(0),
+            Some(TagInfo { category: TagCategory::TopLevelGenre, parent: None })
+        );
+        assert_eq!(
+            TagTaxonomy::<Test>::get(110),
+            Some(TagInfo { category: TagCategory::SubGenre, parent: Some(55) })
+        );
+
+        assert_eq!(
+            PublishedGames::<Test>::get(PUBLISHER, 1),
+            Some(GameDetails {
+                name,
+                tags: bounded_vec(&[0, retired_id]),
+                distribution: Distribution::Individual { price: 1000, asset: PaymentAsset::Native },
+                regional_prices: Default::default(),
+                rating: Default::default(),
+            })
+        );
+        assert!(GamesByTag::<Test>::contains_key(0, (PUBLISHER, 1)));
+        assert!(GamesByTag::<Test>::contains_key(retired_id, (PUBLISHER, 1)));
+        assert_eq!(
+            BuyerOrders::<Test>::get(FUNDED_BUYER, (PUBLISHER, 1)),
+            Some(OrderDetails {
+                deposit: 1000,
+                asset: PaymentAsset::Native,
+                region: None,
+                receipt_deposit: 0,
+            })
+        );
+
+        // The migration only runs once
+        MigrateV0ToV1::<Test>::on_runtime_upgrade();
+        assert_eq!(Tags::<Test>::count(), TAGS.len() as u32 + 1);
+    })
+}
@@ -15,9 +15,39 @@ pub trait WeightInfo {
     fn order_place() -> Weight;
     fn order_cancel() -> Weight;
     fn order_fulfill() -> Weight;
+    fn preorder_place() -> Weight;
+    fn preorder_release() -> Weight;
+    fn preorder_cancel() -> Weight;
+    fn preorder_settle() -> Weight;
+    fn preorder_claim() -> Weight;
+    fn preorder_schedule(n: u32) -> Weight;
+    fn plan_add(n: u32) -> Weight;
+    fn subscription_buy() -> Weight;
+    fn tag_add() -> Weight;
+    fn tag_rename() -> Weight;
+    fn tag_deprecate() -> Weight;
+    fn tag_classify() -> Weight;
+    fn tag_translate() -> Weight;
+    fn tag_propose() -> Weight;
+    fn tag_proposal_approve() -> Weight;
+    fn tag_proposal_reject() -> Weight;
+    fn tag_proposal_expire(n: u32) -> Weight;
+    fn tag_vote() -> Weight;
+    fn tag_vote_withdraw() -> Weight;
+    fn wishlist_add() -> Weight;
+    fn wishlist_remove() -> Weight;
+    fn game_price_set() -> Weight;
+    fn game_discount_set() -> Weight;
+    fn receipt_remove() -> Weight;
+    fn game_availability_report() -> Weight;
+    fn game_build_set() -> Weight;
+    fn cid_reporter_set() -> Weight;
 
     fn game_buy() -> Weight {
-        Self::buy_free().max(Self::buy_instant()).max(Self::order_place())
+        Self::buy_free()
+            .max(Self::buy_instant())
+            .max(Self::order_place())
+            .max(Self::preorder_place())
     }
 }
 
@@ -46,4 +76,112 @@ impl WeightInfo for () {
     fn order_fulfill() -> Weight {
         Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
     }
+
+    fn preorder_place() -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn preorder_release() -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn preorder_cancel() -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn preorder_settle() -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn preorder_claim() -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn preorder_schedule(_n: u32) -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn plan_add(_n: u32) -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn subscription_buy() -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn tag_add() -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn tag_rename() -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn tag_deprecate() -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn tag_classify() -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn tag_translate() -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn tag_propose() -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn tag_proposal_approve() -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn tag_proposal_reject() -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn tag_proposal_expire(_n: u32) -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn tag_vote() -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn tag_vote_withdraw() -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn wishlist_add() -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn wishlist_remove() -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn game_price_set() -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn game_discount_set() -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn receipt_remove() -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn game_availability_report() -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn game_build_set() -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn cid_reporter_set() -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
 }
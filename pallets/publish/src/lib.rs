@@ -148,6 +148,20 @@ pub mod pallet {
             Ok(())
         }
     }
+
+    /// View functions, queried by metadata-aware clients.
+    #[pallet::view_functions]
+    impl<T: Config> Pallet<T> {
+        /// Returns the details of the publisher, or `None` if the publisher is not registered.
+        pub fn publisher_info(id: PublisherId<T>) -> Option<PublisherDetails> {
+            Publishers::<T>::get(id)
+        }
+
+        /// Returns the deposit held when registering a publisher.
+        pub fn current_deposit() -> CurrencyOf<T> {
+            PublisherDeposit::<T>::get()
+        }
+    }
 }
 
 impl<T: Config> PublisherManager for Pallet<T> {
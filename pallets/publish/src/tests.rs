@@ -90,3 +90,22 @@ fn test_publisher_manager_is_valid_publisher() {
         assert!(!Publish::is_valid_publisher(&2));
     })
 }
+
+#[test]
+fn test_publisher_info() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(Publish::publisher_info(FUNDED_PUBLISHER), None);
+        assert_eq!(Publish::current_deposit(), PUBLISHER_DEPOSIT);
+
+        let details = PublisherDetails {
+            name: bounded_vec(b"Example Publisher"),
+            url: bounded_vec(b"https://example.com"),
+        };
+        assert_ok!(Publish::publisher_register(
+            RuntimeOrigin::signed(FUNDED_PUBLISHER),
+            details.clone()
+        ));
+
+        assert_eq!(Publish::publisher_info(FUNDED_PUBLISHER), Some(details));
+    });
+}
@@ -0,0 +1,158 @@
+//! Benchmarking setup for liganite-reviews
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as Reviews;
+use frame_benchmarking::v2::*;
+use frame_support::sp_runtime::traits::Bounded;
+use frame_system::RawOrigin;
+use liganite_primitives::{testing::bounded_vec, MAX_REVIEW_SCORE};
+
+const SEED: u32 = 0;
+
+fn get_account<T: Config>(index: u32) -> T::AccountId {
+    account("account", index, SEED)
+}
+
+fn prefund_account<T: Config>(account: &T::AccountId) {
+    let initial_balance = CurrencyOf::<T>::max_value()
+        .checked_div(&2u32.into())
+        .expect("never fails; qed");
+    T::Currency::set_balance(account, CurrencyOf::<T>::from(initial_balance));
+}
+
+fn insert_review<T: Config>(publisher: &T::AccountId, game_id: GameId, reviewer: &T::AccountId) {
+    prefund_account::<T>(reviewer);
+    let deposit = T::ReviewDeposit::get();
+    T::Currency::hold(&HoldReason::ReviewDeposit.into(), reviewer, deposit)
+        .expect("the reviewer is funded; qed");
+    let game = (publisher.clone(), game_id);
+    let details = review_details(MAX_REVIEW_SCORE);
+    ReviewSummaries::<T>::mutate(&game, |summary| summary.add(details.score));
+    GameReviews::<T>::insert(
+        &game,
+        reviewer,
+        Review { details, deposit, reports: 0, hidden: false },
+    );
+}
+
+fn review_details(score: ReviewScore) -> ReviewDetails {
+    ReviewDetails {
+        score,
+        cid: bounded_vec(b"bafkrgqe3nmefcemf5kx23jw4mpkux4kswm4umtvwmc2j6cf3ftvkv7flwpfrwe7nvlhyzvvn7wtfhec5nkkwxv3hvzjivkw6yv3f3og5edhcldy"),
+    }
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn review_add() {
+        let reviewer: T::AccountId = whitelisted_caller();
+        let publisher = get_account::<T>(0);
+        let game_id = GameId::default();
+        T::OwnershipManager::insert_owner(&reviewer, &publisher, game_id);
+        prefund_account::<T>(&reviewer);
+        let details = review_details(MAX_REVIEW_SCORE);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(reviewer.clone()), publisher.clone(), game_id, details.clone());
+
+        let review = GameReviews::<T>::get((publisher, game_id), reviewer);
+        assert_eq!(review.map(|review| review.details), Some(details));
+    }
+
+    #[benchmark]
+    fn review_edit() {
+        let reviewer: T::AccountId = whitelisted_caller();
+        let publisher = get_account::<T>(0);
+        let game_id = GameId::default();
+        let game = (publisher.clone(), game_id);
+        insert_review::<T>(&publisher, game_id, &reviewer);
+        let details = review_details(1);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(reviewer.clone()), publisher, game_id, details.clone());
+
+        let review = GameReviews::<T>::get(game, reviewer);
+        assert_eq!(review.map(|review| review.details), Some(details));
+    }
+
+    #[benchmark]
+    fn review_delete() {
+        let reviewer: T::AccountId = whitelisted_caller();
+        let publisher = get_account::<T>(0);
+        let game_id = GameId::default();
+        let game = (publisher.clone(), game_id);
+        insert_review::<T>(&publisher, game_id, &reviewer);
+        insert_review::<T>(&publisher, game_id, &get_account::<T>(1));
+        ReviewReplies::<T>::insert(&game, &reviewer, review_details(1).cid);
+        let reports = T::MaxReportsPerReview::get();
+        for i in 0..reports {
+            ReviewReports::<T>::insert(
+                (game.clone(), reviewer.clone()),
+                get_account::<T>(2 + i),
+                (),
+            );
+        }
+        GameReviews::<T>::mutate(&game, &reviewer, |review| {
+            review.as_mut().expect("the review was inserted; qed").reports = reports
+        });
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(reviewer.clone()), publisher, game_id);
+
+        assert_eq!(GameReviews::<T>::get(&game, &reviewer), None);
+        assert_eq!(ReviewReports::<T>::iter_prefix((game, reviewer)).count(), 0);
+    }
+
+    #[benchmark]
+    fn review_reply() {
+        let publisher: T::AccountId = whitelisted_caller();
+        let reviewer = get_account::<T>(0);
+        let game_id = GameId::default();
+        insert_review::<T>(&publisher, game_id, &reviewer);
+        let cid = review_details(1).cid;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(publisher.clone()), game_id, reviewer.clone(), Some(cid.clone()));
+
+        assert_eq!(ReviewReplies::<T>::get((publisher, game_id), reviewer), Some(cid));
+    }
+
+    #[benchmark]
+    fn review_report() {
+        let reporter: T::AccountId = whitelisted_caller();
+        let publisher = get_account::<T>(0);
+        let reviewer = get_account::<T>(1);
+        let game_id = GameId::default();
+        T::OwnershipManager::insert_owner(&reporter, &publisher, game_id);
+        insert_review::<T>(&publisher, game_id, &reviewer);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(reporter.clone()), publisher.clone(), game_id, reviewer.clone());
+
+        assert!(ReviewReports::<T>::contains_key(((publisher, game_id), reviewer), reporter));
+    }
+
+    #[benchmark]
+    fn review_hide() -> Result<(), BenchmarkError> {
+        let origin =
+            T::ModeratorOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let publisher = get_account::<T>(0);
+        let reviewer = get_account::<T>(1);
+        let game_id = GameId::default();
+        insert_review::<T>(&publisher, game_id, &reviewer);
+
+        #[extrinsic_call]
+        _(origin, publisher.clone(), game_id, reviewer.clone(), true);
+
+        let review = GameReviews::<T>::get((publisher, game_id), reviewer);
+        assert!(review.is_some_and(|review| review.hidden));
+        Ok(())
+    }
+
+    impl_benchmark_test_suite!(Reviews, mock::new_test_ext(), mock::Test);
+}
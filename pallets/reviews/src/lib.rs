@@ -0,0 +1,483 @@
+// We make sure this pallet uses `no_std` for compiling to Wasm.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{
+    pallet_prelude::*,
+    sp_runtime::traits::Saturating,
+    traits::{
+        fungible::{hold::Mutate as FunHoldMutate, Inspect as FunInspect, Mutate as FunMutate},
+        tokens::{Fortitude::Polite, Precision::BestEffort},
+    },
+};
+use frame_system::pallet_prelude::*;
+use liganite_primitives::{
+    ownership::OwnershipManager,
+    types::{
+        AccountIdOf, BuyerId, Cid, GameId, GlobalGameId, PublisherId, Review, ReviewDetails,
+        ReviewScore, ReviewSummary,
+    },
+    validate,
+};
+// Re-export pallet items so that they can be accessed from the crate namespace.
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::*;
+
+type CurrencyOf<T> = <<T as Config>::Currency as FunInspect<AccountIdOf<T>>>::Balance;
+type ReviewOf<T> = Review<CurrencyOf<T>>;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    /// A reason for the pallet placing a hold on funds.
+    #[pallet::composite_enum]
+    pub enum HoldReason {
+        /// The deposit of a review.
+        ReviewDeposit,
+    }
+
+    /// The pallet's configuration trait.
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// A type representing the weights required by the dispatchables of this pallet.
+        type WeightInfo: WeightInfo;
+
+        /// The overarching runtime event type.
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Used to check the game ownership of the reviewers.
+        type OwnershipManager: OwnershipManager<AccountId = AccountIdOf<Self>>;
+
+        /// Overarching hold reason.
+        type RuntimeHoldReason: From<HoldReason>;
+
+        /// Used to operate on currencies.
+        type Currency: FunMutate<Self::AccountId>
+            + FunHoldMutate<Self::AccountId, Reason = Self::RuntimeHoldReason>;
+
+        /// The origin allowed to hide reviews and slash their deposit.
+        type ModeratorOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// The deposit held from a reviewer. It is returned once the review is deleted, unless a
+        /// moderator slashed it.
+        #[pallet::constant]
+        type ReviewDeposit: Get<CurrencyOf<Self>>;
+
+        /// The maximum number of reports a review can receive.
+        #[pallet::constant]
+        type MaxReportsPerReview: Get<u32>;
+    }
+
+    /// Storage for the game reviews. Is a map of GlobalGameId -> BuyerId -> Review.
+    #[pallet::storage]
+    pub type GameReviews<T> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        GlobalGameId<T>,
+        Twox64Concat,
+        BuyerId<T>,
+        ReviewOf<T>,
+        OptionQuery,
+    >;
+
+    /// Storage for the publisher replies to the reviews. Is a map of GlobalGameId -> BuyerId ->
+    /// Cid.
+    #[pallet::storage]
+    pub type ReviewReplies<T> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        GlobalGameId<T>,
+        Twox64Concat,
+        BuyerId<T>,
+        Cid,
+        OptionQuery,
+    >;
+
+    /// Storage for the accounts which reported a review. Is a map of (GlobalGameId, BuyerId) ->
+    /// AccountId -> ().
+    #[pallet::storage]
+    pub type ReviewReports<T> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        (GlobalGameId<T>, BuyerId<T>),
+        Twox64Concat,
+        AccountIdOf<T>,
+        (),
+        OptionQuery,
+    >;
+
+    /// Storage for the reviewers whose hidden review of a game was deleted, who cannot review the
+    /// game again. Is a map of GlobalGameId -> BuyerId -> ().
+    #[pallet::storage]
+    pub type HiddenReviewers<T> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        GlobalGameId<T>,
+        Twox64Concat,
+        BuyerId<T>,
+        (),
+        OptionQuery,
+    >;
+
+    /// Storage for the aggregated reviews of the games. Is a map of GlobalGameId -> ReviewSummary.
+    #[pallet::storage]
+    pub type ReviewSummaries<T> =
+        StorageMap<_, Blake2_128Concat, GlobalGameId<T>, ReviewSummary, ValueQuery>;
+
+    /// Events that functions in this pallet can emit.
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// A review has been added.
+        ReviewAdded {
+            /// The reviewer.
+            reviewer: BuyerId<T>,
+            /// The publisher of the game.
+            publisher: PublisherId<T>,
+            /// The game id.
+            game_id: GameId,
+            /// The score given to the game.
+            score: ReviewScore,
+        },
+        /// A review has been edited.
+        ReviewEdited {
+            /// The reviewer.
+            reviewer: BuyerId<T>,
+            /// The publisher of the game.
+            publisher: PublisherId<T>,
+            /// The game id.
+            game_id: GameId,
+            /// The new score given to the game.
+            score: ReviewScore,
+        },
+        /// A review has been deleted.
+        ReviewDeleted {
+            /// The reviewer.
+            reviewer: BuyerId<T>,
+            /// The publisher of the game.
+            publisher: PublisherId<T>,
+            /// The game id.
+            game_id: GameId,
+        },
+        /// The publisher has replied to a review, or removed its reply.
+        ReviewReplied {
+            /// The publisher of the game.
+            publisher: PublisherId<T>,
+            /// The game id.
+            game_id: GameId,
+            /// The reviewer.
+            reviewer: BuyerId<T>,
+            /// The CID of the reply text, or `None` if the reply was removed.
+            cid: Option<Cid>,
+        },
+        /// A review has been reported.
+        ReviewReported {
+            /// The account reporting the review.
+            reporter: AccountIdOf<T>,
+            /// The publisher of the game.
+            publisher: PublisherId<T>,
+            /// The game id.
+            game_id: GameId,
+            /// The reviewer.
+            reviewer: BuyerId<T>,
+            /// The number of reports of the review.
+            reports: u32,
+        },
+        /// A review has been hidden by a moderator.
+        ReviewHidden {
+            /// The publisher of the game.
+            publisher: PublisherId<T>,
+            /// The game id.
+            game_id: GameId,
+            /// The reviewer.
+            reviewer: BuyerId<T>,
+            /// The amount slashed from the reviewer deposit.
+            slashed: CurrencyOf<T>,
+        },
+    }
+
+    /// Errors.
+    #[pallet::error]
+    pub enum Error<T> {
+        /// The game is not owned by the account.
+        GameNotOwned,
+        /// The review already exists.
+        ReviewAlreadyExists,
+        /// The review is not found.
+        ReviewNotFound,
+        /// The review details are invalid.
+        ReviewDetailsInvalid,
+        /// The review has been hidden by a moderator.
+        ReviewHidden,
+        /// The reply CID is invalid.
+        ReplyInvalid,
+        /// The review was already reported by the account.
+        ReviewAlreadyReported,
+        /// The review cannot be reported by its own reviewer.
+        ReviewSelfReport,
+        /// The review has reached the maximum number of reports.
+        ReviewReportLimitReached,
+    }
+
+    /// Dispatchable functions ([`Call`]s).
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Adds a review of an owned game.
+        ///
+        /// This function stores the review in the `GameReviews` storage and counts its score in
+        /// the game's `ReviewSummaries`. Each owner can post a single review per game, and the
+        /// `ReviewDeposit` is held until the review is deleted. An owner whose review was hidden
+        /// cannot review the game again. A `ReviewAdded` event is emitted once the review is
+        /// successfully added.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::review_add())]
+        pub fn review_add(
+            origin: OriginFor<T>,
+            publisher: PublisherId<T>,
+            game_id: GameId,
+            details: ReviewDetails,
+        ) -> DispatchResult {
+            let reviewer = ensure_signed(origin)?;
+            ensure!(
+                T::OwnershipManager::is_owner(&reviewer, &publisher, game_id),
+                Error::<T>::GameNotOwned
+            );
+            let game = (publisher.clone(), game_id);
+            ensure!(
+                !GameReviews::<T>::contains_key(&game, &reviewer),
+                Error::<T>::ReviewAlreadyExists
+            );
+            ensure!(
+                !HiddenReviewers::<T>::contains_key(&game, &reviewer),
+                Error::<T>::ReviewHidden
+            );
+            ensure!(details.is_valid(), Error::<T>::ReviewDetailsInvalid);
+
+            let deposit = T::ReviewDeposit::get();
+            T::Currency::hold(&HoldReason::ReviewDeposit.into(), &reviewer, deposit)?;
+
+            let score = details.score;
+            ReviewSummaries::<T>::mutate(&game, |summary| summary.add(score));
+            GameReviews::<T>::insert(
+                &game,
+                &reviewer,
+                Review { details, deposit, reports: 0, hidden: false },
+            );
+
+            Self::deposit_event(Event::ReviewAdded { reviewer, publisher, game_id, score });
+            Ok(())
+        }
+
+        /// Edits a review.
+        ///
+        /// This function replaces the score and text of an existing review and updates the
+        /// game's `ReviewSummaries` accordingly. Hidden reviews cannot be edited. A `ReviewEdited`
+        /// event is emitted once the review is successfully edited.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::review_edit())]
+        pub fn review_edit(
+            origin: OriginFor<T>,
+            publisher: PublisherId<T>,
+            game_id: GameId,
+            details: ReviewDetails,
+        ) -> DispatchResult {
+            let reviewer = ensure_signed(origin)?;
+            let game = (publisher.clone(), game_id);
+            let mut review =
+                GameReviews::<T>::get(&game, &reviewer).ok_or(Error::<T>::ReviewNotFound)?;
+            ensure!(!review.hidden, Error::<T>::ReviewHidden);
+            ensure!(details.is_valid(), Error::<T>::ReviewDetailsInvalid);
+
+            let score = details.score;
+            ReviewSummaries::<T>::mutate(&game, |summary| {
+                summary.remove(review.details.score);
+                summary.add(score);
+            });
+            review.details = details;
+            GameReviews::<T>::insert(&game, &reviewer, review);
+
+            Self::deposit_event(Event::ReviewEdited { reviewer, publisher, game_id, score });
+            Ok(())
+        }
+
+        /// Deletes a review.
+        ///
+        /// This function removes the review, its reply and its reports, and discounts its score
+        /// from the game's `ReviewSummaries` unless it was hidden, in which case the reviewer is
+        /// kept in `HiddenReviewers`. The remaining deposit is returned to the reviewer. A
+        /// `ReviewDeleted` event is emitted once the review is successfully deleted.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::review_delete())]
+        pub fn review_delete(
+            origin: OriginFor<T>,
+            publisher: PublisherId<T>,
+            game_id: GameId,
+        ) -> DispatchResult {
+            let reviewer = ensure_signed(origin)?;
+            let game = (publisher.clone(), game_id);
+            let review =
+                GameReviews::<T>::take(&game, &reviewer).ok_or(Error::<T>::ReviewNotFound)?;
+
+            T::Currency::release(
+                &HoldReason::ReviewDeposit.into(),
+                &reviewer,
+                review.deposit,
+                BestEffort,
+            )?;
+
+            if review.hidden {
+                HiddenReviewers::<T>::insert(&game, &reviewer, ());
+            } else {
+                Self::summary_remove(&game, review.details.score);
+            }
+            ReviewReplies::<T>::remove(&game, &reviewer);
+            let _ =
+                ReviewReports::<T>::clear_prefix((game, reviewer.clone()), review.reports, None);
+
+            Self::deposit_event(Event::ReviewDeleted { reviewer, publisher, game_id });
+            Ok(())
+        }
+
+        /// Sets or removes the publisher reply to a review.
+        ///
+        /// This function is triggered by the publisher of the reviewed game. Each review can have
+        /// a single reply, which is replaced when replying again. A `ReviewReplied` event is
+        /// emitted once the reply is successfully set or removed.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::review_reply())]
+        pub fn review_reply(
+            origin: OriginFor<T>,
+            game_id: GameId,
+            reviewer: BuyerId<T>,
+            cid: Option<Cid>,
+        ) -> DispatchResult {
+            let publisher = ensure_signed(origin)?;
+            let game = (publisher.clone(), game_id);
+            ensure!(GameReviews::<T>::contains_key(&game, &reviewer), Error::<T>::ReviewNotFound);
+            ensure!(cid.as_ref().is_none_or(|cid| validate::is_cid(cid)), Error::<T>::ReplyInvalid);
+
+            ReviewReplies::<T>::set(&game, &reviewer, cid.clone());
+
+            Self::deposit_event(Event::ReviewReplied { publisher, game_id, reviewer, cid });
+            Ok(())
+        }
+
+        /// Reports a review.
+        ///
+        /// This function is triggered by an owner of the reviewed game, other than the reviewer.
+        /// Each owner can report a review once, and the number of reports is kept in the review so
+        /// moderators can follow up. A `ReviewReported` event is emitted once the review is
+        /// successfully reported.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::review_report())]
+        pub fn review_report(
+            origin: OriginFor<T>,
+            publisher: PublisherId<T>,
+            game_id: GameId,
+            reviewer: BuyerId<T>,
+        ) -> DispatchResult {
+            let reporter = ensure_signed(origin)?;
+            ensure!(reporter != reviewer, Error::<T>::ReviewSelfReport);
+            ensure!(
+                T::OwnershipManager::is_owner(&reporter, &publisher, game_id),
+                Error::<T>::GameNotOwned
+            );
+            let game = (publisher.clone(), game_id);
+            let mut review =
+                GameReviews::<T>::get(&game, &reviewer).ok_or(Error::<T>::ReviewNotFound)?;
+            ensure!(!review.hidden, Error::<T>::ReviewHidden);
+            let key = (game.clone(), reviewer.clone());
+            ensure!(
+                !ReviewReports::<T>::contains_key(&key, &reporter),
+                Error::<T>::ReviewAlreadyReported
+            );
+            ensure!(
+                review.reports < T::MaxReportsPerReview::get(),
+                Error::<T>::ReviewReportLimitReached
+            );
+
+            review.reports.saturating_inc();
+            let reports = review.reports;
+            ReviewReports::<T>::insert(&key, &reporter, ());
+            GameReviews::<T>::insert(&game, &reviewer, review);
+
+            Self::deposit_event(Event::ReviewReported {
+                reporter,
+                publisher,
+                game_id,
+                reviewer,
+                reports,
+            });
+            Ok(())
+        }
+
+        /// Hides a review.
+        ///
+        /// This function is triggered by the moderator origin. The review is kept in storage but
+        /// is no longer counted in the game's `ReviewSummaries`, and it cannot be edited or
+        /// reported anymore. If `slash` is set, the deposit of the reviewer is slashed. A
+        /// `ReviewHidden` event is emitted once the review is successfully hidden.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::review_hide())]
+        pub fn review_hide(
+            origin: OriginFor<T>,
+            publisher: PublisherId<T>,
+            game_id: GameId,
+            reviewer: BuyerId<T>,
+            slash: bool,
+        ) -> DispatchResult {
+            T::ModeratorOrigin::ensure_origin(origin)?;
+            let game = (publisher.clone(), game_id);
+            let mut review =
+                GameReviews::<T>::get(&game, &reviewer).ok_or(Error::<T>::ReviewNotFound)?;
+            ensure!(!review.hidden, Error::<T>::ReviewHidden);
+
+            let mut slashed = CurrencyOf::<T>::default();
+            if slash {
+                slashed = T::Currency::burn_held(
+                    &HoldReason::ReviewDeposit.into(),
+                    &reviewer,
+                    review.deposit,
+                    BestEffort,
+                    Polite,
+                )?;
+                review.deposit = review.deposit.saturating_sub(slashed);
+            }
+
+            Self::summary_remove(&game, review.details.score);
+            review.hidden = true;
+            GameReviews::<T>::insert(&game, &reviewer, review);
+
+            Self::deposit_event(Event::ReviewHidden { publisher, game_id, reviewer, slashed });
+            Ok(())
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Discounts a review from the summary of the game, removing the summary once it is empty.
+    fn summary_remove(game: &GlobalGameId<T>, score: ReviewScore) {
+        ReviewSummaries::<T>::mutate_exists(game, |maybe_summary| {
+            if let Some(summary) = maybe_summary {
+                summary.remove(score);
+                if summary.count == 0 {
+                    *maybe_summary = None;
+                }
+            }
+        });
+    }
+}
@@ -0,0 +1,210 @@
+use crate as liganite_reviews;
+use frame_support::{
+    derive_impl, parameter_types,
+    traits::{AsEnsureOriginWithArg, ConstU32, ConstU64, VariantCountOf},
+    PalletId,
+};
+use frame_system::{EnsureRoot, EnsureSigned};
+use liganite_primitives::{
+    types::{BuyerId, GameId, PublisherId},
+    validate::CidPolicy,
+};
+use sp_runtime::{
+    testing::{TestSignature, UintAuthorityId},
+    BuildStorage,
+};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+pub type Balance = u64;
+
+#[frame_support::runtime]
+mod runtime {
+    // The main runtime
+    #[runtime::runtime]
+    // Runtime Types to be generated
+    #[runtime::derive(
+        RuntimeCall,
+        RuntimeEvent,
+        RuntimeError,
+        RuntimeOrigin,
+        RuntimeFreezeReason,
+        RuntimeHoldReason,
+        RuntimeSlashReason,
+        RuntimeLockId,
+        RuntimeTask,
+        RuntimeViewFunction
+    )]
+    pub struct Test;
+
+    #[runtime::pallet_index(0)]
+    pub type System = frame_system::Pallet<Test>;
+
+    #[runtime::pallet_index(1)]
+    pub type Balances = pallet_balances::Pallet<Test>;
+
+    #[runtime::pallet_index(2)]
+    pub type Assets = pallet_assets::Pallet<Test>;
+
+    #[runtime::pallet_index(3)]
+    pub type Publish = liganite_publish::Pallet<Test>;
+
+    #[runtime::pallet_index(4)]
+    pub type Games = liganite_games::Pallet<Test>;
+
+    #[runtime::pallet_index(5)]
+    pub type Reviews = liganite_reviews::Pallet<Test>;
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+    type AccountData = pallet_balances::AccountData<u64>;
+}
+
+impl pallet_balances::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type RuntimeFreezeReason = RuntimeFreezeReason;
+    type WeightInfo = ();
+    type Balance = Balance;
+    type DustRemoval = ();
+    type ExistentialDeposit = ConstU64<1>;
+    type AccountStore = System;
+    type ReserveIdentifier = [u8; 8];
+    type FreezeIdentifier = RuntimeFreezeReason;
+    type MaxLocks = ();
+    type MaxReserves = ();
+    type MaxFreezes = VariantCountOf<RuntimeFreezeReason>;
+    type DoneSlashHandler = ();
+}
+
+#[derive_impl(pallet_assets::config_preludes::TestDefaultConfig)]
+impl pallet_assets::Config for Test {
+    type Balance = Balance;
+    type Currency = Balances;
+    type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<Self::AccountId>>;
+    type ForceOrigin = EnsureRoot<Self::AccountId>;
+}
+
+impl liganite_publish::Config for Test {
+    type WeightInfo = ();
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type Currency = Balances;
+}
+
+impl liganite_games::Config for Test {
+    type WeightInfo = ();
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type Currency = Balances;
+    type AssetId = u32;
+    type Assets = Assets;
+    type PalletId = GamesPalletId;
+    type PublisherManager = Publish;
+    type PreOrderDeadline = ConstU64<100>;
+    type MaxPreOrdersScheduledPerBlock = ConstU32<2>;
+    type TagOrigin = EnsureRoot<Self::AccountId>;
+    type TagCuratorOrigin = EnsureRoot<Self::AccountId>;
+    type TagProposalDeposit = ConstU64<10_000>;
+    type TagProposalPeriod = ConstU64<50>;
+    type MaxTagProposalsExpiringPerBlock = ConstU32<2>;
+    type MaxTagVotesPerGame = ConstU32<2>;
+    type MaxWishlistSize = ConstU32<2>;
+    type ReceiptDeposit = ConstU64<0>;
+    type CidPolicy = GamesCidPolicy;
+    type CidCheckInterval = ConstU64<0>;
+    type MaxCidChecksPerRun = ConstU32<0>;
+    type UnsignedPriority = ConstU64<0>;
+    type AuthorityId = TestAuthId;
+    type CidReporterOrigin = EnsureRoot<Self::AccountId>;
+}
+
+pub struct TestAuthId;
+
+impl frame_system::offchain::AppCrypto<UintAuthorityId, TestSignature> for TestAuthId {
+    type RuntimeAppPublic = UintAuthorityId;
+    type GenericPublic = UintAuthorityId;
+    type GenericSignature = TestSignature;
+}
+
+impl frame_system::offchain::SigningTypes for Test {
+    type Public = UintAuthorityId;
+    type Signature = TestSignature;
+}
+
+pub type Extrinsic = sp_runtime::testing::TestXt<RuntimeCall, ()>;
+
+impl<LocalCall> frame_system::offchain::CreateTransactionBase<LocalCall> for Test
+where
+    RuntimeCall: From<LocalCall>,
+{
+    type Extrinsic = Extrinsic;
+    type RuntimeCall = RuntimeCall;
+}
+
+impl<LocalCall> frame_system::offchain::CreateInherent<LocalCall> for Test
+where
+    RuntimeCall: From<LocalCall>,
+{
+    fn create_inherent(call: RuntimeCall) -> Extrinsic {
+        Extrinsic::new_bare(call)
+    }
+}
+
+impl liganite_reviews::Config for Test {
+    type WeightInfo = ();
+    type RuntimeEvent = RuntimeEvent;
+    type OwnershipManager = Games;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type Currency = Balances;
+    type ModeratorOrigin = EnsureRoot<Self::AccountId>;
+    type ReviewDeposit = ConstU64<REVIEW_DEPOSIT>;
+    type MaxReportsPerReview = ConstU32<2>;
+}
+
+parameter_types! {
+    pub const GamesPalletId: PalletId = PalletId(*b"lg/games");
+    pub const GamesCidPolicy: CidPolicy<'static> = CidPolicy::PINNING;
+}
+
+pub const PUBLISHER: PublisherId<Test> = 1;
+pub const OWNER: BuyerId<Test> = 11;
+pub const OTHER_OWNER: BuyerId<Test> = 12;
+pub const NON_OWNER: BuyerId<Test> = 13;
+pub const THIRD_OWNER: BuyerId<Test> = 14;
+pub const GAME_ID: GameId = 1;
+pub const INITIAL_BALANCE: Balance = 1_000_000;
+pub const REVIEW_DEPOSIT: Balance = 1_000;
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![
+            (OWNER, INITIAL_BALANCE),
+            (OTHER_OWNER, INITIAL_BALANCE),
+            (THIRD_OWNER, INITIAL_BALANCE),
+        ],
+        ..Default::default()
+    }
+    .assimilate_storage(&mut storage)
+    .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(storage);
+    ext.execute_with(|| {
+        insert_owner(OWNER);
+        insert_owner(OTHER_OWNER);
+        insert_owner(THIRD_OWNER);
+    });
+
+    // Go past genesis block so events get deposited
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+/// Adds the reviewed game to the collection of the buyer.
+pub fn insert_owner(buyer: BuyerId<Test>) {
+    liganite_games::OwnedGames::<Test>::insert(buyer, (PUBLISHER, GAME_ID), ());
+}
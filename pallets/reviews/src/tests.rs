@@ -0,0 +1,507 @@
+use crate::{
+    mock::*, Error, Event, GameReviews, HiddenReviewers, HoldReason, ReviewReplies, ReviewReports,
+    ReviewSummaries,
+};
+use frame_support::{assert_noop, assert_ok, traits::fungible};
+use liganite_primitives::{
+    testing::bounded_vec,
+    types::{BuyerId, Cid, Review, ReviewDetails, ReviewScore, ReviewSummary},
+    MAX_REVIEW_SCORE,
+};
+use sp_runtime::DispatchError;
+
+fn review(score: ReviewScore) -> ReviewDetails {
+    ReviewDetails {
+        score,
+        cid: bounded_vec(b"bafkreihdwdcefgh4dqkjv67uzcmw7ojee6xedzdetojuzjevtenxquvyku"),
+    }
+}
+
+fn reply() -> Cid {
+    bounded_vec(b"bafkreigh2akiscaildcqabsyg3dfr6chu3fgpregiymsck7e7aqa4s52zy")
+}
+
+fn review_hold(who: &BuyerId<Test>) -> Balance {
+    <Balances as fungible::hold::Inspect<_>>::balance_on_hold(
+        &HoldReason::ReviewDeposit.into(),
+        who,
+    )
+}
+
+#[test]
+fn test_review_add() {
+    new_test_ext().execute_with(|| {
+        let details = review(7);
+        assert_ok!(Reviews::review_add(
+            RuntimeOrigin::signed(OWNER),
+            PUBLISHER,
+            GAME_ID,
+            details.clone()
+        ));
+
+        assert_eq!(
+            GameReviews::<Test>::get((PUBLISHER, GAME_ID), OWNER),
+            Some(Review { details, deposit: REVIEW_DEPOSIT, reports: 0, hidden: false })
+        );
+        assert_eq!(review_hold(&OWNER), REVIEW_DEPOSIT);
+        assert_eq!(
+            ReviewSummaries::<Test>::get((PUBLISHER, GAME_ID)),
+            ReviewSummary { count: 1, total_score: 7 }
+        );
+        System::assert_last_event(
+            Event::ReviewAdded {
+                reviewer: OWNER,
+                publisher: PUBLISHER,
+                game_id: GAME_ID,
+                score: 7,
+            }
+            .into(),
+        );
+
+        assert_ok!(Reviews::review_add(
+            RuntimeOrigin::signed(OTHER_OWNER),
+            PUBLISHER,
+            GAME_ID,
+            review(4)
+        ));
+        assert_eq!(
+            ReviewSummaries::<Test>::get((PUBLISHER, GAME_ID)),
+            ReviewSummary { count: 2, total_score: 11 }
+        );
+    })
+}
+
+#[test]
+fn test_review_add_game_not_owned() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Reviews::review_add(RuntimeOrigin::signed(NON_OWNER), PUBLISHER, GAME_ID, review(7)),
+            Error::<Test>::GameNotOwned
+        );
+        assert_noop!(
+            Reviews::review_add(RuntimeOrigin::signed(OWNER), PUBLISHER, GAME_ID + 1, review(7)),
+            Error::<Test>::GameNotOwned
+        );
+    })
+}
+
+#[test]
+fn test_review_add_insufficient_balance() {
+    new_test_ext().execute_with(|| {
+        insert_owner(NON_OWNER);
+
+        assert_noop!(
+            Reviews::review_add(RuntimeOrigin::signed(NON_OWNER), PUBLISHER, GAME_ID, review(7)),
+            DispatchError::Token(sp_runtime::TokenError::FundsUnavailable)
+        );
+    })
+}
+
+#[test]
+fn test_review_add_already_exists() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Reviews::review_add(
+            RuntimeOrigin::signed(OWNER),
+            PUBLISHER,
+            GAME_ID,
+            review(7)
+        ));
+
+        assert_noop!(
+            Reviews::review_add(RuntimeOrigin::signed(OWNER), PUBLISHER, GAME_ID, review(3)),
+            Error::<Test>::ReviewAlreadyExists
+        );
+    })
+}
+
+#[test]
+fn test_review_add_invalid_details() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Reviews::review_add(RuntimeOrigin::signed(OWNER), PUBLISHER, GAME_ID, review(0)),
+            Error::<Test>::ReviewDetailsInvalid
+        );
+        assert_noop!(
+            Reviews::review_add(
+                RuntimeOrigin::signed(OWNER),
+                PUBLISHER,
+                GAME_ID,
+                review(MAX_REVIEW_SCORE + 1)
+            ),
+            Error::<Test>::ReviewDetailsInvalid
+        );
+        assert_noop!(
+            Reviews::review_add(
+                RuntimeOrigin::signed(OWNER),
+                PUBLISHER,
+                GAME_ID,
+                ReviewDetails { score: 7, cid: bounded_vec(b"Qm123") }
+            ),
+            Error::<Test>::ReviewDetailsInvalid
+        );
+    })
+}
+
+#[test]
+fn test_review_edit() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Reviews::review_edit(RuntimeOrigin::signed(OWNER), PUBLISHER, GAME_ID, review(9)),
+            Error::<Test>::ReviewNotFound
+        );
+
+        assert_ok!(Reviews::review_add(
+            RuntimeOrigin::signed(OWNER),
+            PUBLISHER,
+            GAME_ID,
+            review(7)
+        ));
+        assert_ok!(Reviews::review_add(
+            RuntimeOrigin::signed(OTHER_OWNER),
+            PUBLISHER,
+            GAME_ID,
+            review(4)
+        ));
+
+        assert_noop!(
+            Reviews::review_edit(RuntimeOrigin::signed(OWNER), PUBLISHER, GAME_ID, review(0)),
+            Error::<Test>::ReviewDetailsInvalid
+        );
+
+        assert_ok!(Reviews::review_edit(
+            RuntimeOrigin::signed(OWNER),
+            PUBLISHER,
+            GAME_ID,
+            review(9)
+        ));
+        assert_eq!(
+            GameReviews::<Test>::get((PUBLISHER, GAME_ID), OWNER).map(|review| review.details),
+            Some(review(9))
+        );
+        assert_eq!(
+            ReviewSummaries::<Test>::get((PUBLISHER, GAME_ID)),
+            ReviewSummary { count: 2, total_score: 13 }
+        );
+        System::assert_last_event(
+            Event::ReviewEdited {
+                reviewer: OWNER,
+                publisher: PUBLISHER,
+                game_id: GAME_ID,
+                score: 9,
+            }
+            .into(),
+        );
+    })
+}
+
+#[test]
+fn test_review_delete() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Reviews::review_delete(RuntimeOrigin::signed(OWNER), PUBLISHER, GAME_ID),
+            Error::<Test>::ReviewNotFound
+        );
+
+        assert_ok!(Reviews::review_add(
+            RuntimeOrigin::signed(OWNER),
+            PUBLISHER,
+            GAME_ID,
+            review(7)
+        ));
+        assert_ok!(Reviews::review_add(
+            RuntimeOrigin::signed(OTHER_OWNER),
+            PUBLISHER,
+            GAME_ID,
+            review(4)
+        ));
+
+        assert_ok!(Reviews::review_delete(RuntimeOrigin::signed(OWNER), PUBLISHER, GAME_ID));
+        assert_eq!(GameReviews::<Test>::get((PUBLISHER, GAME_ID), OWNER), None);
+        assert_eq!(review_hold(&OWNER), 0);
+        assert_eq!(
+            ReviewSummaries::<Test>::get((PUBLISHER, GAME_ID)),
+            ReviewSummary { count: 1, total_score: 4 }
+        );
+        System::assert_last_event(
+            Event::ReviewDeleted { reviewer: OWNER, publisher: PUBLISHER, game_id: GAME_ID }.into(),
+        );
+
+        assert_ok!(Reviews::review_delete(RuntimeOrigin::signed(OTHER_OWNER), PUBLISHER, GAME_ID));
+        assert!(!ReviewSummaries::<Test>::contains_key((PUBLISHER, GAME_ID)));
+
+        // The review can be posted again after being deleted
+        assert_ok!(Reviews::review_add(
+            RuntimeOrigin::signed(OWNER),
+            PUBLISHER,
+            GAME_ID,
+            review(8)
+        ));
+    })
+}
+
+#[test]
+fn test_review_reply() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Reviews::review_reply(RuntimeOrigin::signed(PUBLISHER), GAME_ID, OWNER, Some(reply())),
+            Error::<Test>::ReviewNotFound
+        );
+
+        assert_ok!(Reviews::review_add(
+            RuntimeOrigin::signed(OWNER),
+            PUBLISHER,
+            GAME_ID,
+            review(7)
+        ));
+
+        assert_noop!(
+            Reviews::review_reply(
+                RuntimeOrigin::signed(PUBLISHER),
+                GAME_ID,
+                OWNER,
+                Some(bounded_vec(b"Qm123"))
+            ),
+            Error::<Test>::ReplyInvalid
+        );
+        // Only the publisher of the game can reply
+        assert_noop!(
+            Reviews::review_reply(RuntimeOrigin::signed(OWNER), GAME_ID, OWNER, Some(reply())),
+            Error::<Test>::ReviewNotFound
+        );
+
+        assert_ok!(Reviews::review_reply(
+            RuntimeOrigin::signed(PUBLISHER),
+            GAME_ID,
+            OWNER,
+            Some(reply())
+        ));
+        assert_eq!(ReviewReplies::<Test>::get((PUBLISHER, GAME_ID), OWNER), Some(reply()));
+        System::assert_last_event(
+            Event::ReviewReplied {
+                publisher: PUBLISHER,
+                game_id: GAME_ID,
+                reviewer: OWNER,
+                cid: Some(reply()),
+            }
+            .into(),
+        );
+
+        assert_ok!(Reviews::review_reply(RuntimeOrigin::signed(PUBLISHER), GAME_ID, OWNER, None));
+        assert_eq!(ReviewReplies::<Test>::get((PUBLISHER, GAME_ID), OWNER), None);
+        System::assert_last_event(
+            Event::ReviewReplied {
+                publisher: PUBLISHER,
+                game_id: GAME_ID,
+                reviewer: OWNER,
+                cid: None,
+            }
+            .into(),
+        );
+    })
+}
+
+#[test]
+fn test_review_report() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Reviews::review_report(RuntimeOrigin::signed(OTHER_OWNER), PUBLISHER, GAME_ID, OWNER),
+            Error::<Test>::ReviewNotFound
+        );
+
+        assert_ok!(Reviews::review_add(
+            RuntimeOrigin::signed(OWNER),
+            PUBLISHER,
+            GAME_ID,
+            review(7)
+        ));
+
+        assert_noop!(
+            Reviews::review_report(RuntimeOrigin::signed(OWNER), PUBLISHER, GAME_ID, OWNER),
+            Error::<Test>::ReviewSelfReport
+        );
+        assert_noop!(
+            Reviews::review_report(RuntimeOrigin::signed(NON_OWNER), PUBLISHER, GAME_ID, OWNER),
+            Error::<Test>::GameNotOwned
+        );
+
+        assert_ok!(Reviews::review_report(
+            RuntimeOrigin::signed(OTHER_OWNER),
+            PUBLISHER,
+            GAME_ID,
+            OWNER
+        ));
+        assert!(ReviewReports::<Test>::contains_key(((PUBLISHER, GAME_ID), OWNER), OTHER_OWNER));
+        System::assert_last_event(
+            Event::ReviewReported {
+                reporter: OTHER_OWNER,
+                publisher: PUBLISHER,
+                game_id: GAME_ID,
+                reviewer: OWNER,
+                reports: 1,
+            }
+            .into(),
+        );
+
+        assert_noop!(
+            Reviews::review_report(RuntimeOrigin::signed(OTHER_OWNER), PUBLISHER, GAME_ID, OWNER),
+            Error::<Test>::ReviewAlreadyReported
+        );
+
+        assert_ok!(Reviews::review_report(
+            RuntimeOrigin::signed(THIRD_OWNER),
+            PUBLISHER,
+            GAME_ID,
+            OWNER
+        ));
+        assert_eq!(
+            GameReviews::<Test>::get((PUBLISHER, GAME_ID), OWNER).map(|review| review.reports),
+            Some(2)
+        );
+
+        // The reports are cleared along with the review
+        assert_ok!(Reviews::review_delete(RuntimeOrigin::signed(OWNER), PUBLISHER, GAME_ID));
+        assert_eq!(ReviewReports::<Test>::iter_prefix(((PUBLISHER, GAME_ID), OWNER)).count(), 0);
+    })
+}
+
+#[test]
+fn test_review_report_limit_reached() {
+    new_test_ext().execute_with(|| {
+        insert_owner(NON_OWNER);
+        assert_ok!(Reviews::review_add(
+            RuntimeOrigin::signed(OWNER),
+            PUBLISHER,
+            GAME_ID,
+            review(7)
+        ));
+        for reporter in [OTHER_OWNER, THIRD_OWNER] {
+            assert_ok!(Reviews::review_report(
+                RuntimeOrigin::signed(reporter),
+                PUBLISHER,
+                GAME_ID,
+                OWNER
+            ));
+        }
+
+        assert_noop!(
+            Reviews::review_report(RuntimeOrigin::signed(NON_OWNER), PUBLISHER, GAME_ID, OWNER),
+            Error::<Test>::ReviewReportLimitReached
+        );
+    })
+}
+
+#[test]
+fn test_review_hide() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Reviews::review_add(
+            RuntimeOrigin::signed(OWNER),
+            PUBLISHER,
+            GAME_ID,
+            review(7)
+        ));
+        assert_ok!(Reviews::review_add(
+            RuntimeOrigin::signed(OTHER_OWNER),
+            PUBLISHER,
+            GAME_ID,
+            review(4)
+        ));
+
+        assert_noop!(
+            Reviews::review_hide(
+                RuntimeOrigin::signed(PUBLISHER),
+                PUBLISHER,
+                GAME_ID,
+                OWNER,
+                false
+            ),
+            DispatchError::BadOrigin
+        );
+
+        assert_ok!(Reviews::review_hide(RuntimeOrigin::root(), PUBLISHER, GAME_ID, OWNER, false));
+        let hidden = GameReviews::<Test>::get((PUBLISHER, GAME_ID), OWNER).unwrap();
+        assert!(hidden.hidden);
+        assert_eq!(hidden.deposit, REVIEW_DEPOSIT);
+        assert_eq!(review_hold(&OWNER), REVIEW_DEPOSIT);
+        assert_eq!(
+            ReviewSummaries::<Test>::get((PUBLISHER, GAME_ID)),
+            ReviewSummary { count: 1, total_score: 4 }
+        );
+        System::assert_last_event(
+            Event::ReviewHidden {
+                publisher: PUBLISHER,
+                game_id: GAME_ID,
+                reviewer: OWNER,
+                slashed: 0,
+            }
+            .into(),
+        );
+
+        assert_noop!(
+            Reviews::review_hide(RuntimeOrigin::root(), PUBLISHER, GAME_ID, OWNER, true),
+            Error::<Test>::ReviewHidden
+        );
+        assert_noop!(
+            Reviews::review_edit(RuntimeOrigin::signed(OWNER), PUBLISHER, GAME_ID, review(9)),
+            Error::<Test>::ReviewHidden
+        );
+        assert_noop!(
+            Reviews::review_report(RuntimeOrigin::signed(OTHER_OWNER), PUBLISHER, GAME_ID, OWNER),
+            Error::<Test>::ReviewHidden
+        );
+
+        // Deleting a hidden review returns the deposit and leaves the summary untouched
+        assert_ok!(Reviews::review_delete(RuntimeOrigin::signed(OWNER), PUBLISHER, GAME_ID));
+        assert_eq!(review_hold(&OWNER), 0);
+        assert_eq!(<Balances as fungible::Inspect<_>>::balance(&OWNER), INITIAL_BALANCE);
+        assert_eq!(
+            ReviewSummaries::<Test>::get((PUBLISHER, GAME_ID)),
+            ReviewSummary { count: 1, total_score: 4 }
+        );
+
+        // The reviewer cannot post the review again
+        assert!(HiddenReviewers::<Test>::contains_key((PUBLISHER, GAME_ID), OWNER));
+        assert_noop!(
+            Reviews::review_add(RuntimeOrigin::signed(OWNER), PUBLISHER, GAME_ID, review(9)),
+            Error::<Test>::ReviewHidden
+        );
+    })
+}
+
+#[test]
+fn test_review_hide_slash() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Reviews::review_add(
+            RuntimeOrigin::signed(OWNER),
+            PUBLISHER,
+            GAME_ID,
+            review(7)
+        ));
+
+        assert_ok!(Reviews::review_hide(RuntimeOrigin::root(), PUBLISHER, GAME_ID, OWNER, true));
+        assert_eq!(
+            GameReviews::<Test>::get((PUBLISHER, GAME_ID), OWNER).map(|review| review.deposit),
+            Some(0)
+        );
+        assert_eq!(review_hold(&OWNER), 0);
+        assert_eq!(
+            <Balances as fungible::Inspect<_>>::balance(&OWNER),
+            INITIAL_BALANCE - REVIEW_DEPOSIT
+        );
+        assert!(!ReviewSummaries::<Test>::contains_key((PUBLISHER, GAME_ID)));
+        System::assert_last_event(
+            Event::ReviewHidden {
+                publisher: PUBLISHER,
+                game_id: GAME_ID,
+                reviewer: OWNER,
+                slashed: REVIEW_DEPOSIT,
+            }
+            .into(),
+        );
+
+        assert_ok!(Reviews::review_delete(RuntimeOrigin::signed(OWNER), PUBLISHER, GAME_ID));
+        assert_eq!(
+            <Balances as fungible::Inspect<_>>::balance(&OWNER),
+            INITIAL_BALANCE - REVIEW_DEPOSIT
+        );
+    })
+}
@@ -0,0 +1,45 @@
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use core::marker::PhantomData;
+use frame_support::{
+    traits::Get,
+    weights::{constants::RocksDbWeight, Weight},
+};
+
+/// Weight functions needed for liganite_reviews.
+pub trait WeightInfo {
+    fn review_add() -> Weight;
+    fn review_edit() -> Weight;
+    fn review_delete() -> Weight;
+    fn review_reply() -> Weight;
+    fn review_report() -> Weight;
+    fn review_hide() -> Weight;
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+    fn review_add() -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn review_edit() -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn review_delete() -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn review_reply() -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn review_report() -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn review_hide() -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+}
@@ -0,0 +1,161 @@
+//! Verification of the downloaded game builds against their manifest.
+//!
+//! The chain stores the manifest set by the signed transaction of the publisher, and never checks
+//! a signature of it. A publisher distributing the manifest outside of the chain can sign it, and
+//! the signature is verified off-chain only, with [`verify_signature`].
+
+use crate::types::{BuildDigest, BuildManifest};
+use alloc::vec::Vec;
+use frame_support::{
+    pallet_prelude::Encode,
+    sp_runtime::{traits::Verify, AccountId32, MultiSignature},
+};
+
+/// The context the build manifests are signed in, so that their signatures cannot be used for
+/// other messages.
+pub const MANIFEST_SIGNING_CONTEXT: &[u8] = b"liganite/build-manifest";
+
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub enum BuildError {
+    /// The size of the build does not match the manifest
+    SizeMismatch,
+    /// The digest of the build does not match the manifest
+    DigestMismatch,
+    /// The signature of the manifest is not valid for the publisher
+    BadSignature,
+}
+
+impl BuildManifest {
+    /// Returns the payload signed by the publisher.
+    pub fn signing_payload(&self) -> Vec<u8> {
+        (MANIFEST_SIGNING_CONTEXT, self).encode()
+    }
+}
+
+/// Computes the size and the digest of a build read in chunks.
+#[derive(Clone, Default)]
+pub struct BuildHasher {
+    hasher: blake3::Hasher,
+    size: u64,
+}
+
+impl BuildHasher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the next chunk of the build.
+    pub fn update(&mut self, chunk: &[u8]) -> &mut Self {
+        self.hasher.update(chunk);
+        self.size = self.size.saturating_add(chunk.len() as u64);
+        self
+    }
+
+    /// Returns the size of the build read so far, in bytes.
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// Returns the BLAKE3 digest of the build read so far.
+    pub fn digest(&self) -> BuildDigest {
+        self.hasher.finalize().into()
+    }
+
+    /// Verifies the build read so far against the manifest.
+    pub fn verify(&self, manifest: &BuildManifest) -> Result<(), BuildError> {
+        if self.size != manifest.size {
+            return Err(BuildError::SizeMismatch);
+        }
+        if self.digest() != manifest.digest {
+            return Err(BuildError::DigestMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// Returns the BLAKE3 digest of a build.
+pub fn digest(build: &[u8]) -> BuildDigest {
+    blake3::hash(build).into()
+}
+
+/// Verifies a downloaded build against the manifest of the game.
+pub fn verify(manifest: &BuildManifest, build: &[u8]) -> Result<(), BuildError> {
+    BuildHasher::new().update(build).verify(manifest)
+}
+
+/// Verifies the signature of a manifest distributed outside of the chain by the publisher.
+pub fn verify_signature(
+    manifest: &BuildManifest,
+    signature: &MultiSignature,
+    publisher: &AccountId32,
+) -> Result<(), BuildError> {
+    if !signature.verify(&manifest.signing_payload()[..], publisher) {
+        return Err(BuildError::BadSignature);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::bounded_vec;
+    use sp_core::{sr25519, Pair};
+
+    const BUILD: &[u8] = b"the archive of the game build";
+
+    fn manifest() -> BuildManifest {
+        BuildManifest {
+            cid: bounded_vec(b"QmYwAPJzv5CZsnAztbCxjRMoa6zFzFG8pGzLFZxojtL8MX"),
+            size: BUILD.len() as u64,
+            digest: digest(BUILD),
+        }
+    }
+
+    #[test]
+    fn test_verify() {
+        assert_eq!(verify(&manifest(), BUILD), Ok(()));
+    }
+
+    #[test]
+    fn test_verify_chunks() {
+        let (head, tail) = BUILD.split_at(10);
+
+        let mut hasher = BuildHasher::new();
+        hasher.update(head).update(tail);
+        assert_eq!(hasher.size(), BUILD.len() as u64);
+        assert_eq!(hasher.verify(&manifest()), Ok(()));
+    }
+
+    #[test]
+    fn test_verify_tampered_build() {
+        let mut tampered = BUILD.to_vec();
+
+        tampered.push(0);
+        assert_eq!(verify(&manifest(), &tampered), Err(BuildError::SizeMismatch));
+
+        tampered.pop();
+        tampered[0] ^= 1;
+        assert_eq!(verify(&manifest(), &tampered), Err(BuildError::DigestMismatch));
+    }
+
+    #[test]
+    fn test_verify_signature() {
+        let publisher = sr25519::Pair::from_seed(&[1; 32]);
+        let account = AccountId32::from(publisher.public());
+        let other = AccountId32::from(sr25519::Pair::from_seed(&[2; 32]).public());
+        let signature = MultiSignature::from(publisher.sign(&manifest().signing_payload()));
+
+        assert_eq!(verify_signature(&manifest(), &signature, &account), Ok(()));
+        assert_eq!(
+            verify_signature(&manifest(), &signature, &other),
+            Err(BuildError::BadSignature)
+        );
+
+        let mut manifest = manifest();
+        manifest.cid = bounded_vec(b"QmRJzSVrU5kMkXzDCrePyx3TX7gGu8cXsogX5xLyfMuNPG");
+        assert_eq!(
+            verify_signature(&manifest, &signature, &account),
+            Err(BuildError::BadSignature)
+        );
+    }
+}
@@ -1,5 +1,9 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
+pub mod build;
+pub mod ownership;
 pub mod publisher;
 pub mod tags;
 pub mod testing;
@@ -7,7 +11,12 @@ pub mod types;
 pub mod validate;
 
 pub const MAX_CID_SIZE: u32 = 128;
+pub const MAX_GAMES_PER_PLAN: u32 = 100;
+pub const MAX_LOCALE_SIZE: u32 = 16;
 pub const MAX_NAME_SIZE: u32 = 128;
+pub const MAX_REGIONAL_PRICES: u32 = 32;
+pub const MAX_REVIEW_SCORE: u8 = 10;
 pub const MAX_TAGS_PER_GAME: u32 = 20;
 pub const MAX_TAG_SIZE: u32 = 50;
+pub const MAX_TOP_LEVEL_GENRES_PER_GAME: u32 = 3;
 pub const MAX_URL_SIZE: u32 = 128;
@@ -0,0 +1,11 @@
+use crate::types::GameId;
+
+pub trait OwnershipManager {
+    type AccountId;
+
+    fn is_owner(who: &Self::AccountId, publisher: &Self::AccountId, game_id: GameId) -> bool;
+
+    /// Adds the game to the collection of the account, bypassing the purchase.
+    #[cfg(feature = "runtime-benchmarks")]
+    fn insert_owner(who: &Self::AccountId, publisher: &Self::AccountId, game_id: GameId);
+}
@@ -1,420 +1,482 @@
-/// List of predefined game tags.
-pub const TAGS: &[&str; 406] = &[
+use crate::types::{
+    TagCategory::{self, *},
+    TagId,
+};
+
+/// List of predefined game tags with their ids and categories.
+///
+/// The ids are stable: a tag keeps its id when the list is edited, and the ids of removed tags are
+/// never reused. New tags get ids after the highest one in the list.
+pub const TAGS: &[(TagId, TagCategory, &str); 383] = &[
     // Top-Level Genres
-    "Action",
-    "Adventure",
-    "Casual",
-    "Experimental",
-    "Puzzle",
-    "Racing",
-    "RPG",
-    "Simulation",
-    "Sports",
-    "Strategy",
-    "Tabletop",
+    (0, TopLevelGenre, "Action"),
+    (1, TopLevelGenre, "Adventure"),
+    (2, TopLevelGenre, "Casual"),
+    (3, TopLevelGenre, "Experimental"),
+    (4, TopLevelGenre, "Puzzle"),
+    (5, TopLevelGenre, "Racing"),
+    (6, TopLevelGenre, "RPG"),
+    (7, TopLevelGenre, "Simulation"),
+    (8, TopLevelGenre, "Sports"),
+    (9, TopLevelGenre, "Strategy"),
+    (10, TopLevelGenre, "Tabletop"),
     // Genres
-    "Action RPG",
-    "Action-Adventure",
-    "Arcade",
-    "Auto Battler",
-    "Automobile Sim",
-    "Base Building",
-    "Baseball",
-    "Basketball",
-    "Battle Royale",
-    "BMX",
-    "Board Game",
-    "Bowling",
-    "Building",
-    "Card Game",
-    "Character Action Game",
-    "Chess",
-    "Clicker",
-    "Cycling",
-    "Diplomacy",
-    "eSports",
-    "Experimental",
-    "Exploration",
-    "Farming Sim",
-    "Fighting",
-    "Football",
-    "God Game",
-    "Golf",
-    "Hacking",
-    "Hidden Object",
-    "Hockey",
-    "Idler",
-    "Interactive Fiction",
-    "Management",
-    "Match 3",
-    "Medical Sim",
-    "Mini Golf",
-    "Mining",
-    "MMORPG",
-    "MOBA",
-    "Motocross",
-    "Open World",
-    "Outbreak Sim",
-    "Party-Based RPG",
-    "Pinball",
-    "Platformer",
-    "Point & Click",
-    "Rhythm",
-    "Roguelike",
-    "RTS",
-    "Sandbox",
-    "Shooter",
-    "Skateboarding",
-    "Skating",
-    "Skiing",
-    "Snowboarding",
-    "Soccer",
-    "Space Sim",
-    "Stealth",
-    "Strategy RPG",
-    "Survival",
-    "Tennis",
-    "Tower Defense",
-    "Trivia",
-    "Turn-Based Strategy",
-    "Visual Novel",
-    "Walking Simulator",
-    "Word Game",
-    "Wrestling",
+    (11, Genre, "Action RPG"),
+    (12, Genre, "Action-Adventure"),
+    (13, Genre, "Arcade"),
+    (14, Genre, "Auto Battler"),
+    (15, Genre, "Automobile Sim"),
+    (16, Genre, "Base Building"),
+    (17, Genre, "Baseball"),
+    (18, Genre, "Basketball"),
+    (19, Genre, "Battle Royale"),
+    (20, Genre, "BMX"),
+    (21, Genre, "Board Game"),
+    (22, Genre, "Bowling"),
+    (23, Genre, "Building"),
+    (24, Genre, "Card Game"),
+    (25, Genre, "Character Action Game"),
+    (26, Genre, "Chess"),
+    (27, Genre, "Clicker"),
+    (28, Genre, "Cycling"),
+    (29, Genre, "Diplomacy"),
+    (30, Genre, "eSports"),
+    (32, Genre, "Exploration"),
+    (33, Genre, "Farming Sim"),
+    (34, Genre, "Fighting"),
+    (35, Genre, "Football"),
+    (36, Genre, "God Game"),
+    (37, Genre, "Golf"),
+    (38, Genre, "Hacking"),
+    (39, Genre, "Hidden Object"),
+    (40, Genre, "Hockey"),
+    (41, Genre, "Idler"),
+    (42, Genre, "Interactive Fiction"),
+    (43, Genre, "Management"),
+    (44, Genre, "Match 3"),
+    (45, Genre, "Medical Sim"),
+    (46, Genre, "Mini Golf"),
+    (47, Genre, "Mining"),
+    (48, Genre, "MMORPG"),
+    (49, Genre, "MOBA"),
+    (50, Genre, "Motocross"),
+    (51, Genre, "Open World"),
+    (52, Genre, "Outbreak Sim"),
+    (53, Genre, "Party-Based RPG"),
+    (54, Genre, "Pinball"),
+    (55, Genre, "Platformer"),
+    (56, Genre, "Point & Click"),
+    (57, Genre, "Rhythm"),
+    (58, Genre, "Roguelike"),
+    (59, Genre, "RTS"),
+    (60, Genre, "Sandbox"),
+    (61, Genre, "Shooter"),
+    (62, Genre, "Skateboarding"),
+    (63, Genre, "Skating"),
+    (64, Genre, "Skiing"),
+    (65, Genre, "Snowboarding"),
+    (66, Genre, "Soccer"),
+    (67, Genre, "Space Sim"),
+    (68, Genre, "Stealth"),
+    (69, Genre, "Strategy RPG"),
+    (70, Genre, "Survival"),
+    (71, Genre, "Tennis"),
+    (72, Genre, "Tower Defense"),
+    (73, Genre, "Trivia"),
+    (74, Genre, "Turn-Based Strategy"),
+    (75, Genre, "Visual Novel"),
+    (76, Genre, "Walking Simulator"),
+    (77, Genre, "Word Game"),
+    (78, Genre, "Wrestling"),
     // Sub-Genres
-    "2D Fighter",
-    "2D Platformer",
-    "3D Fighter",
-    "3D Platformer",
-    "4X",
-    "Action Roguelike",
-    "Arena Shooter",
-    "Beat 'em up",
-    "Bullet Hell",
-    "Card Battler",
-    "Choose Your Own Adventure",
-    "City Builder",
-    "Collectathon",
-    "Colony Sim",
-    "Combat Racing",
-    "CRPG",
-    "Dating Sim",
-    "Dungeon Crawler",
-    "Education",
-    "Flight",
-    "FPS",
-    "Grand Strategy",
-    "Hack and Slash",
-    "Heist",
-    "Hero Shooter",
-    "Horror",
-    "Immersive Sim",
-    "Investigation",
-    "JRPG",
-    "Life Sim",
-    "Looter Shooter",
-    "Metroidvania",
-    "Mystery Dungeon",
-    "On-Rails Shooter",
-    "Open World Survival Craft",
-    "Political Sim",
-    "Precision Platformer",
-    "Programming",
-    "Real Time Tactics",
-    "Roguelite",
-    "Roguevania",
-    "Runner",
-    "Shoot 'Em Up",
-    "Side Scroller",
-    "Sokoban",
-    "Solitaire",
-    "Souls-like",
-    "Spectacle fighter",
-    "Spelling",
-    "Survival Horror",
-    "Tactical RPG",
-    "Third-Person Shooter",
-    "Time Management",
-    "Top-Down Shooter",
-    "Trading",
-    "Trading Card Game",
-    "Traditional Roguelike",
-    "Turn-Based Tactics",
-    "Twin Stick Shooter",
-    "Typing",
-    "Wargame",
+    (79, SubGenre, "2D Fighter"),
+    (80, SubGenre, "2D Platformer"),
+    (81, SubGenre, "3D Fighter"),
+    (82, SubGenre, "3D Platformer"),
+    (83, SubGenre, "4X"),
+    (84, SubGenre, "Action Roguelike"),
+    (85, SubGenre, "Arena Shooter"),
+    (86, SubGenre, "Beat 'em up"),
+    (87, SubGenre, "Bullet Hell"),
+    (88, SubGenre, "Card Battler"),
+    (89, SubGenre, "Choose Your Own Adventure"),
+    (90, SubGenre, "City Builder"),
+    (91, SubGenre, "Collectathon"),
+    (92, SubGenre, "Colony Sim"),
+    (93, SubGenre, "Combat Racing"),
+    (94, SubGenre, "CRPG"),
+    (95, SubGenre, "Dating Sim"),
+    (96, SubGenre, "Dungeon Crawler"),
+    (97, SubGenre, "Education"),
+    (98, SubGenre, "Flight"),
+    (99, SubGenre, "FPS"),
+    (100, SubGenre, "Grand Strategy"),
+    (101, SubGenre, "Hack and Slash"),
+    (102, SubGenre, "Heist"),
+    (103, SubGenre, "Hero Shooter"),
+    (104, SubGenre, "Horror"),
+    (105, SubGenre, "Immersive Sim"),
+    (106, SubGenre, "Investigation"),
+    (107, SubGenre, "JRPG"),
+    (108, SubGenre, "Life Sim"),
+    (109, SubGenre, "Looter Shooter"),
+    (110, SubGenre, "Metroidvania"),
+    (111, SubGenre, "Mystery Dungeon"),
+    (112, SubGenre, "On-Rails Shooter"),
+    (113, SubGenre, "Open World Survival Craft"),
+    (114, SubGenre, "Political Sim"),
+    (115, SubGenre, "Precision Platformer"),
+    (116, SubGenre, "Programming"),
+    (117, SubGenre, "Real Time Tactics"),
+    (118, SubGenre, "Roguelite"),
+    (119, SubGenre, "Roguevania"),
+    (120, SubGenre, "Runner"),
+    (121, SubGenre, "Shoot 'Em Up"),
+    (122, SubGenre, "Side Scroller"),
+    (123, SubGenre, "Sokoban"),
+    (124, SubGenre, "Solitaire"),
+    (125, SubGenre, "Souls-like"),
+    (126, SubGenre, "Spectacle fighter"),
+    (127, SubGenre, "Spelling"),
+    (128, SubGenre, "Survival Horror"),
+    (129, SubGenre, "Tactical RPG"),
+    (130, SubGenre, "Third-Person Shooter"),
+    (131, SubGenre, "Time Management"),
+    (132, SubGenre, "Top-Down Shooter"),
+    (133, SubGenre, "Trading"),
+    (134, SubGenre, "Trading Card Game"),
+    (135, SubGenre, "Traditional Roguelike"),
+    (136, SubGenre, "Turn-Based Tactics"),
+    (137, SubGenre, "Twin Stick Shooter"),
+    (138, SubGenre, "Typing"),
+    (139, SubGenre, "Wargame"),
     // Visuals & Viewpoint
-    "2.5D",
-    "2D",
-    "360 Video",
-    "3D",
-    "3D Vision",
-    "Abstract",
-    "Anime",
-    "Cartoon",
-    "Cartoony",
-    "Cinematic",
-    "Colorful",
-    "Comic Book",
-    "Cute",
-    "First-Person",
-    "FMV",
-    "Hand-drawn",
-    "Isometric",
-    "Minimalist",
-    "Noir",
-    "Pixel Graphics",
-    "Psychedelic",
-    "Realistic",
-    "Split Screen",
-    "Stylized",
-    "Text-Based",
-    "Third Person",
-    "Top-Down",
-    "Voxel",
-    "VR",
+    (140, Visuals, "2.5D"),
+    (141, Visuals, "2D"),
+    (142, Visuals, "360 Video"),
+    (143, Visuals, "3D"),
+    (144, Visuals, "3D Vision"),
+    (145, Visuals, "Abstract"),
+    (146, Visuals, "Anime"),
+    (147, Visuals, "Cartoon"),
+    (148, Visuals, "Cartoony"),
+    (149, Visuals, "Cinematic"),
+    (150, Visuals, "Colorful"),
+    (151, Visuals, "Comic Book"),
+    (152, Visuals, "Cute"),
+    (153, Visuals, "First-Person"),
+    (154, Visuals, "FMV"),
+    (155, Visuals, "Hand-drawn"),
+    (156, Visuals, "Isometric"),
+    (157, Visuals, "Minimalist"),
+    (158, Visuals, "Noir"),
+    (159, Visuals, "Pixel Graphics"),
+    (160, Visuals, "Psychedelic"),
+    (161, Visuals, "Realistic"),
+    (162, Visuals, "Split Screen"),
+    (163, Visuals, "Stylized"),
+    (164, Visuals, "Text-Based"),
+    (165, Visuals, "Third Person"),
+    (166, Visuals, "Top-Down"),
+    (167, Visuals, "Voxel"),
+    (168, Visuals, "VR"),
     // Themes & Moods
-    "1980s",
-    "1990's",
-    "Agriculture",
-    "Aliens",
-    "Alternate History",
-    "America",
-    "Atmospheric",
-    "Assassin",
-    "Bikes",
-    "Capitalism",
-    "Cats",
-    "Cold War",
-    "Comic Book",
-    "Conspiracy",
-    "Crime",
-    "Cyberpunk",
-    "Dark",
-    "Dark Fantasy",
-    "Demons",
-    "Destruction",
-    "Detective",
-    "Dinosaurs",
-    "Diplomacy",
-    "Dog",
-    "Dragons",
-    "Dynamic Narration",
-    "Economy",
-    "Education",
-    "Faith",
-    "Family Friendly",
-    "Fantasy",
-    "Foreign",
-    "Futuristic",
-    "Gambling",
-    "Game Development",
-    "Gothic",
-    "Heist",
-    "Historical",
-    "Horses",
-    "Illuminati",
-    "Investigation",
-    "Jet",
-    "Lemmings",
-    "LGBTQ+",
-    "Logic",
-    "Loot",
-    "Lovecraftian",
-    "Magic",
-    "Management",
-    "Mars",
-    "Mechs",
-    "Medieval",
-    "Memes",
-    "Military",
-    "Modern",
-    "Motorbike",
-    "Mystery",
-    "Mythology",
-    "Nature",
-    "Naval",
-    "Ninja",
-    "Offroad",
-    "Old School",
-    "Otome",
-    "Parkour",
-    "Philosophical",
-    "Pirates",
-    "Political",
-    "Politics",
-    "Pool",
-    "Post-apocalyptic",
-    "Programming",
-    "Retro",
-    "Robots",
-    "Romance",
-    "Rome",
-    "Satire",
-    "Science",
-    "Sci-fi",
-    "Sniper",
-    "Snow",
-    "Space",
-    "Stealth",
-    "Steampunk",
-    "Submarine",
-    "Superhero",
-    "Supernatural",
-    "Surreal",
-    "Survival",
-    "Swordplay",
-    "Tactical",
-    "Tanks",
-    "Thriller",
-    "Time Travel",
-    "Trains",
-    "Transhumanism",
-    "Transportation",
-    "Underground",
-    "Underwater",
-    "Vampire",
-    "War",
-    "Werewolves",
-    "Western",
-    "World War I",
-    "World War II",
+    (169, Theme, "1980s"),
+    (170, Theme, "1990's"),
+    (171, Theme, "Agriculture"),
+    (172, Theme, "Aliens"),
+    (173, Theme, "Alternate History"),
+    (174, Theme, "America"),
+    (175, Theme, "Atmospheric"),
+    (176, Theme, "Assassin"),
+    (177, Theme, "Bikes"),
+    (178, Theme, "Capitalism"),
+    (179, Theme, "Cats"),
+    (180, Theme, "Cold War"),
+    (182, Theme, "Conspiracy"),
+    (183, Theme, "Crime"),
+    (184, Theme, "Cyberpunk"),
+    (185, Theme, "Dark"),
+    (186, Theme, "Dark Fantasy"),
+    (187, Theme, "Demons"),
+    (188, Theme, "Destruction"),
+    (189, Theme, "Detective"),
+    (190, Theme, "Dinosaurs"),
+    (192, Theme, "Dog"),
+    (193, Theme, "Dragons"),
+    (194, Theme, "Dynamic Narration"),
+    (195, Theme, "Economy"),
+    (197, Theme, "Faith"),
+    (198, Theme, "Family Friendly"),
+    (199, Theme, "Fantasy"),
+    (200, Theme, "Foreign"),
+    (201, Theme, "Futuristic"),
+    (202, Theme, "Gambling"),
+    (203, Theme, "Game Development"),
+    (204, Theme, "Gothic"),
+    (206, Theme, "Historical"),
+    (207, Theme, "Horses"),
+    (208, Theme, "Illuminati"),
+    (210, Theme, "Jet"),
+    (211, Theme, "Lemmings"),
+    (212, Theme, "LGBTQ+"),
+    (213, Theme, "Logic"),
+    (214, Theme, "Loot"),
+    (215, Theme, "Lovecraftian"),
+    (216, Theme, "Magic"),
+    (218, Theme, "Mars"),
+    (219, Theme, "Mechs"),
+    (220, Theme, "Medieval"),
+    (221, Theme, "Memes"),
+    (222, Theme, "Military"),
+    (223, Theme, "Modern"),
+    (224, Theme, "Motorbike"),
+    (225, Theme, "Mystery"),
+    (226, Theme, "Mythology"),
+    (227, Theme, "Nature"),
+    (228, Theme, "Naval"),
+    (229, Theme, "Ninja"),
+    (230, Theme, "Offroad"),
+    (231, Theme, "Old School"),
+    (232, Theme, "Otome"),
+    (233, Theme, "Parkour"),
+    (234, Theme, "Philosophical"),
+    (235, Theme, "Pirates"),
+    (236, Theme, "Political"),
+    (237, Theme, "Politics"),
+    (238, Theme, "Pool"),
+    (239, Theme, "Post-apocalyptic"),
+    (241, Theme, "Retro"),
+    (242, Theme, "Robots"),
+    (243, Theme, "Romance"),
+    (244, Theme, "Rome"),
+    (245, Theme, "Satire"),
+    (246, Theme, "Science"),
+    (247, Theme, "Sci-fi"),
+    (248, Theme, "Sniper"),
+    (249, Theme, "Snow"),
+    (250, Theme, "Space"),
+    (252, Theme, "Steampunk"),
+    (253, Theme, "Submarine"),
+    (254, Theme, "Superhero"),
+    (255, Theme, "Supernatural"),
+    (256, Theme, "Surreal"),
+    (258, Theme, "Swordplay"),
+    (259, Theme, "Tactical"),
+    (260, Theme, "Tanks"),
+    (261, Theme, "Thriller"),
+    (262, Theme, "Time Travel"),
+    (263, Theme, "Trains"),
+    (264, Theme, "Transhumanism"),
+    (265, Theme, "Transportation"),
+    (266, Theme, "Underground"),
+    (267, Theme, "Underwater"),
+    (268, Theme, "Vampire"),
+    (269, Theme, "War"),
+    (270, Theme, "Werewolves"),
+    (271, Theme, "Western"),
+    (272, Theme, "World War I"),
+    (273, Theme, "World War II"),
     // Features
-    "6DOF",
-    "Archery",
-    "Artificial Intelligence",
-    "Asymmetric VR",
-    "ATV",
-    "Automation",
-    "Base Building",
-    "Boxing",
-    "Building",
-    "Bullet Time",
-    "Character Customization",
-    "Choices Matter",
-    "Class-Based",
-    "Combat",
-    "Conversation",
-    "Crafting",
-    "Deckbuilding",
-    "Driving",
-    "Fishing",
-    "Flight",
-    "FMV",
-    "Grid-Based Movement",
-    "Gun Customization",
-    "Hack and Slash",
-    "Hacking",
-    "Hex Grid",
-    "Hunting",
-    "Inventory Management",
-    "Level Editor",
-    "Linear",
-    "Martial Arts",
-    "Mining",
-    "Moddable",
-    "Multiple Endings",
-    "Music-Based Procedural Generation",
-    "Narration",
-    "Naval Combat",
-    "Nonlinear",
-    "Open World",
-    "Perma Death",
-    "Physics",
-    "Procedural Generation",
-    "PvE",
-    "PvP",
-    "Quick-Time Events",
-    "Resource Management",
-    "Sailing",
-    "Score Attack",
-    "Stealth",
-    "Story Rich",
-    "Tabletop",
-    "Team-Based",
-    "Text-Based",
-    "Time Manipulation",
-    "Trading",
-    "Turn-Based Combat",
-    "Turn-Based Tactics",
-    "Tutorial",
-    "Vehicular Combat",
-    "Female Protagonist",
-    "Silent Protagonist",
-    "Villain Protagonist",
-    "Minigames",
-    "Intentionally Awkward Controls",
+    (274, Feature, "6DOF"),
+    (275, Feature, "Archery"),
+    (276, Feature, "Artificial Intelligence"),
+    (277, Feature, "Asymmetric VR"),
+    (278, Feature, "ATV"),
+    (279, Feature, "Automation"),
+    (281, Feature, "Boxing"),
+    (283, Feature, "Bullet Time"),
+    (284, Feature, "Character Customization"),
+    (285, Feature, "Choices Matter"),
+    (286, Feature, "Class-Based"),
+    (287, Feature, "Combat"),
+    (288, Feature, "Conversation"),
+    (289, Feature, "Crafting"),
+    (290, Feature, "Deckbuilding"),
+    (291, Feature, "Driving"),
+    (292, Feature, "Fishing"),
+    (295, Feature, "Grid-Based Movement"),
+    (296, Feature, "Gun Customization"),
+    (299, Feature, "Hex Grid"),
+    (300, Feature, "Hunting"),
+    (301, Feature, "Inventory Management"),
+    (302, Feature, "Level Editor"),
+    (303, Feature, "Linear"),
+    (304, Feature, "Martial Arts"),
+    (306, Feature, "Moddable"),
+    (307, Feature, "Multiple Endings"),
+    (308, Feature, "Music-Based Procedural Generation"),
+    (309, Feature, "Narration"),
+    (310, Feature, "Naval Combat"),
+    (311, Feature, "Nonlinear"),
+    (313, Feature, "Perma Death"),
+    (314, Feature, "Physics"),
+    (315, Feature, "Procedural Generation"),
+    (316, Feature, "PvE"),
+    (317, Feature, "PvP"),
+    (318, Feature, "Quick-Time Events"),
+    (319, Feature, "Resource Management"),
+    (320, Feature, "Sailing"),
+    (321, Feature, "Score Attack"),
+    (323, Feature, "Story Rich"),
+    (325, Feature, "Team-Based"),
+    (327, Feature, "Time Manipulation"),
+    (329, Feature, "Turn-Based Combat"),
+    (331, Feature, "Tutorial"),
+    (332, Feature, "Vehicular Combat"),
+    (333, Feature, "Female Protagonist"),
+    (334, Feature, "Silent Protagonist"),
+    (335, Feature, "Villain Protagonist"),
+    (336, Feature, "Minigames"),
+    (337, Feature, "Intentionally Awkward Controls"),
     // Players
-    "4 Player Local",
-    "Asynchronous Multiplayer",
-    "Co-op",
-    "Co-op Campaign",
-    "Local Co-Op",
-    "Local Multiplayer",
-    "Massively Multiplayer",
-    "Multiplayer",
-    "Online Co-Op",
-    "Singleplayer",
+    (338, Players, "4 Player Local"),
+    (339, Players, "Asynchronous Multiplayer"),
+    (340, Players, "Co-op"),
+    (341, Players, "Co-op Campaign"),
+    (342, Players, "Local Co-Op"),
+    (343, Players, "Local Multiplayer"),
+    (344, Players, "Massively Multiplayer"),
+    (345, Players, "Multiplayer"),
+    (346, Players, "Online Co-Op"),
+    (347, Players, "Singleplayer"),
     // Other Tags
-    "Based on a Novel",
-    "Batman",
-    "Documentary",
-    "Drama",
-    "Dungeons & Dragons",
-    "Episodic",
-    "Experience",
-    "Feature Film",
-    "Games Workshop",
-    "Indie",
-    "Lara Croft",
-    "LEGO",
-    "Mod",
-    "Movie",
-    "Music",
-    "Real-Time",
-    "Real-Time with Pause",
-    "Remake",
-    "Sequel",
-    "Soundtrack",
-    "Star Wars",
-    "Time Attack",
-    "Turn-Based",
-    "Warhammer 40K",
+    (348, Other, "Based on a Novel"),
+    (349, Other, "Batman"),
+    (350, Other, "Documentary"),
+    (351, Other, "Drama"),
+    (352, Other, "Dungeons & Dragons"),
+    (353, Other, "Episodic"),
+    (354, Other, "Experience"),
+    (355, Other, "Feature Film"),
+    (356, Other, "Games Workshop"),
+    (357, Other, "Indie"),
+    (358, Other, "Lara Croft"),
+    (359, Other, "LEGO"),
+    (360, Other, "Mod"),
+    (361, Other, "Movie"),
+    (362, Other, "Music"),
+    (363, Other, "Real-Time"),
+    (364, Other, "Real-Time with Pause"),
+    (365, Other, "Remake"),
+    (366, Other, "Sequel"),
+    (367, Other, "Soundtrack"),
+    (368, Other, "Star Wars"),
+    (369, Other, "Time Attack"),
+    (370, Other, "Turn-Based"),
+    (371, Other, "Warhammer 40K"),
     // Assessments
-    "Addictive",
-    "Beautiful",
-    "Classic",
-    "Competitive",
-    "Cult Classic",
-    "Difficult",
-    "Emotional",
-    "Epic",
-    "Fast-Paced",
-    "Funny",
-    "Great Soundtrack",
-    "Lore-Rich",
-    "Masterpiece",
-    "Psychological",
-    "Relaxing",
-    "Replay Value",
-    "Short",
-    "Unforgiving",
-    "Comedy",
-    "Dark Comedy",
+    (372, Assessment, "Addictive"),
+    (373, Assessment, "Beautiful"),
+    (374, Assessment, "Classic"),
+    (375, Assessment, "Competitive"),
+    (376, Assessment, "Cult Classic"),
+    (377, Assessment, "Difficult"),
+    (378, Assessment, "Emotional"),
+    (379, Assessment, "Epic"),
+    (380, Assessment, "Fast-Paced"),
+    (381, Assessment, "Funny"),
+    (382, Assessment, "Great Soundtrack"),
+    (383, Assessment, "Lore-Rich"),
+    (384, Assessment, "Masterpiece"),
+    (385, Assessment, "Psychological"),
+    (386, Assessment, "Relaxing"),
+    (387, Assessment, "Replay Value"),
+    (388, Assessment, "Short"),
+    (389, Assessment, "Unforgiving"),
+    (390, Assessment, "Comedy"),
+    (391, Assessment, "Dark Comedy"),
     // Ratings etc
-    "Blood",
-    "Gore",
-    "Mature",
-    "NSFW",
-    "Nudity",
-    "Sexual Content",
-    "Violent",
+    (392, Rating, "Blood"),
+    (393, Rating, "Gore"),
+    (394, Rating, "Mature"),
+    (395, Rating, "NSFW"),
+    (396, Rating, "Nudity"),
+    (397, Rating, "Sexual Content"),
+    (398, Rating, "Violent"),
     // Hardware / Input
-    "Controller",
-    "Hardware",
-    "Mouse only",
-    "Steam Machine",
-    "Touch-Friendly",
-    "TrackIR",
-    "Voice Control",
+    (399, Hardware, "Controller"),
+    (400, Hardware, "Hardware"),
+    (401, Hardware, "Mouse only"),
+    (402, Hardware, "Steam Machine"),
+    (403, Hardware, "Touch-Friendly"),
+    (404, Hardware, "TrackIR"),
+    (405, Hardware, "Voice Control"),
 ];
+
+/// List of the parents of the predefined sub-genres, as pairs of tag ids. The parent of a
+/// sub-genre is a genre, and the sub-genres not listed have no parent.
+pub const TAG_PARENTS: &[(TagId, TagId); 45] = &[
+    (79, 34),  // 2D Fighter -> Fighting
+    (80, 55),  // 2D Platformer -> Platformer
+    (81, 34),  // 3D Fighter -> Fighting
+    (82, 55),  // 3D Platformer -> Platformer
+    (83, 74),  // 4X -> Turn-Based Strategy
+    (84, 58),  // Action Roguelike -> Roguelike
+    (85, 61),  // Arena Shooter -> Shooter
+    (86, 34),  // Beat 'em up -> Fighting
+    (87, 61),  // Bullet Hell -> Shooter
+    (88, 24),  // Card Battler -> Card Game
+    (89, 42),  // Choose Your Own Adventure -> Interactive Fiction
+    (90, 23),  // City Builder -> Building
+    (91, 55),  // Collectathon -> Platformer
+    (92, 43),  // Colony Sim -> Management
+    (94, 53),  // CRPG -> Party-Based RPG
+    (95, 75),  // Dating Sim -> Visual Novel
+    (99, 61),  // FPS -> Shooter
+    (101, 25), // Hack and Slash -> Character Action Game
+    (102, 68), // Heist -> Stealth
+    (103, 61), // Hero Shooter -> Shooter
+    (107, 53), // JRPG -> Party-Based RPG
+    (109, 61), // Looter Shooter -> Shooter
+    (110, 55), // Metroidvania -> Platformer
+    (111, 58), // Mystery Dungeon -> Roguelike
+    (112, 61), // On-Rails Shooter -> Shooter
+    (113, 70), // Open World Survival Craft -> Survival
+    (115, 55), // Precision Platformer -> Platformer
+    (117, 59), // Real Time Tactics -> RTS
+    (118, 58), // Roguelite -> Roguelike
+    (119, 58), // Roguevania -> Roguelike
+    (120, 55), // Runner -> Platformer
+    (121, 61), // Shoot 'Em Up -> Shooter
+    (124, 24), // Solitaire -> Card Game
+    (125, 11), // Souls-like -> Action RPG
+    (126, 25), // Spectacle fighter -> Character Action Game
+    (127, 77), // Spelling -> Word Game
+    (128, 70), // Survival Horror -> Survival
+    (129, 69), // Tactical RPG -> Strategy RPG
+    (130, 61), // Third-Person Shooter -> Shooter
+    (131, 43), // Time Management -> Management
+    (132, 61), // Top-Down Shooter -> Shooter
+    (134, 24), // Trading Card Game -> Card Game
+    (135, 58), // Traditional Roguelike -> Roguelike
+    (136, 74), // Turn-Based Tactics -> Turn-Based Strategy
+    (137, 61), // Twin Stick Shooter -> Shooter
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn test_tags_are_unique() {
+        let ids = TAGS.iter().map(|(id, _, _)| id).collect::<BTreeSet<_>>();
+        let names = TAGS.iter().map(|(_, _, name)| name).collect::<BTreeSet<_>>();
+
+        assert_eq!(ids.len(), TAGS.len());
+        assert_eq!(names.len(), TAGS.len());
+    }
+
+    #[test]
+    fn test_tag_parents_are_genres() {
+        let category = |tag_id| TAGS.iter().find(|(id, _, _)| *id == tag_id).map(|(_, c, _)| *c);
+
+        for (tag_id, parent) in TAG_PARENTS {
+            assert_eq!(category(*tag_id), Some(SubGenre));
+            assert_eq!(category(*parent), Some(Genre));
+        }
+        let children = TAG_PARENTS.iter().map(|(id, _)| id).collect::<BTreeSet<_>>();
+        assert_eq!(children.len(), TAG_PARENTS.len());
+    }
+}
@@ -1,15 +1,31 @@
-use crate::{validate, MAX_CID_SIZE, MAX_NAME_SIZE, MAX_TAGS_PER_GAME, MAX_TAG_SIZE, MAX_URL_SIZE};
-use frame_support::pallet_prelude::*;
+use crate::{
+    validate::{self, CidPolicy},
+    MAX_CID_SIZE, MAX_GAMES_PER_PLAN, MAX_LOCALE_SIZE, MAX_NAME_SIZE, MAX_REGIONAL_PRICES,
+    MAX_REVIEW_SCORE, MAX_TAGS_PER_GAME, MAX_TAG_SIZE, MAX_TOP_LEVEL_GENRES_PER_GAME, MAX_URL_SIZE,
+};
+use frame_support::{
+    pallet_prelude::*,
+    sp_runtime::{traits::Saturating, Percent},
+};
 
 pub type Name = BoundedVec<u8, ConstU32<MAX_NAME_SIZE>>;
 pub type Url = BoundedVec<u8, ConstU32<MAX_URL_SIZE>>;
 pub type Tag = BoundedVec<u8, ConstU32<MAX_TAG_SIZE>>;
+pub type Locale = BoundedVec<u8, ConstU32<MAX_LOCALE_SIZE>>;
 pub type GameTags = BoundedVec<TagId, ConstU32<MAX_TAGS_PER_GAME>>;
 pub type Cid = BoundedVec<u8, ConstU32<MAX_CID_SIZE>>;
+pub type PlanGames = BoundedVec<GameId, ConstU32<MAX_GAMES_PER_PLAN>>;
+pub type RegionalPrices<Currency> = BoundedVec<(RegionId, Currency), ConstU32<MAX_REGIONAL_PRICES>>;
 
 pub type GameId = u16;
 pub type GlobalGameId<T> = (PublisherId<T>, GameId);
 pub type TagId = u16;
+pub type TagProposalId = u32;
+pub type PlanId = u16;
+pub type GlobalPlanId<T> = (PublisherId<T>, PlanId);
+pub type RegionId = u16;
+pub type ReviewScore = u8;
+pub type BuildDigest = [u8; 32];
 
 pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 pub type PublisherId<T> = AccountIdOf<T>;
@@ -31,11 +47,118 @@ impl PublisherDetails {
     }
 }
 
+#[derive(
+    Clone,
+    Copy,
+    Eq,
+    PartialEq,
+    Debug,
+    Default,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    MaxEncodedLen,
+    TypeInfo,
+)]
+pub enum TagCategory {
+    /// The broadest genres, such as "Action" or "Strategy"
+    TopLevelGenre,
+    /// The genres, such as "Platformer"
+    Genre,
+    /// The sub-genres, such as "Metroidvania"
+    SubGenre,
+    /// The visuals and the viewpoint
+    Visuals,
+    /// The themes and moods
+    Theme,
+    /// The gameplay features
+    Feature,
+    /// The supported player modes
+    Players,
+    /// The tags not fitting any other category
+    #[default]
+    Other,
+    /// The assessments of the game
+    Assessment,
+    /// The content ratings
+    Rating,
+    /// The hardware and input devices
+    Hardware,
+}
+
+impl TagCategory {
+    /// Returns the category the parent of a tag must belong to, or `None` if tags of this
+    /// category cannot have a parent.
+    pub fn parent_category(&self) -> Option<TagCategory> {
+        match self {
+            TagCategory::Genre => Some(TagCategory::TopLevelGenre),
+            TagCategory::SubGenre => Some(TagCategory::Genre),
+            _ => None,
+        }
+    }
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Eq,
+    PartialEq,
+    Debug,
+    Default,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    MaxEncodedLen,
+    TypeInfo,
+)]
+pub struct TagInfo {
+    /// The category of the tag
+    pub category: TagCategory,
+    /// The broader tag implied by the tag, if any
+    pub parent: Option<TagId>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub struct TagProposal<AccountId, Currency, BlockNumber> {
+    /// The publisher proposing the tag
+    pub proposer: AccountId,
+    /// The name of the proposed tag
+    pub tag: Tag,
+    /// The taxonomy of the proposed tag
+    pub info: TagInfo,
+    /// The deposit held from the proposer until the proposal is approved, rejected or expired
+    pub deposit: Currency,
+    /// The block at which the proposal expires if not curated
+    pub expires_at: BlockNumber,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Eq,
+    PartialEq,
+    Debug,
+    Default,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    MaxEncodedLen,
+    TypeInfo,
+)]
+#[scale_info(skip_type_params(AssetId))]
+pub enum PaymentAsset<AssetId> {
+    /// The native currency of the network
+    #[default]
+    Native,
+    /// A fungible asset registered on the network
+    Asset(AssetId),
+}
+
 #[derive(
     Clone, Eq, PartialEq, Debug, Encode, Decode, DecodeWithMemTracking, MaxEncodedLen, TypeInfo,
 )]
-#[scale_info(skip_type_params(Currency))]
-pub enum Distribution<Currency> {
+#[scale_info(skip_type_params(Currency, AssetId))]
+pub enum Distribution<Currency, AssetId> {
     /// The game is distributed free of charge
     Free {
         /// The CID of the game
@@ -46,6 +169,8 @@ pub enum Distribution<Currency> {
     Instant {
         /// The price of the game
         price: Currency,
+        /// The asset the price is paid in
+        asset: PaymentAsset<AssetId>,
         /// The CID of the game
         cid: Cid,
     },
@@ -55,45 +180,579 @@ pub enum Distribution<Currency> {
     Individual {
         /// The price of the game
         price: Currency,
+        /// The asset the price is paid in
+        asset: PaymentAsset<AssetId>,
+    },
+    /// The game is sold before its release. In this case the price is held from the buyer's
+    /// balance until the publisher releases the game, providing the CID of the game. At release
+    /// the held payment is settled and the game is added to the buyer's collection.
+    PreOrder {
+        /// The price of the game
+        price: Currency,
+        /// The asset the price is paid in
+        asset: PaymentAsset<AssetId>,
     },
 }
 
-impl<Currency: Zero> Distribution<Currency> {
-    pub fn is_valid(&self) -> bool {
+impl<Currency: Zero, AssetId> Distribution<Currency, AssetId> {
+    /// Checks the distribution of the game. The CID of the game must be allowed by `cid_policy`.
+    pub fn is_valid(&self, cid_policy: &CidPolicy) -> bool {
+        match self {
+            Distribution::Free { cid } => validate::is_cid_with(cid, cid_policy),
+            Distribution::Instant { price, cid, .. } => {
+                !price.is_zero() && validate::is_cid_with(cid, cid_policy)
+            },
+            Distribution::Individual { price, .. } => !price.is_zero(),
+            Distribution::PreOrder { price, .. } => !price.is_zero(),
+        }
+    }
+}
+
+impl<Currency: Clone, AssetId: Clone> Distribution<Currency, AssetId> {
+    /// Returns the price of the game and the asset it is paid in, if the game is not free.
+    pub fn price(&self) -> Option<(Currency, PaymentAsset<AssetId>)> {
+        match self {
+            Distribution::Free { .. } => None,
+            Distribution::Instant { price, asset, .. } |
+            Distribution::Individual { price, asset } |
+            Distribution::PreOrder { price, asset } => Some((price.clone(), asset.clone())),
+        }
+    }
+
+    /// Returns the CID of the game, if it is distributed through IPFS.
+    pub fn cid(&self) -> Option<&Cid> {
+        match self {
+            Distribution::Free { cid } | Distribution::Instant { cid, .. } => Some(cid),
+            Distribution::Individual { .. } | Distribution::PreOrder { .. } => None,
+        }
+    }
+
+    /// Returns the kind of the distribution.
+    pub fn kind(&self) -> DistributionKind {
         match self {
-            Distribution::Free { cid } => validate::is_cid(cid),
-            Distribution::Instant { price, cid } => !price.is_zero() && validate::is_cid(cid),
-            Distribution::Individual { price } => !price.is_zero(),
+            Distribution::Free { .. } => DistributionKind::Free,
+            Distribution::Instant { .. } => DistributionKind::Instant,
+            Distribution::Individual { .. } => DistributionKind::Individual,
+            Distribution::PreOrder { .. } => DistributionKind::PreOrder,
         }
     }
+
+    /// Replaces the price of the game. Returns `false` if the game is free.
+    pub fn set_price(&mut self, new_price: Currency) -> bool {
+        match self {
+            Distribution::Free { .. } => false,
+            Distribution::Instant { price, .. } |
+            Distribution::Individual { price, .. } |
+            Distribution::PreOrder { price, .. } => {
+                *price = new_price;
+                true
+            },
+        }
+    }
+}
+
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub enum DistributionKind {
+    /// The game is distributed free of charge
+    Free,
+    /// The game is purchased instantly
+    Instant,
+    /// The game is delivered by the publisher once ordered
+    Individual,
+    /// The game is sold before its release
+    PreOrder,
+}
+
+#[derive(
+    Clone, Eq, PartialEq, Debug, Encode, Decode, DecodeWithMemTracking, MaxEncodedLen, TypeInfo,
+)]
+pub struct BuildManifest {
+    /// The CID of the build archive
+    pub cid: Cid,
+    /// The size of the build archive, in bytes
+    pub size: u64,
+    /// The BLAKE3 digest of the build archive
+    pub digest: BuildDigest,
+}
+
+impl BuildManifest {
+    /// Checks that the manifest describes a non-empty build of the game distributed under the
+    /// given CID.
+    pub fn is_valid_for(&self, cid: &Cid) -> bool {
+        self.cid == *cid && self.size > 0
+    }
+}
+
+#[derive(
+    Clone, Eq, PartialEq, Debug, Encode, Decode, DecodeWithMemTracking, MaxEncodedLen, TypeInfo,
+)]
+pub struct Discount<BlockNumber> {
+    /// The percentage taken off the price of the game
+    pub percent: Percent,
+    /// The block at which the discount ends
+    pub ends_at: BlockNumber,
+}
+
+impl<BlockNumber: PartialOrd> Discount<BlockNumber> {
+    /// A discount takes off part of the price, never the whole price.
+    pub fn is_valid(&self) -> bool {
+        !self.percent.is_zero() && self.percent < Percent::from_percent(100)
+    }
+
+    pub fn is_active_at(&self, at: &BlockNumber) -> bool {
+        at < &self.ends_at
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Encode, Decode, TypeInfo)]
+pub struct WishlistEntry<AccountId, Currency, AssetId> {
+    /// The publisher of the game
+    pub publisher: AccountId,
+    /// The id of the game
+    pub game_id: GameId,
+    /// The price currently charged for the game and the asset it is paid in, or `None` if the
+    /// game is free
+    pub price: Option<(Currency, PaymentAsset<AssetId>)>,
+    /// The discount currently applied to the price, if any
+    pub discount: Option<Percent>,
+}
+
+/// The minimum age a game is suitable for. The variants are ordered from the least to the most
+/// restrictive rating, unrated games are treated as the most restrictive ones.
+#[derive(
+    Clone,
+    Copy,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Debug,
+    Default,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    MaxEncodedLen,
+    TypeInfo,
+)]
+pub enum AgeRating {
+    /// Suitable for all ages
+    Age3,
+    /// Suitable for ages 7 and over
+    Age7,
+    /// Suitable for ages 12 and over
+    Age12,
+    /// Suitable for ages 16 and over
+    Age16,
+    /// Suitable for adults only
+    Age18,
+    /// The game has not been rated
+    #[default]
+    Unrated,
+}
+
+/// The set of content descriptors explaining the age rating of a game.
+#[derive(
+    Clone,
+    Copy,
+    Eq,
+    PartialEq,
+    Debug,
+    Default,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    MaxEncodedLen,
+    TypeInfo,
+)]
+pub struct ContentDescriptors(pub u16);
+
+impl ContentDescriptors {
+    pub const VIOLENCE: Self = Self(1 << 0);
+    pub const BAD_LANGUAGE: Self = Self(1 << 1);
+    pub const FEAR: Self = Self(1 << 2);
+    pub const SEXUAL_CONTENT: Self = Self(1 << 3);
+    pub const DRUGS: Self = Self(1 << 4);
+    pub const DISCRIMINATION: Self = Self(1 << 5);
+    pub const GAMBLING: Self = Self(1 << 6);
+    pub const IN_GAME_PURCHASES: Self = Self(1 << 7);
+
+    /// All the known descriptors.
+    pub const ALL: Self = Self((1 << 8) - 1);
+
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    pub fn intersects(&self, other: Self) -> bool {
+        self.0 & other.0 != 0
+    }
+
+    /// Checks that only known descriptors are set.
+    pub fn is_valid(&self) -> bool {
+        Self::ALL.contains(*self)
+    }
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Eq,
+    PartialEq,
+    Debug,
+    Default,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    MaxEncodedLen,
+    TypeInfo,
+)]
+pub struct ContentRating {
+    /// The minimum age the game is suitable for
+    pub age: AgeRating,
+    /// The content descriptors explaining the rating
+    pub descriptors: ContentDescriptors,
+}
+
+impl ContentRating {
+    /// Unrated games cannot have content descriptors.
+    pub fn is_valid(&self) -> bool {
+        self.descriptors.is_valid() &&
+            (self.age != AgeRating::Unrated || self.descriptors.is_empty())
+    }
+
+    /// Checks that the game is suitable for the given age rating and has none of the excluded
+    /// content descriptors.
+    pub fn is_allowed(&self, max_age: AgeRating, excluded: ContentDescriptors) -> bool {
+        self.age <= max_age && !self.descriptors.intersects(excluded)
+    }
 }
 
 #[derive(
     Clone, Eq, PartialEq, Debug, Encode, Decode, DecodeWithMemTracking, MaxEncodedLen, TypeInfo,
 )]
-#[scale_info(skip_type_params(Currency))]
-pub struct GameDetails<Currency> {
+#[scale_info(skip_type_params(Currency, AssetId))]
+pub struct GameDetails<Currency, AssetId> {
     /// The name of the game
     pub name: Name,
     /// The tags of the game
     pub tags: GameTags,
     /// The way the game is distributed
-    pub distribution: Distribution<Currency>,
+    pub distribution: Distribution<Currency, AssetId>,
+    /// The prices overriding the distribution price for buyers from the given regions, sorted by
+    /// region
+    pub regional_prices: RegionalPrices<Currency>,
+    /// The age rating and content descriptors of the game
+    pub rating: ContentRating,
 }
 
-impl<Currency: Zero> GameDetails<Currency> {
-    pub fn is_valid<V: Fn(&TagId) -> bool>(&self, valid_tag: V) -> bool {
+impl<Currency: Zero, AssetId> GameDetails<Currency, AssetId> {
+    /// Checks the details of the game. The `tag_info` function returns the taxonomy of a tag, or
+    /// `None` if the tag cannot be used, and `cid_policy` restricts the CID of the game.
+    pub fn is_valid<I: Fn(&TagId) -> Option<TagInfo>>(
+        &self,
+        tag_info: I,
+        cid_policy: &CidPolicy,
+    ) -> bool {
         validate::is_non_empty_string(&self.name) &&
-            self.distribution.is_valid() &&
-            self.tags.iter().all(valid_tag)
+            self.distribution.is_valid(cid_policy) &&
+            self.tags_are_valid(tag_info) &&
+            self.regional_prices_are_valid() &&
+            self.rating.is_valid()
+    }
+
+    /// A game can have at most `MAX_TOP_LEVEL_GENRES_PER_GAME` top-level genres, and a tag with a
+    /// parent requires the game to be tagged with the parent as well.
+    fn tags_are_valid<I: Fn(&TagId) -> Option<TagInfo>>(&self, tag_info: I) -> bool {
+        let mut top_level_genres = 0;
+
+        for tag_id in self.tags.iter() {
+            let Some(info) = tag_info(tag_id) else { return false };
+            if info.category == TagCategory::TopLevelGenre {
+                top_level_genres += 1;
+            }
+            if info.parent.is_some_and(|parent| !self.tags.contains(&parent)) {
+                return false;
+            }
+        }
+
+        top_level_genres <= MAX_TOP_LEVEL_GENRES_PER_GAME
+    }
+
+    /// Regional prices are only allowed for paid distributions. The prices must be non-zero and
+    /// the regions strictly ascending, so that every region has a single price.
+    fn regional_prices_are_valid(&self) -> bool {
+        if self.regional_prices.is_empty() {
+            return true;
+        }
+
+        !matches!(self.distribution, Distribution::Free { .. }) &&
+            self.regional_prices.iter().all(|(_, price)| !price.is_zero()) &&
+            self.regional_prices.windows(2).all(|pair| pair[0].0 < pair[1].0)
+    }
+}
+
+impl<Currency: Clone, AssetId> GameDetails<Currency, AssetId> {
+    /// Returns the region tier and its price charged to a buyer from the given region, or `None`
+    /// if the distribution price applies.
+    pub fn regional_price(&self, region: Option<RegionId>) -> Option<(RegionId, Currency)> {
+        let region = region?;
+        let index = self.regional_prices.binary_search_by_key(&region, |(id, _)| *id).ok()?;
+        self.regional_prices.get(index).cloned()
     }
 }
 
 #[derive(Default, Clone, Eq, PartialEq, Debug, Encode, Decode, MaxEncodedLen, TypeInfo)]
-#[scale_info(skip_type_params(Currency))]
-pub struct OrderDetails<Currency> {
+#[scale_info(skip_type_params(Currency, AssetId))]
+pub struct OrderDetails<Currency, AssetId> {
     /// The deposit held from the buyer
     pub deposit: Currency,
+    /// The asset the deposit is paid in
+    pub asset: PaymentAsset<AssetId>,
+    /// The region tier the deposit was charged at, if any
+    pub region: Option<RegionId>,
+    /// The deposit held from the buyer for the receipt of the purchase
+    pub receipt_deposit: Currency,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Encode, Decode, MaxEncodedLen, TypeInfo)]
+#[scale_info(skip_type_params(Currency, AssetId, BlockNumber))]
+pub struct Receipt<Currency, AssetId, BlockNumber> {
+    /// The price paid by the buyer
+    pub price: Currency,
+    /// The part of the price paid to the publisher
+    pub publisher_amount: Currency,
+    /// The part of the price kept as a protocol fee, currently none
+    pub protocol_amount: Currency,
+    /// The asset the price was paid in
+    pub asset: PaymentAsset<AssetId>,
+    /// The region tier the price was charged at, if any
+    pub region: Option<RegionId>,
+    /// The distribution the game was purchased under
+    pub kind: DistributionKind,
+    /// The block at which the purchase completed
+    pub block: BlockNumber,
+    /// The deposit held from the buyer for storing the receipt
+    pub deposit: Currency,
+}
+
+#[derive(Clone, Default, Eq, PartialEq, Debug, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub struct UnitsSold {
+    /// The units distributed free of charge
+    pub free: u32,
+    /// The units purchased instantly
+    pub instant: u32,
+    /// The units delivered by the publisher for an order
+    pub individual: u32,
+    /// The units delivered for a pre-order at release
+    pub pre_order: u32,
+}
+
+impl UnitsSold {
+    /// Returns the units sold under the given distribution.
+    pub fn get(&self, kind: DistributionKind) -> u32 {
+        match kind {
+            DistributionKind::Free => self.free,
+            DistributionKind::Instant => self.instant,
+            DistributionKind::Individual => self.individual,
+            DistributionKind::PreOrder => self.pre_order,
+        }
+    }
+
+    /// Returns the units sold under all distributions.
+    pub fn total(&self) -> u32 {
+        self.free
+            .saturating_add(self.instant)
+            .saturating_add(self.individual)
+            .saturating_add(self.pre_order)
+    }
+
+    fn get_mut(&mut self, kind: DistributionKind) -> &mut u32 {
+        match kind {
+            DistributionKind::Free => &mut self.free,
+            DistributionKind::Instant => &mut self.instant,
+            DistributionKind::Individual => &mut self.individual,
+            DistributionKind::PreOrder => &mut self.pre_order,
+        }
+    }
+}
+
+#[derive(Clone, Default, Eq, PartialEq, Debug, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub struct SalesStats<Currency> {
+    /// The units sold, by distribution
+    pub units: UnitsSold,
+    /// The gross revenue of the sales
+    pub revenue: Currency,
+    /// The number of orders and pre-orders refunded to the buyers
+    pub refunds: u32,
+    /// The amount refunded to the buyers
+    pub refunded: Currency,
+    /// The number of orders and pre-orders waiting to be settled
+    pub open_orders: u32,
+}
+
+impl<Currency: Saturating + Copy> SalesStats<Currency> {
+    /// Records a completed sale at the given price.
+    pub fn record_sale(&mut self, kind: DistributionKind, price: Currency) {
+        let units = self.units.get_mut(kind);
+        *units = units.saturating_add(1);
+        self.revenue = self.revenue.saturating_add(price);
+    }
+
+    /// Records an order or pre-order waiting to be settled.
+    pub fn record_order(&mut self) {
+        self.open_orders = self.open_orders.saturating_add(1);
+    }
+
+    /// Records an open order or pre-order completed as a sale at the given price.
+    pub fn record_order_sale(&mut self, kind: DistributionKind, price: Currency) {
+        self.open_orders = self.open_orders.saturating_sub(1);
+        self.record_sale(kind, price);
+    }
+
+    /// Records an open order or pre-order refunded to the buyer.
+    pub fn record_order_refund(&mut self, price: Currency) {
+        self.open_orders = self.open_orders.saturating_sub(1);
+        self.refunds = self.refunds.saturating_add(1);
+        self.refunded = self.refunded.saturating_add(price);
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Encode, Decode, MaxEncodedLen, TypeInfo)]
+#[scale_info(skip_type_params(BlockNumber))]
+pub struct PreOrderRelease<BlockNumber> {
+    /// The block at which the game is released
+    pub at: BlockNumber,
+    /// The CID of the released game
+    pub cid: Cid,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Encode, Decode, MaxEncodedLen, TypeInfo)]
+#[scale_info(skip_type_params(BlockNumber))]
+pub struct PreOrderSchedule<BlockNumber> {
+    /// The block by which the game must be released, otherwise the pre-orders are refunded
+    pub deadline: BlockNumber,
+    /// The release set by the publisher, if any
+    pub release: Option<PreOrderRelease<BlockNumber>>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub enum PreOrderSettlement {
+    /// The game has been released, the held payments are transferred to the publisher
+    Release {
+        /// The CID of the released game
+        cid: Cid,
+    },
+    /// The pre-orders are cancelled, the held payments are released back to the buyers
+    Refund,
+}
+
+#[derive(
+    Clone, Eq, PartialEq, Debug, Encode, Decode, DecodeWithMemTracking, MaxEncodedLen, TypeInfo,
+)]
+#[scale_info(skip_type_params(Currency, AssetId, BlockNumber))]
+pub struct SubscriptionPlan<Currency, AssetId, BlockNumber> {
+    /// The name of the plan
+    pub name: Name,
+    /// The price of a single subscription period
+    pub price: Currency,
+    /// The asset the price is paid in
+    pub asset: PaymentAsset<AssetId>,
+    /// The length of a subscription period in blocks
+    pub period: BlockNumber,
+    /// The games of the publisher the plan grants access to
+    pub games: PlanGames,
+}
+
+impl<Currency: Zero, AssetId, BlockNumber: Zero> SubscriptionPlan<Currency, AssetId, BlockNumber> {
+    pub fn is_valid<V: Fn(&GameId) -> bool>(&self, valid_game: V) -> bool {
+        validate::is_non_empty_string(&self.name) &&
+            !self.price.is_zero() &&
+            !self.period.is_zero() &&
+            !self.games.is_empty() &&
+            self.games.iter().all(valid_game)
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Encode, Decode, MaxEncodedLen, TypeInfo)]
+#[scale_info(skip_type_params(BlockNumber))]
+pub struct Entitlement<BlockNumber> {
+    /// The block since which the access is granted
+    pub starts_at: BlockNumber,
+    /// The block at which the access expires
+    pub expires_at: BlockNumber,
+}
+
+impl<BlockNumber: PartialOrd> Entitlement<BlockNumber> {
+    pub fn is_active_at(&self, at: &BlockNumber) -> bool {
+        &self.starts_at <= at && at < &self.expires_at
+    }
+}
+
+#[derive(
+    Clone, Eq, PartialEq, Debug, Encode, Decode, DecodeWithMemTracking, MaxEncodedLen, TypeInfo,
+)]
+pub struct ReviewDetails {
+    /// The score given to the game, from 1 to `MAX_REVIEW_SCORE`
+    pub score: ReviewScore,
+    /// The CID of the review text
+    pub cid: Cid,
+}
+
+impl ReviewDetails {
+    pub fn is_valid(&self) -> bool {
+        (1..=MAX_REVIEW_SCORE).contains(&self.score) && validate::is_cid(&self.cid)
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub struct Review<Currency> {
+    /// The score and text of the review
+    pub details: ReviewDetails,
+    /// The deposit held from the reviewer, returned once the review is deleted
+    pub deposit: Currency,
+    /// The number of accounts which reported the review
+    pub reports: u32,
+    /// Whether the review has been hidden by a moderator
+    pub hidden: bool,
+}
+
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Default, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub struct ReviewSummary {
+    /// The number of reviews of the game
+    pub count: u32,
+    /// The sum of the scores of all the reviews
+    pub total_score: u64,
+}
+
+impl ReviewSummary {
+    /// Counts a new review with the given score.
+    pub fn add(&mut self, score: ReviewScore) {
+        self.count = self.count.saturating_add(1);
+        self.total_score = self.total_score.saturating_add(score.into());
+    }
+
+    /// Discounts a removed review with the given score.
+    pub fn remove(&mut self, score: ReviewScore) {
+        self.count = self.count.saturating_sub(1);
+        self.total_score = self.total_score.saturating_sub(score.into());
+    }
+
+    /// Returns the average score multiplied by 100, or `None` if the game has no reviews.
+    pub fn average_score_percent(&self) -> Option<u64> {
+        self.total_score.saturating_mul(100).checked_div(self.count.into())
+    }
 }
 
 #[cfg(test)]
@@ -144,11 +803,14 @@ mod tests {
             tags: bounded_vec(&[1, 2, 3]),
             distribution: Distribution::Instant {
                 price: 1234,
+                asset: PaymentAsset::<u32>::Native,
                 cid: bounded_vec(b"QmYwAPJzv5CZsnAztbCxjRMoa6zFzFG8pGzLFZxojtL8MX"),
             },
+            regional_prices: Default::default(),
+            rating: Default::default(),
         };
 
-        assert!(details.is_valid(|_| true));
+        assert!(details.is_valid(|_| Some(TagInfo::default()), &CidPolicy::PINNING));
     }
 
     #[test]
@@ -158,11 +820,14 @@ mod tests {
             tags: bounded_vec(&[1, 2, 3]),
             distribution: Distribution::Instant {
                 price: 1234,
+                asset: PaymentAsset::<u32>::Native,
                 cid: bounded_vec(b"QmYwAPJzv5CZsnAztbCxjRMoa6zFzFG8pGzLFZxojtL8MX"),
             },
+            regional_prices: Default::default(),
+            rating: Default::default(),
         };
 
-        assert!(!details.is_valid(|_| true));
+        assert!(!details.is_valid(|_| Some(TagInfo::default()), &CidPolicy::PINNING));
     }
 
     #[test]
@@ -172,12 +837,15 @@ mod tests {
             tags: bounded_vec(&[]),
             distribution: Distribution::Instant {
                 price: 1234,
+                asset: PaymentAsset::<u32>::Native,
                 cid: bounded_vec(b"QmYwAPJzv5CZsnAztbCxjRMoa6zFzFG8pGzLFZxojtL8MX"),
             },
+            regional_prices: Default::default(),
+            rating: Default::default(),
         };
 
         // empty tags are valid
-        assert!(details.is_valid(|_| true));
+        assert!(details.is_valid(|_| Some(TagInfo::default()), &CidPolicy::PINNING));
     }
 
     #[test]
@@ -187,11 +855,14 @@ mod tests {
             tags: bounded_vec(&[1, 2, 3]),
             distribution: Distribution::Instant {
                 price: 1234,
+                asset: PaymentAsset::<u32>::Native,
                 cid: bounded_vec(b"QmYwAPJzv5CZsnAztbCxjRMoa6zFzFG8pGzLFZxojtL8MX"),
             },
+            regional_prices: Default::default(),
+            rating: Default::default(),
         };
 
-        assert!(!details.is_valid(|_| false));
+        assert!(!details.is_valid(|_| None, &CidPolicy::PINNING));
     }
 
     #[test]
@@ -201,10 +872,292 @@ mod tests {
             tags: bounded_vec(&[1, 2, 3]),
             distribution: Distribution::Instant {
                 price: 0,
+                asset: PaymentAsset::<u32>::Native,
+                cid: bounded_vec(b"QmYwAPJzv5CZsnAztbCxjRMoa6zFzFG8pGzLFZxojtL8MX"),
+            },
+            regional_prices: Default::default(),
+            rating: Default::default(),
+        };
+
+        assert!(!details.is_valid(|_| Some(TagInfo::default()), &CidPolicy::PINNING));
+    }
+
+    #[test]
+    fn test_game_details_cid_not_pinnable() {
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: bounded_vec(&[1, 2, 3]),
+            distribution: Distribution::Free {
+                cid: bounded_vec(b"bafyreigh2akiscaildcqabsyg3dfr6chu3fgpregiymsck7e7aqa4s52zy"),
+            },
+            regional_prices: Default::default(),
+            rating: Default::default(),
+        };
+
+        assert!(!details.is_valid(|_| Some(TagInfo::default()), &CidPolicy::PINNING));
+
+        let policy = CidPolicy { codecs: &[0x71], ..CidPolicy::PINNING };
+        assert!(details.is_valid(|_| Some(TagInfo::default()), &policy));
+    }
+
+    #[test]
+    fn test_game_details_tag_taxonomy() {
+        let tag_info = |tag_id: &TagId| match tag_id {
+            1..=4 => Some(TagInfo { category: TagCategory::TopLevelGenre, parent: None }),
+            10 => Some(TagInfo { category: TagCategory::Genre, parent: Some(1) }),
+            20 => Some(TagInfo { category: TagCategory::SubGenre, parent: Some(10) }),
+            _ => Some(TagInfo::default()),
+        };
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: bounded_vec(&[1, 10, 20, 30]),
+            distribution: Distribution::Individual {
+                price: 1234,
+                asset: PaymentAsset::<u32>::Native,
+            },
+            regional_prices: Default::default(),
+            rating: Default::default(),
+        };
+
+        assert!(details.is_valid(tag_info, &CidPolicy::PINNING));
+        assert!(GameDetails { tags: bounded_vec(&[1, 2, 3]), ..details.clone() }
+            .is_valid(tag_info, &CidPolicy::PINNING));
+        assert!(!GameDetails { tags: bounded_vec(&[1, 2, 3, 4]), ..details.clone() }
+            .is_valid(tag_info, &CidPolicy::PINNING));
+        assert!(!GameDetails { tags: bounded_vec(&[1, 20]), ..details.clone() }
+            .is_valid(tag_info, &CidPolicy::PINNING));
+        assert!(!GameDetails { tags: bounded_vec(&[10, 20]), ..details }
+            .is_valid(tag_info, &CidPolicy::PINNING));
+    }
+
+    #[test]
+    fn test_tag_category_parent_category() {
+        assert_eq!(TagCategory::TopLevelGenre.parent_category(), None);
+        assert_eq!(TagCategory::Genre.parent_category(), Some(TagCategory::TopLevelGenre));
+        assert_eq!(TagCategory::SubGenre.parent_category(), Some(TagCategory::Genre));
+        assert_eq!(TagCategory::Theme.parent_category(), None);
+    }
+
+    #[test]
+    fn test_game_details_regional_prices() {
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: bounded_vec(&[]),
+            distribution: Distribution::Individual {
+                price: 1234,
+                asset: PaymentAsset::<u32>::Native,
+            },
+            regional_prices: bounded_vec(&[(1, 500), (4, 800)]),
+            rating: Default::default(),
+        };
+
+        assert!(details.is_valid(|_| Some(TagInfo::default()), &CidPolicy::PINNING));
+        assert_eq!(details.regional_price(Some(1)), Some((1, 500)));
+        assert_eq!(details.regional_price(Some(4)), Some((4, 800)));
+        assert_eq!(details.regional_price(Some(2)), None);
+        assert_eq!(details.regional_price(None), None);
+
+        let zero_price = GameDetails { regional_prices: bounded_vec(&[(1, 0)]), ..details.clone() };
+        assert!(!zero_price.is_valid(|_| Some(TagInfo::default()), &CidPolicy::PINNING));
+
+        let unsorted =
+            GameDetails { regional_prices: bounded_vec(&[(4, 800), (1, 500)]), ..details.clone() };
+        assert!(!unsorted.is_valid(|_| Some(TagInfo::default()), &CidPolicy::PINNING));
+
+        let duplicated =
+            GameDetails { regional_prices: bounded_vec(&[(1, 500), (1, 800)]), ..details.clone() };
+        assert!(!duplicated.is_valid(|_| Some(TagInfo::default()), &CidPolicy::PINNING));
+
+        let free = GameDetails {
+            distribution: Distribution::Free {
                 cid: bounded_vec(b"QmYwAPJzv5CZsnAztbCxjRMoa6zFzFG8pGzLFZxojtL8MX"),
             },
+            ..details
+        };
+        assert!(!free.is_valid(|_| Some(TagInfo::default()), &CidPolicy::PINNING));
+    }
+
+    #[test]
+    fn test_content_rating_is_valid() {
+        let descriptors = ContentDescriptors::VIOLENCE.union(ContentDescriptors::FEAR);
+
+        assert!(ContentRating::default().is_valid());
+        assert!(ContentRating { age: AgeRating::Age16, descriptors }.is_valid());
+        assert!(!ContentRating { age: AgeRating::Unrated, descriptors }.is_valid());
+        assert!(!ContentRating { age: AgeRating::Age16, descriptors: ContentDescriptors(1 << 15) }
+            .is_valid());
+    }
+
+    #[test]
+    fn test_content_rating_is_allowed() {
+        let rating = ContentRating {
+            age: AgeRating::Age12,
+            descriptors: ContentDescriptors::VIOLENCE.union(ContentDescriptors::IN_GAME_PURCHASES),
+        };
+
+        assert!(rating.is_allowed(AgeRating::Age12, ContentDescriptors::empty()));
+        assert!(rating.is_allowed(AgeRating::Age18, ContentDescriptors::GAMBLING));
+        assert!(!rating.is_allowed(AgeRating::Age7, ContentDescriptors::empty()));
+        assert!(!rating.is_allowed(AgeRating::Age18, ContentDescriptors::IN_GAME_PURCHASES));
+        assert!(!ContentRating::default().is_allowed(AgeRating::Age18, ContentDescriptors::empty()));
+        assert!(
+            ContentRating::default().is_allowed(AgeRating::Unrated, ContentDescriptors::empty())
+        );
+    }
+
+    #[test]
+    fn test_distribution_pre_order_is_valid() {
+        assert!(Distribution::PreOrder { price: 1234, asset: PaymentAsset::<u32>::Native }
+            .is_valid(&CidPolicy::PINNING));
+        assert!(!Distribution::PreOrder { price: 0, asset: PaymentAsset::<u32>::Native }
+            .is_valid(&CidPolicy::PINNING));
+    }
+
+    #[test]
+    fn test_distribution_price() {
+        let cid: Cid = bounded_vec(b"QmYwAPJzv5CZsnAztbCxjRMoa6zFzFG8pGzLFZxojtL8MX");
+
+        assert_eq!(Distribution::<u64, u32>::Free { cid: cid.clone() }.price(), None);
+        assert_eq!(
+            Distribution::Instant { price: 1234u64, asset: PaymentAsset::Asset(7u32), cid }.price(),
+            Some((1234, PaymentAsset::Asset(7)))
+        );
+        assert_eq!(
+            Distribution::Individual { price: 1234u64, asset: PaymentAsset::<u32>::Native }.price(),
+            Some((1234, PaymentAsset::Native))
+        );
+    }
+
+    #[test]
+    fn test_distribution_set_price() {
+        let cid: Cid = bounded_vec(b"QmYwAPJzv5CZsnAztbCxjRMoa6zFzFG8pGzLFZxojtL8MX");
+
+        let mut free = Distribution::<u64, u32>::Free { cid };
+        assert!(!free.set_price(1234));
+        assert_eq!(free.price(), None);
+
+        let mut individual =
+            Distribution::Individual { price: 1234u64, asset: PaymentAsset::<u32>::Native };
+        assert!(individual.set_price(1000));
+        assert_eq!(individual.price(), Some((1000, PaymentAsset::Native)));
+    }
+
+    #[test]
+    fn test_sales_stats() {
+        let mut stats = SalesStats::<u64>::default();
+
+        stats.record_sale(DistributionKind::Free, 0);
+        stats.record_sale(DistributionKind::Instant, 100);
+        stats.record_order();
+        stats.record_order();
+        assert_eq!(stats.open_orders, 2);
+
+        stats.record_order_sale(DistributionKind::Individual, 50);
+        stats.record_order_refund(30);
+        assert_eq!(
+            stats,
+            SalesStats {
+                units: UnitsSold { free: 1, instant: 1, individual: 1, pre_order: 0 },
+                revenue: 150,
+                refunds: 1,
+                refunded: 30,
+                open_orders: 0,
+            }
+        );
+        assert_eq!(stats.units.get(DistributionKind::Instant), 1);
+        assert_eq!(stats.units.total(), 3);
+    }
+
+    #[test]
+    fn test_distribution_kind() {
+        let cid: Cid = bounded_vec(b"QmYwAPJzv5CZsnAztbCxjRMoa6zFzFG8pGzLFZxojtL8MX");
+
+        assert_eq!(Distribution::<u64, u32>::Free { cid }.kind(), DistributionKind::Free);
+        assert_eq!(
+            Distribution::<u64, u32>::PreOrder { price: 1234, asset: PaymentAsset::Native }.kind(),
+            DistributionKind::PreOrder
+        );
+    }
+
+    #[test]
+    fn test_distribution_cid() {
+        let cid: Cid = bounded_vec(b"QmYwAPJzv5CZsnAztbCxjRMoa6zFzFG8pGzLFZxojtL8MX");
+
+        assert_eq!(Distribution::<u64, u32>::Free { cid: cid.clone() }.cid(), Some(&cid));
+        assert_eq!(
+            Distribution::<u64, u32>::Individual { price: 1234, asset: PaymentAsset::Native }.cid(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_discount() {
+        let discount = Discount { percent: Percent::from_percent(25), ends_at: 20 };
+
+        assert!(discount.is_valid());
+        assert!(!Discount { percent: Percent::zero(), ends_at: 20 }.is_valid());
+        assert!(!Discount { percent: Percent::from_percent(100), ends_at: 20 }.is_valid());
+        assert!(discount.is_active_at(&19));
+        assert!(!discount.is_active_at(&20));
+    }
+
+    #[test]
+    fn test_subscription_plan_is_valid() {
+        let plan = SubscriptionPlan {
+            name: bounded_vec(b"Example Plan"),
+            price: 1234,
+            asset: PaymentAsset::<u32>::Native,
+            period: 100,
+            games: bounded_vec(&[1, 2, 3]),
         };
 
-        assert!(!details.is_valid(|_| true));
+        assert!(plan.is_valid(|_| true));
+        assert!(!plan.is_valid(|game_id| *game_id != 2));
+        assert!(!SubscriptionPlan { price: 0, ..plan.clone() }.is_valid(|_| true));
+        assert!(!SubscriptionPlan { period: 0, ..plan.clone() }.is_valid(|_| true));
+        assert!(!SubscriptionPlan { games: bounded_vec(&[]), ..plan }.is_valid(|_| true));
+    }
+
+    #[test]
+    fn test_entitlement_is_active_at() {
+        let entitlement = Entitlement { starts_at: 10, expires_at: 20 };
+
+        assert!(!entitlement.is_active_at(&9));
+        assert!(entitlement.is_active_at(&10));
+        assert!(entitlement.is_active_at(&19));
+        assert!(!entitlement.is_active_at(&20));
+    }
+
+    #[test]
+    fn test_review_details_is_valid() {
+        let details = ReviewDetails {
+            score: 7,
+            cid: bounded_vec(b"bafkreihdwdcefgh4dqkjv67uzcmw7ojee6xedzdetojuzjevtenxquvyku"),
+        };
+
+        assert!(details.is_valid());
+        assert!(ReviewDetails { score: 1, ..details.clone() }.is_valid());
+        assert!(ReviewDetails { score: MAX_REVIEW_SCORE, ..details.clone() }.is_valid());
+        assert!(!ReviewDetails { score: 0, ..details.clone() }.is_valid());
+        assert!(!ReviewDetails { score: MAX_REVIEW_SCORE + 1, ..details.clone() }.is_valid());
+        assert!(!ReviewDetails { cid: bounded_vec(b"Qm123"), ..details }.is_valid());
+    }
+
+    #[test]
+    fn test_review_summary() {
+        let mut summary = ReviewSummary::default();
+        assert_eq!(summary.average_score_percent(), None);
+
+        summary.add(7);
+        summary.add(8);
+        assert_eq!(summary, ReviewSummary { count: 2, total_score: 15 });
+        assert_eq!(summary.average_score_percent(), Some(750));
+
+        summary.remove(7);
+        assert_eq!(summary, ReviewSummary { count: 1, total_score: 8 });
+        assert_eq!(summary.average_score_percent(), Some(800));
+
+        summary.remove(8);
+        assert_eq!(summary.average_score_percent(), None);
     }
 }
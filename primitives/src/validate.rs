@@ -1,7 +1,62 @@
+use alloc::string::ToString;
 use cid::Cid;
 use core::str::FromStr;
+use frame_support::pallet_prelude::{Encode, TypeInfo};
 use url::Url;
 
+/// The multicodec of the protobuf-encoded DAG nodes, used by the UnixFS files.
+pub const CODEC_DAG_PB: u64 = 0x70;
+/// The multicodec of the raw binary blocks.
+pub const CODEC_RAW: u64 = 0x55;
+/// The multicodec of the content-addressable archives.
+pub const CODEC_CAR: u64 = 0x0202;
+
+/// The multihash code of SHA2-256.
+pub const HASH_SHA2_256: u64 = 0x12;
+/// The multihash code of BLAKE3.
+pub const HASH_BLAKE3: u64 = 0x1e;
+
+/// The profile of the CIDs accepted by [`is_cid_with`].
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Encode, TypeInfo)]
+pub struct CidPolicy<'a> {
+    /// The accepted CID versions
+    pub versions: &'a [u64],
+    /// The accepted multicodecs of the content
+    pub codecs: &'a [u64],
+    /// The accepted multihash codes, with the length of their digests
+    pub hashes: &'a [(u64, u8)],
+    /// Whether the CIDs must be in their canonical string form, which is base32 in lowercase for
+    /// the CIDs v1
+    pub canonical: bool,
+}
+
+impl CidPolicy<'static> {
+    /// The CIDs the pinning service can serve.
+    pub const PINNING: Self = Self {
+        versions: &[0, 1],
+        codecs: &[CODEC_DAG_PB, CODEC_RAW, CODEC_CAR],
+        hashes: &[(HASH_SHA2_256, 32), (HASH_BLAKE3, 32)],
+        canonical: true,
+    };
+}
+
+impl Default for CidPolicy<'static> {
+    fn default() -> Self {
+        Self::PINNING
+    }
+}
+
+impl CidPolicy<'_> {
+    /// Checks that the CID is allowed by the policy.
+    pub fn allows(&self, cid: &Cid) -> bool {
+        let hash = cid.hash();
+
+        self.versions.contains(&cid.version().into()) &&
+            self.codecs.contains(&cid.codec()) &&
+            self.hashes.contains(&(hash.code(), hash.size()))
+    }
+}
+
 pub fn is_cid(cid: &[u8]) -> bool {
     match core::str::from_utf8(cid) {
         Ok(cid) => Cid::from_str(cid).is_ok(),
@@ -9,6 +64,14 @@ pub fn is_cid(cid: &[u8]) -> bool {
     }
 }
 
+/// Checks that the CID is parseable and allowed by the policy.
+pub fn is_cid_with(cid: &[u8], policy: &CidPolicy) -> bool {
+    let Ok(string) = core::str::from_utf8(cid) else { return false };
+    let Ok(parsed) = Cid::from_str(string) else { return false };
+
+    policy.allows(&parsed) && (!policy.canonical || parsed.to_string() == string)
+}
+
 pub fn is_string(string: &[u8]) -> bool {
     core::str::from_utf8(string).is_ok()
 }
@@ -24,6 +87,19 @@ pub fn is_url(url: &[u8]) -> bool {
     }
 }
 
+/// Checks that the locale is a language code, optionally followed by region or script subtags,
+/// such as "en", "pt-BR" or "zh-Hant".
+pub fn is_locale(locale: &[u8]) -> bool {
+    let mut subtags = locale.split(|c| *c == b'-');
+    let language = subtags.next().unwrap_or_default();
+
+    (2..=3).contains(&language.len()) &&
+        language.iter().all(u8::is_ascii_alphabetic) &&
+        subtags.all(|subtag| {
+            (1..=8).contains(&subtag.len()) && subtag.iter().all(u8::is_ascii_alphanumeric)
+        })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -42,6 +118,51 @@ mod tests {
         assert!(!is_cid(b"NotARealCID123"));
     }
 
+    #[test]
+    fn test_is_cid_policy() {
+        // CAR with BLAKE3
+        assert!(is_cid_with(
+            b"bagbaihragmzc2vwmec24nt3qaaozuk5wh43n5izfer6chasuzd7z4iuwnhmq",
+            &CidPolicy::PINNING
+        ));
+        // DAG-CBOR
+        assert!(!is_cid_with(
+            b"bafyreigh2akiscaildcqabsyg3dfr6chu3fgpregiymsck7e7aqa4s52zy",
+            &CidPolicy::PINNING
+        ));
+        // SHA2-512
+        assert!(!is_cid_with(
+            b"bafkrgqe3nmefcemf5kx23jw4mpkux4kswm4umtvwmc2j6cf3ftvkv7flwpfrwe7nvlhyzvvn7wtfhec5nkkwxv3hvzjivkw6yv3f3og5edhcldy",
+            &CidPolicy::PINNING
+        ));
+        // Uppercase base32 and base58
+        assert!(!is_cid_with(
+            b"BAFKREIHDWDCEFGH4DQKJV67UZCMW7OJEE6XEDZDETOJUZJEVTENXQUVYKU",
+            &CidPolicy::PINNING
+        ));
+        assert!(!is_cid_with(
+            b"zb2rhe5P4gXftAwvA4eXQ5HJwsER2owDyS9sKaQRRVQPn93bA",
+            &CidPolicy::PINNING
+        ));
+
+        let policy = CidPolicy { versions: &[1], canonical: false, ..CidPolicy::PINNING };
+        assert!(!is_cid_with(b"QmYwAPJzv5CZsnAztbCxjRMoa6zFzFG8pGzLFZxojtL8MX", &policy));
+        assert!(is_cid_with(
+            b"BAFKREIHDWDCEFGH4DQKJV67UZCMW7OJEE6XEDZDETOJUZJEVTENXQUVYKU",
+            &policy
+        ));
+
+        let policy = CidPolicy { codecs: &[CODEC_DAG_PB], ..CidPolicy::PINNING };
+        assert!(is_cid_with(
+            b"bafybeigdyrzt3whh4p5fy7uj5zd7qvmdtg7okjqcyawh5hj7sgl2xylz4u",
+            &policy
+        ));
+        assert!(!is_cid_with(
+            b"bafkreihdwdcefgh4dqkjv67uzcmw7ojee6xedzdetojuzjevtenxquvyku",
+            &policy
+        ));
+    }
+
     #[test]
     fn test_is_string() {
         assert!(is_string(b"hello"));
@@ -62,6 +183,23 @@ mod tests {
         assert!(!is_non_empty_string(b""));
     }
 
+    #[test]
+    fn test_is_locale() {
+        assert!(is_locale(b"en"));
+        assert!(is_locale(b"fil"));
+        assert!(is_locale(b"pt-BR"));
+        assert!(is_locale(b"zh-Hant-TW"));
+        assert!(is_locale(b"es-419"));
+
+        assert!(!is_locale(b""));
+        assert!(!is_locale(b"e"));
+        assert!(!is_locale(b"engl"));
+        assert!(!is_locale(b"en-"));
+        assert!(!is_locale(b"en_US"));
+        assert!(!is_locale(b"12-US"));
+        assert!(!is_locale(b"en-toolongsubtag"));
+    }
+
     #[test]
     fn test_is_url() {
         assert!(is_url(b"https://example.com"));
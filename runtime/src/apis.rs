@@ -4,6 +4,14 @@ use frame_support::{
     genesis_builder_helper::{build_state, get_preset},
     weights::Weight,
 };
+use liganite_primitives::{
+    ownership::OwnershipManager,
+    types::{
+        AgeRating, BuildManifest, ContentDescriptors, GameDetails, GameId, Locale, OrderDetails,
+        PaymentAsset, PublisherDetails, Receipt, SalesStats, Tag, TagCategory, TagId, TagProposal,
+        TagProposalId, WishlistEntry,
+    },
+};
 use pallet_grandpa::AuthorityId as GrandpaId;
 use sp_api::impl_runtime_apis;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
@@ -17,8 +25,9 @@ use sp_version::RuntimeVersion;
 
 // Local module imports
 use super::{
-    AccountId, Aura, Balance, Block, Executive, Grandpa, InherentDataExt, Nonce, Runtime,
-    RuntimeCall, RuntimeGenesisConfig, SessionKeys, System, TransactionPayment, VERSION,
+    AccountId, AssetId, Aura, Balance, Block, BlockNumber, Executive, Games, Grandpa,
+    InherentDataExt, Nonce, Publish, Runtime, RuntimeCall, RuntimeGenesisConfig, SessionKeys,
+    System, TransactionPayment, VERSION,
 };
 
 impl_runtime_apis! {
@@ -195,6 +204,120 @@ impl_runtime_apis! {
         }
     }
 
+    impl liganite_games_runtime_api::GamesApi<Block, AccountId, Balance, AssetId, BlockNumber> for Runtime {
+        fn game_details(publisher: AccountId, game_id: GameId) -> Option<GameDetails<Balance, AssetId>> {
+            Games::game_details(&publisher, game_id)
+        }
+
+        fn games_by_publisher(
+            publisher: AccountId,
+            start_after: Option<GameId>,
+            limit: u32,
+        ) -> Vec<(GameId, GameDetails<Balance, AssetId>)> {
+            Games::games_by_publisher(&publisher, start_after, limit)
+        }
+
+        fn owns_game(who: AccountId, publisher: AccountId, game_id: GameId) -> bool {
+            Games::is_owner(&who, &publisher, game_id)
+        }
+
+        fn has_access(who: AccountId, publisher: AccountId, game_id: GameId, at: BlockNumber) -> bool {
+            Games::has_access(&who, &(publisher, game_id), at)
+        }
+
+        fn owned_games(who: AccountId) -> Vec<(AccountId, GameId)> {
+            Games::owned_games(&who)
+        }
+
+        fn pending_orders(publisher: AccountId) -> Vec<(GameId, AccountId, OrderDetails<Balance, AssetId>)> {
+            Games::pending_orders(&publisher)
+        }
+
+        fn game_build(publisher: AccountId, game_id: GameId) -> Option<BuildManifest> {
+            Games::game_build(&publisher, game_id)
+        }
+
+        fn game_sales(
+            publisher: AccountId,
+            game_id: GameId,
+        ) -> Vec<(PaymentAsset<AssetId>, SalesStats<Balance>)> {
+            Games::game_sales(&publisher, game_id)
+        }
+
+        fn publisher_sales(
+            publisher: AccountId,
+        ) -> Vec<(PaymentAsset<AssetId>, SalesStats<Balance>)> {
+            Games::publisher_sales(&publisher)
+        }
+
+        fn receipts_by_buyer(
+            buyer: AccountId,
+        ) -> Vec<((AccountId, GameId), Receipt<Balance, AssetId, BlockNumber>)> {
+            Games::receipts_by_buyer(&buyer)
+        }
+
+        fn receipts_by_publisher(
+            publisher: AccountId,
+            start_after: Option<(GameId, AccountId)>,
+            limit: u32,
+        ) -> Vec<(GameId, AccountId, Receipt<Balance, AssetId, BlockNumber>)> {
+            Games::receipts_by_publisher(&publisher, start_after, limit)
+        }
+
+        fn games_by_rating(
+            max_age: AgeRating,
+            excluded: ContentDescriptors,
+            start_after: Option<(AccountId, GameId)>,
+            limit: u32,
+        ) -> Vec<(AccountId, GameId)> {
+            Games::games_by_rating(max_age, excluded, start_after, limit)
+        }
+
+        fn games_by_tags(
+            tags: Vec<TagId>,
+            start_after: Option<(AccountId, GameId)>,
+            limit: u32,
+        ) -> Vec<(AccountId, GameId)> {
+            Games::games_by_tags(&tags, start_after, limit)
+        }
+
+        fn search_by_tags(
+            tags: Vec<TagId>,
+            start_after: Option<(AccountId, GameId)>,
+            limit: u32,
+        ) -> Vec<((AccountId, GameId), GameDetails<Balance, AssetId>)> {
+            Games::search_by_tags(&tags, start_after, limit)
+        }
+
+        fn top_tags(publisher: AccountId, game_id: GameId, limit: u32) -> Vec<(TagId, u32)> {
+            Games::top_tags(&(publisher, game_id), limit)
+        }
+
+        fn tags_by_category(category: TagCategory) -> Vec<(TagId, Tag)> {
+            Games::tags_by_category(category)
+        }
+
+        fn tags_for_locale(locale: Locale) -> Vec<(TagId, Tag)> {
+            Games::tags_for_locale(&locale)
+        }
+
+        fn tag_proposals() -> Vec<(TagProposalId, TagProposal<AccountId, Balance, BlockNumber>)> {
+            Games::tag_proposals()
+        }
+
+        fn wishlist(who: AccountId) -> Vec<WishlistEntry<AccountId, Balance, AssetId>> {
+            Games::wishlist(&who)
+        }
+
+        fn publisher_details(publisher: AccountId) -> Option<PublisherDetails> {
+            Publish::publisher_info(publisher)
+        }
+
+        fn publisher_deposit() -> Balance {
+            Publish::current_deposit()
+        }
+    }
+
     #[cfg(feature = "runtime-benchmarks")]
     impl frame_benchmarking::Benchmark<Block> for Runtime {
         fn benchmark_metadata(extra: bool) -> (
@@ -8,4 +8,5 @@ frame_benchmarking::define_benchmarks!(
     [pallet_sudo, Sudo]
     [liganite_publish, Publish]
     [liganite_games, Games]
+    [liganite_reviews, Reviews]
 );
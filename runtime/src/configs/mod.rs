@@ -3,25 +3,37 @@ use frame_support::{
     derive_impl,
     pallet_prelude::DispatchClass,
     parameter_types,
-    traits::{ConstBool, ConstU128, ConstU32, ConstU64, ConstU8, VariantCountOf},
+    traits::{
+        AsEnsureOriginWithArg, ConstBool, ConstU128, ConstU32, ConstU64, ConstU8, VariantCountOf,
+    },
     weights::{
         constants::{RocksDbWeight, WEIGHT_REF_TIME_PER_SECOND},
         IdentityFee, Weight,
     },
+    PalletId,
+};
+use frame_system::{
+    limits::{BlockLength, BlockWeights},
+    EnsureRoot, EnsureSigned,
 };
-use frame_system::limits::{BlockLength, BlockWeights};
+use liganite_primitives::validate::CidPolicy;
 use pallet_transaction_payment::{ConstFeeMultiplier, FungibleAdapter, Multiplier};
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
-use sp_runtime::{traits::One, Perbill};
+use sp_runtime::{
+    traits::{One, Verify},
+    transaction_validity::TransactionPriority,
+    Perbill,
+};
 use sp_version::RuntimeVersion;
 
 use crate::weights::{block_weights::BlockExecutionWeight, extrinsic_weights::ExtrinsicBaseWeight};
 
 // Local module imports
 use super::{
-    weights, AccountId, Aura, Balance, Balances, Block, BlockNumber, Hash, Nonce, PalletInfo,
-    Publish, Runtime, RuntimeCall, RuntimeEvent, RuntimeFreezeReason, RuntimeHoldReason,
-    RuntimeOrigin, RuntimeTask, System, EXISTENTIAL_DEPOSIT, SLOT_DURATION, VERSION,
+    weights, AccountId, AssetId, Assets, Aura, Balance, Balances, Block, BlockNumber, Games, Hash,
+    Nonce, PalletInfo, Publish, Runtime, RuntimeCall, RuntimeEvent, RuntimeFreezeReason,
+    RuntimeHoldReason, RuntimeOrigin, RuntimeTask, Signature, System, UncheckedExtrinsic, DAYS,
+    EXISTENTIAL_DEPOSIT, HOURS, MILLI_UNIT, SLOT_DURATION, UNIT, VERSION,
 };
 
 /// We assume that ~10% of the block weight is consumed by `on_initialize` handlers.
@@ -156,6 +168,29 @@ impl pallet_sudo::Config for Runtime {
     type WeightInfo = weights::pallet_sudo::WeightInfo<Runtime>;
 }
 
+impl pallet_assets::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Balance = Balance;
+    type RemoveItemsLimit = ConstU32<1000>;
+    type AssetId = AssetId;
+    type AssetIdParameter = scale_codec::Compact<u32>;
+    type Currency = Balances;
+    type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId>>;
+    type ForceOrigin = EnsureRoot<AccountId>;
+    type AssetDeposit = ConstU128<{ 10 * UNIT }>;
+    type AssetAccountDeposit = ConstU128<{ MILLI_UNIT }>;
+    type MetadataDepositBase = ConstU128<{ UNIT }>;
+    type MetadataDepositPerByte = ConstU128<{ MILLI_UNIT }>;
+    type ApprovalDeposit = ConstU128<{ MILLI_UNIT }>;
+    type StringLimit = ConstU32<50>;
+    type Freezer = ();
+    type Extra = ();
+    type CallbackHandle = ();
+    type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = ();
+}
+
 impl liganite_publish::Config for Runtime {
     type WeightInfo = weights::liganite_publish::WeightInfo<Runtime>;
     type RuntimeEvent = RuntimeEvent;
@@ -163,10 +198,69 @@ impl liganite_publish::Config for Runtime {
     type Currency = Balances;
 }
 
+parameter_types! {
+    pub const GamesPalletId: PalletId = PalletId(*b"lg/games");
+    pub const GamesUnsignedPriority: TransactionPriority = TransactionPriority::MAX / 2;
+    pub const GamesCidPolicy: CidPolicy<'static> = CidPolicy::PINNING;
+}
+
 impl liganite_games::Config for Runtime {
     type WeightInfo = weights::liganite_games::WeightInfo<Runtime>;
     type RuntimeEvent = RuntimeEvent;
     type RuntimeHoldReason = RuntimeHoldReason;
     type Currency = Balances;
+    type AssetId = AssetId;
+    type Assets = Assets;
+    type PalletId = GamesPalletId;
     type PublisherManager = Publish;
+    type PreOrderDeadline = ConstU32<{ 180 * DAYS }>;
+    type MaxPreOrdersScheduledPerBlock = ConstU32<64>;
+    type TagOrigin = EnsureRoot<AccountId>;
+    type TagCuratorOrigin = EnsureRoot<AccountId>;
+    type TagProposalDeposit = ConstU128<{ UNIT }>;
+    type TagProposalPeriod = ConstU32<{ 30 * DAYS }>;
+    type MaxTagProposalsExpiringPerBlock = ConstU32<64>;
+    type MaxTagVotesPerGame = ConstU32<20>;
+    type MaxWishlistSize = ConstU32<100>;
+    type ReceiptDeposit = ConstU128<{ MILLI_UNIT }>;
+    type CidPolicy = GamesCidPolicy;
+    type CidCheckInterval = ConstU32<{ HOURS }>;
+    type MaxCidChecksPerRun = ConstU32<16>;
+    type UnsignedPriority = GamesUnsignedPriority;
+    type AuthorityId = liganite_games::crypto::ReporterId;
+    type CidReporterOrigin = EnsureRoot<AccountId>;
+}
+
+impl frame_system::offchain::SigningTypes for Runtime {
+    type Public = <Signature as Verify>::Signer;
+    type Signature = Signature;
+}
+
+impl<LocalCall> frame_system::offchain::CreateTransactionBase<LocalCall> for Runtime
+where
+    RuntimeCall: From<LocalCall>,
+{
+    type Extrinsic = UncheckedExtrinsic;
+    type RuntimeCall = RuntimeCall;
+}
+
+impl<LocalCall> frame_system::offchain::CreateInherent<LocalCall> for Runtime
+where
+    RuntimeCall: From<LocalCall>,
+{
+    fn create_inherent(call: RuntimeCall) -> UncheckedExtrinsic {
+        UncheckedExtrinsic::new_bare(call)
+    }
+}
+
+impl liganite_reviews::Config for Runtime {
+    // The pallet has not been benchmarked yet, see `just bench-pallets`
+    type WeightInfo = ();
+    type RuntimeEvent = RuntimeEvent;
+    type OwnershipManager = Games;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type Currency = Balances;
+    type ModeratorOrigin = EnsureRoot<AccountId>;
+    type ReviewDeposit = ConstU128<{ 10 * MILLI_UNIT }>;
+    type MaxReportsPerReview = ConstU32<100>;
 }
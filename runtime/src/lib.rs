@@ -70,10 +70,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     //   `spec_version`, and `authoring_version` are the same between Wasm and native.
     // This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
     //   the compatible custom types.
-    spec_version: 100,
+    spec_version: 101,
     impl_version: 1,
     apis: apis::RUNTIME_API_VERSIONS,
-    transaction_version: 1,
+    transaction_version: 2,
     system_version: 1,
 };
 
@@ -123,6 +123,9 @@ pub type AccountId = <<Signature as Verify>::Signer as IdentifyAccount>::Account
 /// Balance of an account.
 pub type Balance = u128;
 
+/// Identifier of a fungible asset.
+pub type AssetId = u32;
+
 /// Index of a transaction in the chain.
 pub type Nonce = u32;
 
@@ -172,7 +175,7 @@ pub type SignedPayload = generic::SignedPayload<RuntimeCall, TxExtension>;
 ///
 /// This can be a tuple of types, each implementing `OnRuntimeUpgrade`.
 #[allow(unused_parens)]
-type Migrations = ();
+type Migrations = (liganite_games::migrations::MigrateV0ToV1<Runtime>,);
 
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
@@ -228,4 +231,10 @@ mod runtime {
 
     #[runtime::pallet_index(8)]
     pub type Games = liganite_games;
+
+    #[runtime::pallet_index(9)]
+    pub type Assets = pallet_assets;
+
+    #[runtime::pallet_index(10)]
+    pub type Reviews = liganite_reviews;
 }
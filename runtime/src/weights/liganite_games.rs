@@ -146,4 +146,87 @@ impl<T: frame_system::Config> liganite_games::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(5))
 	}
+	// The extrinsics below have not been benchmarked yet, and fall back to the default weights
+	// of the pallet until this file is regenerated.
+	fn preorder_place() -> Weight {
+		<() as liganite_games::WeightInfo>::preorder_place()
+	}
+	fn preorder_release() -> Weight {
+		<() as liganite_games::WeightInfo>::preorder_release()
+	}
+	fn preorder_cancel() -> Weight {
+		<() as liganite_games::WeightInfo>::preorder_cancel()
+	}
+	fn preorder_settle() -> Weight {
+		<() as liganite_games::WeightInfo>::preorder_settle()
+	}
+	fn preorder_claim() -> Weight {
+		<() as liganite_games::WeightInfo>::preorder_claim()
+	}
+	fn preorder_schedule(n: u32, ) -> Weight {
+		<() as liganite_games::WeightInfo>::preorder_schedule(n)
+	}
+	fn plan_add(n: u32, ) -> Weight {
+		<() as liganite_games::WeightInfo>::plan_add(n)
+	}
+	fn subscription_buy() -> Weight {
+		<() as liganite_games::WeightInfo>::subscription_buy()
+	}
+	fn tag_add() -> Weight {
+		<() as liganite_games::WeightInfo>::tag_add()
+	}
+	fn tag_rename() -> Weight {
+		<() as liganite_games::WeightInfo>::tag_rename()
+	}
+	fn tag_deprecate() -> Weight {
+		<() as liganite_games::WeightInfo>::tag_deprecate()
+	}
+	fn tag_classify() -> Weight {
+		<() as liganite_games::WeightInfo>::tag_classify()
+	}
+	fn tag_translate() -> Weight {
+		<() as liganite_games::WeightInfo>::tag_translate()
+	}
+	fn tag_propose() -> Weight {
+		<() as liganite_games::WeightInfo>::tag_propose()
+	}
+	fn tag_proposal_approve() -> Weight {
+		<() as liganite_games::WeightInfo>::tag_proposal_approve()
+	}
+	fn tag_proposal_reject() -> Weight {
+		<() as liganite_games::WeightInfo>::tag_proposal_reject()
+	}
+	fn tag_proposal_expire(n: u32, ) -> Weight {
+		<() as liganite_games::WeightInfo>::tag_proposal_expire(n)
+	}
+	fn tag_vote() -> Weight {
+		<() as liganite_games::WeightInfo>::tag_vote()
+	}
+	fn tag_vote_withdraw() -> Weight {
+		<() as liganite_games::WeightInfo>::tag_vote_withdraw()
+	}
+	fn wishlist_add() -> Weight {
+		<() as liganite_games::WeightInfo>::wishlist_add()
+	}
+	fn wishlist_remove() -> Weight {
+		<() as liganite_games::WeightInfo>::wishlist_remove()
+	}
+	fn game_price_set() -> Weight {
+		<() as liganite_games::WeightInfo>::game_price_set()
+	}
+	fn game_discount_set() -> Weight {
+		<() as liganite_games::WeightInfo>::game_discount_set()
+	}
+	fn receipt_remove() -> Weight {
+		<() as liganite_games::WeightInfo>::receipt_remove()
+	}
+	fn game_availability_report() -> Weight {
+		<() as liganite_games::WeightInfo>::game_availability_report()
+	}
+	fn game_build_set() -> Weight {
+		<() as liganite_games::WeightInfo>::game_build_set()
+	}
+	fn cid_reporter_set() -> Weight {
+		<() as liganite_games::WeightInfo>::cid_reporter_set()
+	}
 }